@@ -0,0 +1,3 @@
+ALTER TABLE permroles ADD COLUMN vote_weight REAL;
+ALTER TABLE permrole_starboards ADD COLUMN vote_weight REAL;
+ALTER TABLE votes ADD COLUMN weight REAL NOT NULL DEFAULT 1;
@@ -0,0 +1 @@
+ALTER TABLE starboards ADD COLUMN emoji_points JSONB NOT NULL DEFAULT '{}';
@@ -0,0 +1,38 @@
+-- existing votes are dated by when their message was sent, since that's the
+-- closest we have to when they were cast
+ALTER TABLE votes ADD COLUMN created_at TIMESTAMPTZ;
+UPDATE votes SET created_at = to_timestamp(((message_id >> 22) + 1420070400000) / 1000.0);
+ALTER TABLE votes
+    ALTER COLUMN created_at SET DEFAULT now(),
+    ALTER COLUMN created_at SET NOT NULL;
+
+-- 0 = Add, 1 = Remove, 2 = Flip
+CREATE TABLE vote_events (
+    id SERIAL NOT NULL,
+    message_id BIGINT NOT NULL,
+    starboard_id INTEGER NOT NULL,
+    user_id BIGINT NOT NULL,
+    kind SMALLINT NOT NULL,
+    is_downvote BOOLEAN NOT NULL,
+    weight REAL NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    FOREIGN KEY (message_id) REFERENCES messages (message_id)
+        MATCH SIMPLE
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+    FOREIGN KEY (starboard_id) REFERENCES starboards (id)
+        MATCH SIMPLE
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users (user_id)
+        MATCH SIMPLE
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    PRIMARY KEY (id)
+);
+
+CREATE INDEX IF NOT EXISTS votes__created_at ON votes USING BTREE ((created_at));
+CREATE INDEX IF NOT EXISTS vote_events__message_id_created_at
+    ON vote_events USING BTREE ((message_id), (created_at));
@@ -0,0 +1,25 @@
+ALTER TABLE guilds ADD COLUMN season INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE guilds ADD COLUMN season_started_at TIMESTAMPTZ;
+ALTER TABLE guilds ADD COLUMN xproles_use_season BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE guilds ADD COLUMN posroles_use_season BOOLEAN NOT NULL DEFAULT false;
+
+ALTER TABLE members ADD COLUMN season_xp REAL NOT NULL DEFAULT 0;
+-- the first season covers everything up until now
+UPDATE members SET season_xp=xp;
+
+CREATE TABLE IF NOT EXISTS season_standings (
+    guild_id BIGINT NOT NULL,
+    season INTEGER NOT NULL,
+    user_id BIGINT NOT NULL,
+    xp REAL NOT NULL,
+
+    FOREIGN KEY (guild_id) REFERENCES guilds (guild_id)
+        MATCH SIMPLE
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    PRIMARY KEY (guild_id, season, user_id)
+);
+
+CREATE INDEX IF NOT EXISTS members__guild_id_season_xp ON members USING BTREE ((guild_id), (season_xp));
+CREATE INDEX IF NOT EXISTS season_standings__guild_id_season_xp ON season_standings USING BTREE ((guild_id), (season), (xp));
@@ -0,0 +1 @@
+ALTER TABLE starboards ADD COLUMN vote_buttons BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,4 @@
+ALTER TABLE filters ADD COLUMN user_account_older_than BIGINT;
+ALTER TABLE filters ADD COLUMN user_joined_older_than BIGINT;
+ALTER TABLE filters ADD COLUMN voter_account_older_than BIGINT;
+ALTER TABLE filters ADD COLUMN voter_joined_older_than BIGINT;
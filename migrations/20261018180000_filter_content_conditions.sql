@@ -0,0 +1,10 @@
+ALTER TABLE filters ADD COLUMN contains_link BOOLEAN;
+ALTER TABLE filters ADD COLUMN contains_invite BOOLEAN;
+ALTER TABLE filters ADD COLUMN allowed_link_domains TEXT[];
+ALTER TABLE filters ADD COLUMN denied_link_domains TEXT[];
+ALTER TABLE filters ADD COLUMN mentions_users BOOLEAN;
+ALTER TABLE filters ADD COLUMN mentions_roles BOOLEAN;
+ALTER TABLE filters ADD COLUMN has_sticker BOOLEAN;
+ALTER TABLE filters ADD COLUMN attachment_types TEXT[];
+ALTER TABLE filters ADD COLUMN attachment_extensions TEXT[];
+ALTER TABLE filters ADD COLUMN is_reply BOOLEAN;
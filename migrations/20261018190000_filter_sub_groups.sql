@@ -0,0 +1,3 @@
+ALTER TABLE filters ADD COLUMN passes_all_of_groups INTEGER[];
+ALTER TABLE filters ADD COLUMN passes_some_of_groups INTEGER[];
+ALTER TABLE filters ADD COLUMN passes_none_of_groups INTEGER[];
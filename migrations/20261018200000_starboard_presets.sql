@@ -0,0 +1,15 @@
+CREATE TABLE starboard_presets (
+    id SERIAL NOT NULL,
+    guild_id BIGINT NOT NULL,
+    name TEXT NOT NULL,
+    preset JSONB NOT NULL,
+
+    FOREIGN KEY (guild_id) REFERENCES guilds (guild_id)
+        MATCH SIMPLE
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    PRIMARY KEY (id)
+);
+
+CREATE UNIQUE INDEX starboard_presets__guild_id_name ON starboard_presets USING BTREE (guild_id, name);
@@ -0,0 +1,21 @@
+CREATE TABLE audit_log (
+    id SERIAL NOT NULL,
+    guild_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    command TEXT NOT NULL,
+    action TEXT NOT NULL,
+    target_kind TEXT NOT NULL,
+    target_id BIGINT NOT NULL,
+    target_name TEXT NOT NULL,
+    changes JSONB NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    FOREIGN KEY (guild_id) REFERENCES guilds (guild_id)
+        MATCH SIMPLE
+        ON DELETE CASCADE
+        ON UPDATE CASCADE,
+
+    PRIMARY KEY (id)
+);
+
+CREATE INDEX audit_log__guild_id_created_at ON audit_log USING BTREE (guild_id, created_at);
@@ -0,0 +1 @@
+ALTER TABLE audit_log ADD COLUMN undone BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1 @@
+ALTER TABLE votes ADD COLUMN from_button BOOLEAN NOT NULL DEFAULT false;
@@ -2,6 +2,7 @@ use std::{hash::Hash, sync::Arc, time::Duration};
 
 use dashmap::{DashMap, DashSet};
 use moka::future::Cache as MokaCache;
+use regex::Regex;
 use tokio::sync::RwLock;
 use twilight_gateway::Event;
 use twilight_model::{
@@ -19,10 +20,12 @@ use crate::{
     client::bot::StarboardBot,
     constants,
     core::emoji::SimpleEmoji,
+    database::models::filter::Filter,
     errors::StarboardResult,
     utils::{
         async_dash::{AsyncDashMap, AsyncDashSet},
         get_status::get_status,
+        safe_regex::build_regex,
     },
 };
 
@@ -102,6 +105,11 @@ pub struct Cache {
     pub autostar_channel_ids: AsyncDashSet<Id<ChannelMarker>>,
     pub guild_vote_emojis: AsyncDashMap<i64, Vec<SimpleEmoji>>,
     pub guild_premium: AsyncDashMap<i64, bool>,
+    /// The filters of each filter group, in order. Must be invalidated when
+    /// the filters of a group change.
+    pub filter_groups: MokaCache<i32, Arc<Vec<Filter>>>,
+    /// Compiled regexes by their pattern, or `None` if the pattern is invalid.
+    pub regexes: MokaCache<String, Option<Arc<Regex>>>,
 
     // misc
     pub responses: MokaCache<Id<MessageMarker>, Id<MessageMarker>>,
@@ -120,6 +128,11 @@ impl Cache {
             autostar_channel_ids: autostar_channel_ids.into(),
             guild_vote_emojis: DashMap::new().into(),
             guild_premium: DashMap::new().into(),
+            filter_groups: moka_cache(
+                constants::MAX_FILTER_GROUPS_CACHED,
+                constants::FILTER_GROUPS_TTI,
+            ),
+            regexes: moka_cache(constants::MAX_REGEXES, constants::REGEXES_TTI),
 
             responses: moka_cache(
                 constants::MAX_STORED_RESPONSES,
@@ -274,6 +287,43 @@ impl Cache {
         Ok(user)
     }
 
+    pub async fn fog_filter_group(
+        &self,
+        bot: &StarboardBot,
+        filter_group_id: i32,
+    ) -> StarboardResult<Arc<Vec<Filter>>> {
+        if let Some(cached) = self.filter_groups.get(&filter_group_id) {
+            return Ok(cached);
+        }
+
+        let filters = Arc::new(Filter::list_by_filter(&bot.pool, filter_group_id).await?);
+        self.filter_groups
+            .insert(filter_group_id, filters.clone())
+            .await;
+
+        Ok(filters)
+    }
+
+    /// Get a compiled regex, or `None` if the pattern is invalid or too large.
+    pub async fn regex(&self, pattern: &str) -> Option<Arc<Regex>> {
+        if let Some(cached) = self.regexes.get(pattern) {
+            return cached;
+        }
+
+        let re = match build_regex(pattern) {
+            Ok(re) => Some(Arc::new(re)),
+            Err(why) => {
+                // regexes are checked when they're saved, so this is one that
+                // was saved before the size limits existed
+                eprintln!("Warning: invalid stored regex `{pattern}`:\n{why}");
+                None
+            }
+        };
+        self.regexes.insert(pattern.to_string(), re.clone()).await;
+
+        re
+    }
+
     pub async fn fog_member(
         &self,
         bot: &StarboardBot,
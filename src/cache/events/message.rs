@@ -71,6 +71,14 @@ impl UpdateCache for MessageUpdate {
             Some(content) => content.clone(),
             None => cached.content.clone(),
         };
+        let mentions = match &self.mentions {
+            Some(mentions) => mentions.iter().map(|m| m.id).collect(),
+            None => cached.mentions.clone(),
+        };
+        let mention_roles = match &self.mention_roles {
+            Some(mention_roles) => mention_roles.clone(),
+            None => cached.mention_roles.clone(),
+        };
 
         let message = CachedMessage {
             author_id: cached.author_id,
@@ -80,6 +88,8 @@ impl UpdateCache for MessageUpdate {
             content,
             stickers: cached.stickers.clone(),
             referenced_message: cached.referenced_message,
+            mentions,
+            mention_roles,
         };
 
         cache
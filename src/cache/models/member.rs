@@ -4,13 +4,14 @@ use twilight_model::{
     gateway::payload::incoming::MemberUpdate,
     guild::Member,
     id::{marker::RoleMarker, Id},
-    util::ImageHash,
+    util::{ImageHash, Timestamp},
 };
 
 pub struct CachedMember {
     pub nickname: Option<String>,
     pub server_avatar_hash: Option<ImageHash>,
     pub roles: HashSet<Id<RoleMarker>>,
+    pub joined_at: Timestamp,
 }
 
 impl From<Member> for CachedMember {
@@ -19,6 +20,7 @@ impl From<Member> for CachedMember {
             nickname: member.nick,
             server_avatar_hash: member.avatar,
             roles: HashSet::from_iter(member.roles),
+            joined_at: member.joined_at,
         }
     }
 }
@@ -29,6 +31,7 @@ impl From<&Member> for CachedMember {
             nickname: member.nick.clone(),
             server_avatar_hash: member.avatar,
             roles: HashSet::from_iter(member.roles.to_owned()),
+            joined_at: member.joined_at,
         }
     }
 }
@@ -39,6 +42,7 @@ impl From<&MemberUpdate> for CachedMember {
             nickname: member.nick.clone(),
             server_avatar_hash: member.avatar,
             roles: HashSet::from_iter(member.roles.to_owned()),
+            joined_at: member.joined_at,
         }
     }
 }
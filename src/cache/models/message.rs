@@ -4,7 +4,7 @@ use twilight_model::{
         Attachment, Message,
     },
     id::{
-        marker::{MessageMarker, UserMarker},
+        marker::{MessageMarker, RoleMarker, UserMarker},
         Id,
     },
     user::User,
@@ -45,6 +45,8 @@ pub struct CachedMessage {
     pub stickers: Vec<MessageSticker>,
     pub embeds: Vec<Embed>,
     pub referenced_message: Option<Id<MessageMarker>>,
+    pub mentions: Vec<Id<UserMarker>>,
+    pub mention_roles: Vec<Id<RoleMarker>>,
 }
 
 impl From<Message> for CachedMessage {
@@ -58,6 +60,8 @@ impl From<Message> for CachedMessage {
             content,
             stickers: msg.sticker_items,
             referenced_message: msg.reference.as_ref().and_then(|r| r.message_id),
+            mentions: msg.mentions.into_iter().map(|m| m.id).collect(),
+            mention_roles: msg.mention_roles,
         }
     }
 }
@@ -72,6 +76,8 @@ impl From<&Message> for CachedMessage {
             content: msg.system_content(),
             stickers: msg.sticker_items.clone(),
             referenced_message: msg.reference.as_ref().and_then(|r| r.message_id),
+            mentions: msg.mentions.iter().map(|m| m.id).collect(),
+            mention_roles: msg.mention_roles.clone(),
         }
     }
 }
@@ -3,6 +3,9 @@ use twilight_model::guild::Role;
 pub struct CachedRole {
     pub position: i64,
     pub name: String,
+    /// Whether the role is managed by an integration, so it can't be given
+    /// to members.
+    pub managed: bool,
 }
 
 impl From<&Role> for CachedRole {
@@ -10,6 +13,7 @@ impl From<&Role> for CachedRole {
         Self {
             position: value.position,
             name: value.name.to_owned(),
+            managed: value.managed,
         }
     }
 }
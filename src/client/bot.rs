@@ -16,6 +16,7 @@ use twilight_standby::Standby;
 use crate::{
     cache::Cache,
     client::config::Config,
+    core::starboard::refresh_queue::RefreshQueue,
     errors::{StarboardError, StarboardResult},
     utils::into_id::IntoId,
 };
@@ -33,6 +34,7 @@ pub struct StarboardBot {
     pub gw_config: GatewayConfig,
     pub cooldowns: Cooldowns,
     pub locks: Locks,
+    pub refresh_queue: RefreshQueue,
     pub start: DateTime<Utc>,
 }
 
@@ -100,6 +102,7 @@ impl StarboardBot {
             gw_config,
             cooldowns: Cooldowns::new(),
             locks: Locks::new(),
+            refresh_queue: RefreshQueue::new(),
             reqwest: reqwest::Client::new(),
             start: Utc::now(),
         })
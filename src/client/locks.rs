@@ -7,6 +7,9 @@ pub struct Locks {
     pub post_update_lock: DashSetLock<i64>,
     pub guild_pr_update: DashSetLock<i64>,
     pub vote_recount: DashSetLock<Id<MessageMarker>>,
+    pub backfill: DashSetLock<i64>,
+    pub starboard_posts: DashSetLock<i32>,
+    pub starboard_move: DashSetLock<i32>,
 }
 
 impl Locks {
@@ -11,6 +11,7 @@ use crate::{
         premium::{
             expire::loop_expire_premium, patreon::patreon_loop, roles::loop_update_supporter_roles,
         },
+        starboard::catch_up::loop_catch_up_votes,
     },
     events::handle_event,
 };
@@ -43,6 +44,7 @@ pub async fn run(bot: StarboardBot) {
     tokio::spawn(loop_expire_premium(bot.clone()));
     tokio::spawn(patreon_loop(bot.clone()));
     tokio::spawn(loop_update_supporter_roles(bot.clone()));
+    tokio::spawn(loop_catch_up_votes(bot.clone()));
 
     // handle events
     let mut shards: Vec<_> = stream::create_range(
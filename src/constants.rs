@@ -8,6 +8,7 @@ pub const BOT_COLOR: u32 = 0xFFE19C;
 
 pub const YEAR_SECONDS: i64 = 31_557_600;
 pub const MONTH_SECONDS: i64 = 2_630_016;
+pub const WEEK_SECONDS: i64 = 604_800;
 pub const MONTH_DAYS: u64 = 31;
 
 pub const CREDITS_PER_MONTH: u64 = 3;
@@ -29,6 +30,26 @@ pub const UPDATE_PRS_DELAY: Duration = Duration::from_secs(60 * 60);
 pub const CHECK_EXPIRED_PREMIUM: Duration = Duration::from_secs(60 * 60);
 pub const UPDATE_PATREON_DELAY: Duration = Duration::from_secs(60);
 pub const UPDATE_SUPPORTER_ROLES_DELAY: Duration = Duration::from_secs(60);
+pub const CATCH_UP_VOTES_STARTUP_DELAY: Duration = Duration::from_secs(60);
+pub const CATCH_UP_VOTES_DELAY: Duration = Duration::from_secs(60 * 30);
+/// The pause between each message that is caught up, to leave room in the rate limits.
+pub const CATCH_UP_VOTES_PAUSE: Duration = Duration::from_secs(1);
+/// How far back (in seconds) a message needs to have been voted on to be caught up.
+pub const CATCH_UP_VOTES_WINDOW: i64 = 60 * 60 * 6;
+pub const MAX_CATCH_UP_MESSAGES: i64 = 500;
+/// The pause between each page of history fetched by a backfill.
+pub const BACKFILL_PAGE_DELAY: Duration = Duration::from_secs(1);
+/// How many messages a backfill processes between progress updates.
+pub const BACKFILL_REPORT_EVERY: u64 = 10;
+/// How long (in seconds) the cancel button of a backfill stays active.
+pub const BACKFILL_CANCEL_TIMEOUT: u64 = 60 * 60 * 6;
+/// The pause between each post that is regenerated, to leave room in the rate limits.
+pub const REGENERATE_PAUSE: Duration = Duration::from_secs(1);
+/// How many posts are regenerated between progress updates.
+pub const REGENERATE_REPORT_EVERY: u64 = 10;
+/// How many times a post that is already being updated is retried when moving
+/// a starboard's posts, waiting `REGENERATE_PAUSE` each time.
+pub const MOVE_BUSY_RETRIES: u32 = 3;
 
 // Cache size
 pub const MAX_MESSAGES: u64 = 50_000;
@@ -37,6 +58,10 @@ pub const MAX_USERS: u64 = 50_000;
 pub const USERS_TTI: Duration = Duration::from_secs(60 * 60);
 pub const MAX_MEMBERS: u64 = 50_000;
 pub const MEMBERS_TTI: Duration = Duration::from_secs(60 * 60);
+pub const MAX_FILTER_GROUPS_CACHED: u64 = 10_000;
+pub const FILTER_GROUPS_TTI: Duration = Duration::from_secs(60 * 60);
+pub const MAX_REGEXES: u64 = 10_000;
+pub const REGEXES_TTI: Duration = Duration::from_secs(60 * 60);
 
 pub const MAX_STORED_RESPONSES: u64 = 100;
 pub const STORED_RESPONSES_TTI: Duration = Duration::from_secs(60 * 5);
@@ -48,11 +73,23 @@ pub const PREM_AUTOSTAR_COOLDOWN: (u64, Duration) = (100, Duration::from_secs(10
 pub const MESSAGE_EDIT: (u64, Duration) = (2, Duration::from_secs(10));
 pub const XP_REFRESH: (u64, Duration) = (1, Duration::from_secs(60 * 10));
 pub const VOTE_RECOUNT: (u64, Duration) = (5, Duration::from_secs(30));
+/// How long to wait before retrying an edit that hit the MESSAGE_EDIT cooldown.
+pub const REFRESH_QUEUE_DELAY: Duration = Duration::from_secs(10);
 
 // Common Validation
 pub const MAX_NAME_LENGTH: u32 = 32;
 pub const MIN_NAME_LENGTH: u32 = 3;
 pub const MAX_REGEX_LENGTH: u32 = 1_000;
+/// The largest file, in bytes, that can be imported.
+pub const MAX_IMPORT_SIZE: u64 = 1_000_000;
+/// The most memory, in bytes, that a compiled regex can use.
+pub const REGEX_SIZE_LIMIT: usize = 1024 * 1024;
+/// The most memory, in bytes, that a regex's lazy DFA can use while matching.
+pub const REGEX_DFA_SIZE_LIMIT: usize = 1024 * 1024;
+/// How many bytes of a message are searched by a regex. This bounds how long a
+/// single match can take, so it's below the longest messages (4,000 characters
+/// of up to 4 bytes each), and only the start of those is searched.
+pub const MAX_REGEX_HAYSTACK: usize = 4_000;
 pub const MAX_NEWER_THAN: i64 = YEAR_SECONDS * 50;
 pub const MAX_OLDER_THAN: i64 = YEAR_SECONDS * 50;
 
@@ -80,8 +117,11 @@ pub const MAX_COOLDOWN_PERIOD: i16 = 3600;
 
 pub const MAX_VOTE_EMOJIS: usize = 3;
 pub const MAX_PREM_VOTE_EMOJIS: usize = 20;
+pub const MIN_EMOJI_POINTS: f32 = 0.0;
+pub const MAX_EMOJI_POINTS: f32 = 100.0;
 pub const MAX_STARBOARDS: i64 = 3;
 pub const MAX_PREM_STARBOARDS: i64 = 20;
+pub const MAX_STARBOARD_PRESETS: i64 = 25;
 
 // Override Validation
 pub const MAX_CHANNELS_PER_OVERRIDE: usize = 100;
@@ -90,9 +130,17 @@ pub const MAX_OVERRIDES_PER_STARBOARD: i64 = 10;
 // Filter Validation
 pub const MAX_FILTER_GROUPS: usize = 50;
 pub const MAX_FILTERS_PER_GROUP: usize = 9;
+pub const MAX_FILTER_SUB_GROUPS: usize = 10;
+/// How deeply filter groups can reference each other.
+pub const MAX_FILTER_GROUP_DEPTH: usize = 10;
+/// How much of the filter group tree is shown by `/filters view`, to fit in a field.
+pub const MAX_FILTER_TREE_LENGTH: usize = 1_000;
 
 pub const MAX_FILTER_ROLES: usize = 25;
 pub const MAX_FILTER_CHANNELS: usize = 25;
+pub const MAX_FILTER_DOMAINS: usize = 25;
+pub const MAX_FILTER_EXTENSIONS: usize = 25;
+pub const MAX_EXTENSION_LENGTH: usize = 16;
 pub const MAX_ATTACHMENTS: i64 = 10;
 pub const MAX_LENGTH: i64 = 5_000;
 
@@ -101,6 +149,8 @@ pub const MAX_EXCLUSIVE_GROUPS: i64 = 10;
 
 // PermRole Validation
 pub const MAX_PERMROLES: i64 = 50;
+pub const MIN_VOTE_WEIGHT: f32 = 0.0;
+pub const MAX_VOTE_WEIGHT: f32 = 100.0;
 
 // XP-based Award Role Validation
 pub const MAX_XPROLES: i64 = 50;
@@ -0,0 +1,190 @@
+//! A record of which settings were changed, by whom, and how.
+
+pub mod undo;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+use twilight_model::application::interaction::application_command::{
+    CommandData, CommandOptionValue,
+};
+
+use crate::{
+    database::{models::audit_log_entry::AuditLogEntry, DbGuild},
+    errors::StarboardResult,
+    interactions::context::CommandCtx,
+    utils::id_as_i64::GetI64,
+};
+
+#[derive(Clone, Copy)]
+pub enum AuditAction {
+    Create,
+    Edit,
+    Rename,
+    Delete,
+    Undo,
+    Import,
+}
+
+impl AuditAction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Edit => "edit",
+            Self::Rename => "rename",
+            Self::Delete => "delete",
+            Self::Undo => "undo",
+            Self::Import => "import",
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum AuditTarget {
+    Starboard,
+    Override,
+    FilterGroup,
+    Filter,
+    PermRole,
+    PermRoleStarboard,
+    AutoStarChannel,
+    ExclusiveGroup,
+    Preset,
+    Guild,
+}
+
+impl AuditTarget {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Starboard => "starboard",
+            Self::Override => "override",
+            Self::FilterGroup => "filter group",
+            Self::Filter => "filter",
+            Self::PermRole => "permrole",
+            Self::PermRoleStarboard => "permrole starboard",
+            Self::AutoStarChannel => "autostar channel",
+            Self::ExclusiveGroup => "exclusive group",
+            Self::Preset => "preset",
+            Self::Guild => "server",
+        }
+    }
+
+    pub fn parse(kind: &str) -> Option<Self> {
+        [
+            Self::Starboard,
+            Self::Override,
+            Self::FilterGroup,
+            Self::Filter,
+            Self::PermRole,
+            Self::PermRoleStarboard,
+            Self::AutoStarChannel,
+            Self::ExclusiveGroup,
+            Self::Preset,
+            Self::Guild,
+        ]
+        .into_iter()
+        .find(|target| target.as_str() == kind)
+    }
+}
+
+/// Compares the serialized fields of `old` and `new`, returning
+/// `{"field": {"old": .., "new": ..}}` for each field that changed. Fields
+/// that are missing on one side (such as settings that an override doesn't
+/// set) are left out of that side.
+pub fn diff<T: Serialize>(old: &T, new: &T) -> Value {
+    let old = serde_json::to_value(old).unwrap_or_default();
+    let new = serde_json::to_value(new).unwrap_or_default();
+    let empty = Map::new();
+    let old = old.as_object().unwrap_or(&empty);
+    let new = new.as_object().unwrap_or(&empty);
+
+    let mut changes = Map::new();
+    for key in old
+        .keys()
+        .chain(new.keys().filter(|k| !old.contains_key(*k)))
+    {
+        let (old_val, new_val) = (old.get(key), new.get(key));
+        if old_val == new_val {
+            continue;
+        }
+
+        let mut change = Map::new();
+        if let Some(val) = old_val {
+            change.insert("old".to_string(), val.clone());
+        }
+        if let Some(val) = new_val {
+            change.insert("new".to_string(), val.clone());
+        }
+        changes.insert(key.clone(), Value::Object(change));
+    }
+
+    Value::Object(changes)
+}
+
+/// The changes for renaming something.
+pub fn rename(old: &str, new: &str) -> Value {
+    serde_json::json!({"name": {"old": old, "new": new}})
+}
+
+/// The target name for a filter, since filters are only identified by their
+/// group and position.
+pub fn filter_name(group_name: &str, position: i16) -> String {
+    format!("{group_name} (filter {position})")
+}
+
+/// The full name of a command, including its subcommands.
+pub fn command_name(data: &CommandData) -> String {
+    let mut name = data.name.clone();
+    let mut options = &data.options;
+    while let [option] = options.as_slice() {
+        match &option.value {
+            CommandOptionValue::SubCommand(sub) | CommandOptionValue::SubCommandGroup(sub) => {
+                name.push(' ');
+                name.push_str(&option.name);
+                options = sub;
+            }
+            _ => break,
+        }
+    }
+
+    name
+}
+
+/// Records a change made by the command in `ctx`, in the same transaction as
+/// the change itself. Edits (and undos) that didn't change anything aren't
+/// recorded.
+pub async fn log(
+    con: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ctx: &CommandCtx,
+    action: AuditAction,
+    target: AuditTarget,
+    target_id: i64,
+    target_name: &str,
+    changes: Value,
+) -> StarboardResult<()> {
+    if matches!(action, AuditAction::Edit | AuditAction::Undo)
+        && changes.as_object().is_some_and(|c| c.is_empty())
+    {
+        return Ok(());
+    }
+    let (Some(guild_id), Some(user_id)) = (ctx.interaction.guild_id, ctx.interaction.author_id())
+    else {
+        return Ok(());
+    };
+    let guild_id = guild_id.get_i64();
+
+    DbGuild::create(&mut *con, guild_id).await?;
+    AuditLogEntry::create(
+        &mut *con,
+        guild_id,
+        user_id.get_i64(),
+        &command_name(&ctx.data),
+        action.as_str(),
+        target.as_str(),
+        target_id,
+        target_name,
+        changes,
+    )
+    .await?;
+
+    Ok(())
+}
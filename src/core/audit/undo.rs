@@ -0,0 +1,246 @@
+//! Reverting edits using the old values recorded in the audit log.
+
+use serde_json::{Map, Value};
+
+use crate::{
+    client::bot::StarboardBot,
+    database::{
+        models::{audit_log_entry::AuditLogEntry, filter::Filter},
+        validation::{
+            filter_groups::validate_group_references, starboard_settings::validate_override_values,
+        },
+        ExclusiveGroup, OverrideValues, Starboard, StarboardOverride,
+    },
+    errors::StarboardResult,
+};
+
+use super::{diff, AuditTarget};
+
+/// The kinds of targets whose edits can be undone.
+pub const UNDOABLE_TARGETS: &[AuditTarget] = &[
+    AuditTarget::Starboard,
+    AuditTarget::Override,
+    AuditTarget::Filter,
+];
+
+pub enum UndoResult {
+    /// The edit was reverted.
+    Undone {
+        /// The changes made by reverting the edit.
+        changes: Value,
+        /// Settings that were left alone, because they were changed again
+        /// since the edit.
+        conflicts: Vec<String>,
+    },
+    /// The target of the edit no longer exists.
+    Missing,
+    /// The old settings are no longer valid, such as if they required
+    /// premium.
+    Invalid(String),
+}
+
+/// Sets each changed setting in `current` back to its old value, as long as
+/// it still has the value the edit set it to. Returns the settings that were
+/// left alone.
+fn revert(current: &mut Map<String, Value>, changes: &Map<String, Value>) -> Vec<String> {
+    let mut conflicts = Vec::new();
+    for (key, change) in changes {
+        if current.get(key) != change.get("new") {
+            conflicts.push(key.clone());
+            continue;
+        }
+
+        match change.get("old") {
+            Some(old) => current.insert(key.clone(), old.clone()),
+            None => current.remove(key),
+        };
+    }
+
+    conflicts
+}
+
+async fn exclusive_group_exists(bot: &StarboardBot, group: Option<i32>) -> StarboardResult<bool> {
+    match group {
+        None => Ok(true),
+        Some(id) => Ok(ExclusiveGroup::get(&bot.pool, id).await?.is_some()),
+    }
+}
+
+/// Reverts an edit recorded in the audit log in the transaction `con`. This
+/// does not mark the entry as undone, or update the caches.
+pub async fn undo(
+    bot: &StarboardBot,
+    con: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    entry: &AuditLogEntry,
+    premium: bool,
+) -> StarboardResult<UndoResult> {
+    let empty = Map::new();
+    let changes = entry.changes.as_object().unwrap_or(&empty);
+    let Ok(id) = i32::try_from(entry.target_id) else {
+        return Ok(UndoResult::Missing);
+    };
+
+    match AuditTarget::parse(&entry.target_kind) {
+        Some(AuditTarget::Starboard) => undo_starboard(bot, con, id, changes, premium).await,
+        Some(AuditTarget::Override) => undo_override(bot, con, id, changes, premium).await,
+        Some(AuditTarget::Filter) => undo_filter(bot, con, entry.guild_id, id, changes).await,
+        _ => Ok(UndoResult::Invalid(
+            "Only edits to starboards, overrides, and filters can be undone.".to_string(),
+        )),
+    }
+}
+
+/// Updates the caches after an undo has been committed.
+pub async fn update_cache(bot: &StarboardBot, entry: &AuditLogEntry) -> StarboardResult<()> {
+    match AuditTarget::parse(&entry.target_kind) {
+        Some(AuditTarget::Starboard | AuditTarget::Override) => {
+            bot.cache.guild_vote_emojis.remove(&entry.guild_id);
+        }
+        Some(AuditTarget::Filter) => {
+            let Ok(id) = i32::try_from(entry.target_id) else {
+                return Ok(());
+            };
+            if let Some(filter) = Filter::get(&bot.pool, id).await? {
+                bot.cache
+                    .filter_groups
+                    .invalidate(&filter.filter_group_id)
+                    .await;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+async fn undo_starboard(
+    bot: &StarboardBot,
+    con: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    id: i32,
+    changes: &Map<String, Value>,
+    premium: bool,
+) -> StarboardResult<UndoResult> {
+    let Some(mut starboard) = Starboard::get(&bot.pool, id).await? else {
+        return Ok(UndoResult::Missing);
+    };
+    // moves are recorded as a change to the channel, which isn't a setting
+    if changes.contains_key("channel_id") {
+        return Ok(UndoResult::Invalid(
+            "Moving a starboard can't be undone. Use `/starboards move` to move it back."
+                .to_string(),
+        ));
+    }
+
+    let old_settings = starboard.settings.clone();
+    let mut settings = serde_json::to_value(&starboard.settings)?;
+    let Some(settings_map) = settings.as_object_mut() else {
+        return Ok(UndoResult::Missing);
+    };
+    let conflicts = revert(settings_map, changes);
+    starboard.settings = serde_json::from_value(settings)?;
+
+    let values = OverrideValues::from_settings(starboard.settings.clone());
+    if let Err(why) = validate_override_values(&values, premium) {
+        return Ok(UndoResult::Invalid(why));
+    }
+    if !exclusive_group_exists(bot, starboard.settings.exclusive_group).await? {
+        return Ok(UndoResult::Invalid(
+            "The exclusive group it used was deleted.".to_string(),
+        ));
+    }
+
+    let changes = diff(&old_settings, &starboard.settings);
+    starboard.update_settings(&mut *con).await?;
+
+    Ok(UndoResult::Undone { changes, conflicts })
+}
+
+async fn undo_override(
+    bot: &StarboardBot,
+    con: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    id: i32,
+    changes: &Map<String, Value>,
+    premium: bool,
+) -> StarboardResult<UndoResult> {
+    let Some(ov) = StarboardOverride::get_by_id(&bot.pool, id).await? else {
+        return Ok(UndoResult::Missing);
+    };
+
+    // the channels of an override are stored separately from its settings
+    let mut changes = changes.clone();
+    let channel_change = changes.remove("channel_ids");
+
+    let mut settings = ov.overrides.clone();
+    let Some(settings_map) = settings.as_object_mut() else {
+        return Ok(UndoResult::Missing);
+    };
+    let mut conflicts = revert(settings_map, &changes);
+
+    let values: OverrideValues = serde_json::from_value(settings.clone())?;
+    if let Err(why) = validate_override_values(&values, premium) {
+        return Ok(UndoResult::Invalid(why));
+    }
+    if !exclusive_group_exists(bot, values.exclusive_group.flatten()).await? {
+        return Ok(UndoResult::Invalid(
+            "The exclusive group it used was deleted.".to_string(),
+        ));
+    }
+
+    let mut channel_ids = ov.channel_ids.clone();
+    if let Some(change) = channel_change {
+        match (change.get("old"), change.get("new")) {
+            (Some(old), Some(new)) if *new == serde_json::json!(ov.channel_ids) => {
+                channel_ids = serde_json::from_value(old.clone())?;
+            }
+            _ => conflicts.push("channel_ids".to_string()),
+        }
+    }
+    if let Err(why) = StarboardOverride::validate_channels(&channel_ids) {
+        return Ok(UndoResult::Invalid(why));
+    }
+
+    let mut new_changes = diff(&ov.overrides, &settings);
+    if channel_ids != ov.channel_ids {
+        new_changes.as_object_mut().unwrap().insert(
+            "channel_ids".to_string(),
+            serde_json::json!({"old": ov.channel_ids, "new": channel_ids}),
+        );
+    }
+
+    StarboardOverride::set_channels(&mut *con, ov.guild_id, &ov.name, &channel_ids).await?;
+    StarboardOverride::update_settings_raw(&mut *con, ov.id, settings).await?;
+
+    Ok(UndoResult::Undone {
+        changes: new_changes,
+        conflicts,
+    })
+}
+
+async fn undo_filter(
+    bot: &StarboardBot,
+    con: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    guild_id: i64,
+    id: i32,
+    changes: &Map<String, Value>,
+) -> StarboardResult<UndoResult> {
+    let Some(filter) = Filter::get(&bot.pool, id).await? else {
+        return Ok(UndoResult::Missing);
+    };
+
+    let mut value = serde_json::to_value(&filter)?;
+    let Some(value_map) = value.as_object_mut() else {
+        return Ok(UndoResult::Missing);
+    };
+    let conflicts = revert(value_map, changes);
+    let new: Filter = serde_json::from_value(value)?;
+
+    let guild_filters = Filter::list_by_guild(&mut *con, guild_id).await?;
+    if let Err(why) = validate_group_references(&new, &guild_filters) {
+        return Ok(UndoResult::Invalid(why));
+    }
+
+    let changes = diff(&filter, &new);
+    new.update_settings(&mut *con).await?;
+
+    Ok(UndoResult::Undone { changes, conflicts })
+}
@@ -99,13 +99,48 @@ impl BuiltStarboardEmbed {
         })
     }
 
+    pub fn build_vote_buttons(handle: &Embedder) -> Vec<Button> {
+        if !handle.config.resolved.vote_buttons {
+            return vec![];
+        }
+
+        let resolved = &handle.config.resolved;
+        let mut buttons = Vec::new();
+        let kinds = [
+            ("upvote", "Upvote", &resolved.upvote_emojis),
+            ("downvote", "Downvote", &resolved.downvote_emojis),
+        ];
+        for (id, label, emojis) in kinds {
+            let Some(emoji) = emojis.first() else {
+                continue;
+            };
+
+            buttons.push(Button {
+                custom_id: Some(format!("stateless::vote::{id}")),
+                disabled: false,
+                emoji: Some(SimpleEmoji::from_stored(emoji.clone()).as_reaction_type()),
+                label: Some(label.to_string()),
+                style: ButtonStyle::Secondary,
+                url: None,
+            });
+        }
+
+        buttons
+    }
+
     pub fn build_components(handle: &Embedder) -> Vec<Component> {
-        let Some(gtm) = Self::build_go_to_message_button(handle) else {
+        let buttons: Vec<_> = Self::build_vote_buttons(handle)
+            .into_iter()
+            .chain(Self::build_go_to_message_button(handle))
+            .map(Component::Button)
+            .collect();
+
+        if buttons.is_empty() {
             return vec![];
-        };
+        }
 
         vec![Component::ActionRow(ActionRow {
-            components: vec![Component::Button(gtm)],
+            components: buttons,
         })]
     }
 
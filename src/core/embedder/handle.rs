@@ -251,6 +251,40 @@ impl Embedder {
         Ok(false)
     }
 
+    /// Whether a starboard message was sent by the bot while the starboard now
+    /// uses webhooks, or the other way around. These can't be edited into the
+    /// current style, so they have to be resent.
+    pub async fn needs_resend(
+        &self,
+        bot: &StarboardBot,
+        message_id: Id<MessageMarker>,
+    ) -> StarboardResult<bool> {
+        let guild_id = self.config.starboard.guild_id.into_id();
+        let sb_channel_id = self.config.starboard.channel_id.into_id();
+
+        let is_forum = bot.cache.is_channel_forum(guild_id, sb_channel_id);
+        let real_channel_id = if is_forum {
+            message_id.get().into_id()
+        } else {
+            sb_channel_id
+        };
+
+        let ret = bot
+            .cache
+            .fog_message(bot, real_channel_id, message_id)
+            .await?;
+        let MessageResult::Ok(msg) = ret else {
+            return Ok(false);
+        };
+
+        let sent_by_bot = msg.author_id.get() == bot.config.bot_id;
+        if self.config.resolved.use_webhook {
+            Ok(sent_by_bot || Some(msg.author_id.get_i64()) != self.config.starboard.webhook_id)
+        } else {
+            Ok(!sent_by_bot)
+        }
+    }
+
     pub async fn delete(
         &self,
         bot: &StarboardBot,
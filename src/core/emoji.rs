@@ -126,6 +126,36 @@ pub trait EmojiCommon: Sized {
 }
 
 impl SimpleEmoji {
+    /// Get the number of points a vote using this emoji is worth, given a map of
+    /// stored emojis to point values. Emojis that aren't in the map are worth 1 point.
+    pub fn points(&self, emoji_points: &serde_json::Value) -> f32 {
+        let Some(emoji_points) = emoji_points.as_object() else {
+            return 1.0;
+        };
+
+        emoji_points
+            .iter()
+            .find(|(emoji, _)| self == *emoji)
+            .and_then(|(_, points)| points.as_f64())
+            .map(|points| points as f32)
+            .unwrap_or(1.0)
+    }
+
+    /// Get this emoji in a form that can be attached to a button.
+    pub fn as_reaction_type(&self) -> ReactionType {
+        if let Some(emoji_id) = self.as_id {
+            ReactionType::Custom {
+                animated: false,
+                id: emoji_id,
+                name: None,
+            }
+        } else {
+            ReactionType::Unicode {
+                name: self.raw.clone(),
+            }
+        }
+    }
+
     pub fn reactable(&self) -> RequestReactionType {
         if let Some(emoji_id) = self.as_id {
             RequestReactionType::Custom {
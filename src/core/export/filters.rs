@@ -0,0 +1,488 @@
+//! Exporting filter groups to JSON, and importing them into another server.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{
+    client::bot::StarboardBot,
+    constants,
+    database::{
+        models::{filter::Filter, filter_group::FilterGroup},
+        validation::{
+            filter_content::{parse_attachment_types, parse_domains, parse_extensions},
+            time_delta::validate_relative_duration,
+        },
+    },
+    errors::StarboardResult,
+    utils::{id_as_i64::GetI64, into_id::IntoId, safe_regex::build_regex},
+};
+
+use super::{role_exists, ImportReport, EXPORT_VERSION};
+
+/// A filter, without anything tied to the group it belongs to. Other filter
+/// groups are referenced by name, since their ids differ between servers.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct ExportedFilter {
+    pub instant_pass: bool,
+    pub instant_fail: bool,
+
+    // other filter groups
+    pub passes_all_of_groups: Option<Vec<String>>,
+    pub passes_some_of_groups: Option<Vec<String>>,
+    pub passes_none_of_groups: Option<Vec<String>>,
+
+    // default context
+    pub user_has_all_of: Option<Vec<i64>>,
+    pub user_has_some_of: Option<Vec<i64>>,
+    pub user_missing_all_of: Option<Vec<i64>>,
+    pub user_missing_some_of: Option<Vec<i64>>,
+    pub user_is_bot: Option<bool>,
+    pub user_account_older_than: Option<i64>,
+    pub user_joined_older_than: Option<i64>,
+
+    // message context
+    pub in_channel: Option<Vec<i64>>,
+    pub not_in_channel: Option<Vec<i64>>,
+    pub in_channel_or_sub_channels: Option<Vec<i64>>,
+    pub not_in_channel_or_sub_channels: Option<Vec<i64>>,
+    pub min_attachments: Option<i16>,
+    pub max_attachments: Option<i16>,
+    pub min_length: Option<i32>,
+    pub max_length: Option<i32>,
+    pub matches: Option<String>,
+    pub not_matches: Option<String>,
+    pub contains_link: Option<bool>,
+    pub contains_invite: Option<bool>,
+    pub allowed_link_domains: Option<Vec<String>>,
+    pub denied_link_domains: Option<Vec<String>>,
+    pub mentions_users: Option<bool>,
+    pub mentions_roles: Option<bool>,
+    pub has_sticker: Option<bool>,
+    pub attachment_types: Option<Vec<String>>,
+    pub attachment_extensions: Option<Vec<String>>,
+    pub is_reply: Option<bool>,
+
+    // vote context
+    pub voter_has_all_of: Option<Vec<i64>>,
+    pub voter_has_some_of: Option<Vec<i64>>,
+    pub voter_missing_all_of: Option<Vec<i64>>,
+    pub voter_missing_some_of: Option<Vec<i64>>,
+    pub older_than: Option<i64>,
+    pub newer_than: Option<i64>,
+    pub voter_account_older_than: Option<i64>,
+    pub voter_joined_older_than: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportedFilterGroup {
+    pub version: u32,
+    pub name: String,
+    pub filters: Vec<ExportedFilter>,
+}
+
+fn group_names(ids: &Option<Vec<i32>>, names: &HashMap<i32, String>) -> Option<Vec<String>> {
+    let names: Vec<_> = ids
+        .as_ref()?
+        .iter()
+        .filter_map(|id| names.get(id).cloned())
+        .collect();
+
+    if names.is_empty() {
+        None
+    } else {
+        Some(names)
+    }
+}
+
+impl ExportedFilter {
+    pub fn new(filter: &Filter, group_names: &HashMap<i32, String>) -> Self {
+        let filter = filter.clone();
+        Self {
+            instant_pass: filter.instant_pass,
+            instant_fail: filter.instant_fail,
+            passes_all_of_groups: self::group_names(&filter.passes_all_of_groups, group_names),
+            passes_some_of_groups: self::group_names(&filter.passes_some_of_groups, group_names),
+            passes_none_of_groups: self::group_names(&filter.passes_none_of_groups, group_names),
+            user_has_all_of: filter.user_has_all_of,
+            user_has_some_of: filter.user_has_some_of,
+            user_missing_all_of: filter.user_missing_all_of,
+            user_missing_some_of: filter.user_missing_some_of,
+            user_is_bot: filter.user_is_bot,
+            user_account_older_than: filter.user_account_older_than,
+            user_joined_older_than: filter.user_joined_older_than,
+            in_channel: filter.in_channel,
+            not_in_channel: filter.not_in_channel,
+            in_channel_or_sub_channels: filter.in_channel_or_sub_channels,
+            not_in_channel_or_sub_channels: filter.not_in_channel_or_sub_channels,
+            min_attachments: filter.min_attachments,
+            max_attachments: filter.max_attachments,
+            min_length: filter.min_length,
+            max_length: filter.max_length,
+            matches: filter.matches,
+            not_matches: filter.not_matches,
+            contains_link: filter.contains_link,
+            contains_invite: filter.contains_invite,
+            allowed_link_domains: filter.allowed_link_domains,
+            denied_link_domains: filter.denied_link_domains,
+            mentions_users: filter.mentions_users,
+            mentions_roles: filter.mentions_roles,
+            has_sticker: filter.has_sticker,
+            attachment_types: filter.attachment_types,
+            attachment_extensions: filter.attachment_extensions,
+            is_reply: filter.is_reply,
+            voter_has_all_of: filter.voter_has_all_of,
+            voter_has_some_of: filter.voter_has_some_of,
+            voter_missing_all_of: filter.voter_missing_all_of,
+            voter_missing_some_of: filter.voter_missing_some_of,
+            older_than: filter.older_than,
+            newer_than: filter.newer_than,
+            voter_account_older_than: filter.voter_account_older_than,
+            voter_joined_older_than: filter.voter_joined_older_than,
+        }
+    }
+
+    /// Checks the filter against the same limits as `/filters edit`, and
+    /// normalizes its lists.
+    pub fn validate(&mut self, premium: bool) -> Result<(), String> {
+        let role_lists = [
+            &self.user_has_all_of,
+            &self.user_has_some_of,
+            &self.user_missing_all_of,
+            &self.user_missing_some_of,
+            &self.voter_has_all_of,
+            &self.voter_has_some_of,
+            &self.voter_missing_all_of,
+            &self.voter_missing_some_of,
+        ];
+        if role_lists
+            .into_iter()
+            .flatten()
+            .any(|roles| roles.len() > constants::MAX_FILTER_ROLES)
+        {
+            return Err(format!(
+                "You can only have up to {} roles in a list.",
+                constants::MAX_FILTER_ROLES
+            ));
+        }
+
+        let channel_lists = [
+            &self.in_channel,
+            &self.not_in_channel,
+            &self.in_channel_or_sub_channels,
+            &self.not_in_channel_or_sub_channels,
+        ];
+        if channel_lists
+            .into_iter()
+            .flatten()
+            .any(|channels| channels.len() > constants::MAX_FILTER_CHANNELS)
+        {
+            return Err(format!(
+                "You can only have up to {} channels in a list.",
+                constants::MAX_FILTER_CHANNELS
+            ));
+        }
+
+        let group_lists = [
+            &self.passes_all_of_groups,
+            &self.passes_some_of_groups,
+            &self.passes_none_of_groups,
+        ];
+        if group_lists
+            .into_iter()
+            .flatten()
+            .any(|groups| groups.len() > constants::MAX_FILTER_SUB_GROUPS)
+        {
+            return Err(format!(
+                "You can only reference up to {} filter groups in a list.",
+                constants::MAX_FILTER_SUB_GROUPS
+            ));
+        }
+
+        for attachments in [self.min_attachments, self.max_attachments]
+            .into_iter()
+            .flatten()
+        {
+            if attachments < 0 || attachments as i64 > constants::MAX_ATTACHMENTS {
+                return Err(format!(
+                    "The number of attachments must be between 0 and {}.",
+                    constants::MAX_ATTACHMENTS
+                ));
+            }
+        }
+        for length in [self.min_length, self.max_length].into_iter().flatten() {
+            if length < 0 || length as i64 > constants::MAX_LENGTH {
+                return Err(format!(
+                    "The length must be between 0 and {}.",
+                    constants::MAX_LENGTH
+                ));
+            }
+        }
+
+        for regex in [&self.matches, &self.not_matches].into_iter().flatten() {
+            if !premium {
+                return Err(
+                    "Only premium servers can use the `matches` and `not-matches` conditions."
+                        .to_string(),
+                );
+            }
+            if regex.len() > constants::MAX_REGEX_LENGTH as usize {
+                return Err(format!(
+                    "Regexes cannot be longer than {}.",
+                    constants::MAX_REGEX_LENGTH
+                ));
+            }
+            if let Err(why) = build_regex(regex) {
+                return Err(format!("```\n{why}\n```"));
+            }
+        }
+
+        let ages = [
+            self.user_account_older_than,
+            self.user_joined_older_than,
+            self.voter_account_older_than,
+            self.voter_joined_older_than,
+        ];
+        for age in ages.into_iter().flatten() {
+            if !(0..=constants::MAX_OLDER_THAN).contains(&age) {
+                return Err("An account or join age is out of range.".to_string());
+            }
+        }
+        validate_relative_duration(self.newer_than, self.older_than)?;
+
+        if let Some(val) = &self.allowed_link_domains {
+            self.allowed_link_domains = parse_domains(&val.join(" "))?;
+        }
+        if let Some(val) = &self.denied_link_domains {
+            self.denied_link_domains = parse_domains(&val.join(" "))?;
+        }
+        if let Some(val) = &self.attachment_types {
+            self.attachment_types = parse_attachment_types(&val.join(" "))?;
+        }
+        if let Some(val) = &self.attachment_extensions {
+            self.attachment_extensions = parse_extensions(&val.join(" "))?;
+        }
+
+        Ok(())
+    }
+
+    /// Copies the conditions onto a newly created filter, leaving out any
+    /// roles, channels or filter groups that don't exist in the server.
+    pub async fn apply(
+        self,
+        bot: &StarboardBot,
+        guild_id: Id<GuildMarker>,
+        group_ids: &HashMap<String, i32>,
+        filter: &mut Filter,
+        report: &mut ImportReport,
+    ) -> StarboardResult<()> {
+        let mut groups = |names: Option<Vec<String>>| {
+            let ids: Vec<_> = names?
+                .into_iter()
+                .filter_map(|name| match group_ids.get(&name) {
+                    Some(id) => Some(*id),
+                    None => {
+                        report.missing_groups.insert(name);
+                        None
+                    }
+                })
+                .collect();
+            if ids.is_empty() {
+                None
+            } else {
+                Some(ids)
+            }
+        };
+        filter.passes_all_of_groups = groups(self.passes_all_of_groups);
+        filter.passes_some_of_groups = groups(self.passes_some_of_groups);
+        filter.passes_none_of_groups = groups(self.passes_none_of_groups);
+
+        let roles = |ids: Option<Vec<i64>>, report: &mut ImportReport| {
+            let ids: Vec<_> = ids?
+                .into_iter()
+                .filter(|id| {
+                    let exists = role_exists(bot, guild_id, *id);
+                    if !exists {
+                        report.missing_roles.insert(*id);
+                    }
+                    exists
+                })
+                .collect();
+            if ids.is_empty() {
+                None
+            } else {
+                Some(ids)
+            }
+        };
+        filter.user_has_all_of = roles(self.user_has_all_of, report);
+        filter.user_has_some_of = roles(self.user_has_some_of, report);
+        filter.user_missing_all_of = roles(self.user_missing_all_of, report);
+        filter.user_missing_some_of = roles(self.user_missing_some_of, report);
+        filter.voter_has_all_of = roles(self.voter_has_all_of, report);
+        filter.voter_has_some_of = roles(self.voter_has_some_of, report);
+        filter.voter_missing_all_of = roles(self.voter_missing_all_of, report);
+        filter.voter_missing_some_of = roles(self.voter_missing_some_of, report);
+
+        let channel_lists = [
+            self.in_channel,
+            self.not_in_channel,
+            self.in_channel_or_sub_channels,
+            self.not_in_channel_or_sub_channels,
+        ];
+        let mut channels = Vec::new();
+        for ids in channel_lists {
+            let Some(ids) = ids else {
+                channels.push(None);
+                continue;
+            };
+
+            let mut existing = Vec::new();
+            for id in ids {
+                if bot
+                    .cache
+                    .guild_has_channel(bot, guild_id, id.into_id())
+                    .await?
+                {
+                    existing.push(id);
+                } else {
+                    report.missing_channels.insert(id);
+                }
+            }
+            channels.push(if existing.is_empty() {
+                None
+            } else {
+                Some(existing)
+            });
+        }
+        let [in_channel, not_in_channel, in_channel_or_sub_channels, not_in_channel_or_sub_channels]: [_; 4] =
+            channels.try_into().unwrap();
+        filter.in_channel = in_channel;
+        filter.not_in_channel = not_in_channel;
+        filter.in_channel_or_sub_channels = in_channel_or_sub_channels;
+        filter.not_in_channel_or_sub_channels = not_in_channel_or_sub_channels;
+
+        filter.instant_pass = self.instant_pass;
+        filter.instant_fail = self.instant_fail;
+        filter.user_is_bot = self.user_is_bot;
+        filter.user_account_older_than = self.user_account_older_than;
+        filter.user_joined_older_than = self.user_joined_older_than;
+        filter.min_attachments = self.min_attachments;
+        filter.max_attachments = self.max_attachments;
+        filter.min_length = self.min_length;
+        filter.max_length = self.max_length;
+        filter.matches = self.matches;
+        filter.not_matches = self.not_matches;
+        filter.contains_link = self.contains_link;
+        filter.contains_invite = self.contains_invite;
+        filter.allowed_link_domains = self.allowed_link_domains;
+        filter.denied_link_domains = self.denied_link_domains;
+        filter.mentions_users = self.mentions_users;
+        filter.mentions_roles = self.mentions_roles;
+        filter.has_sticker = self.has_sticker;
+        filter.attachment_types = self.attachment_types;
+        filter.attachment_extensions = self.attachment_extensions;
+        filter.is_reply = self.is_reply;
+        filter.older_than = self.older_than;
+        filter.newer_than = self.newer_than;
+        filter.voter_account_older_than = self.voter_account_older_than;
+        filter.voter_joined_older_than = self.voter_joined_older_than;
+
+        Ok(())
+    }
+}
+
+impl ExportedFilterGroup {
+    pub fn new(
+        group: &FilterGroup,
+        filters: &[Filter],
+        group_names: &HashMap<i32, String>,
+    ) -> Self {
+        Self {
+            version: EXPORT_VERSION,
+            name: group.name.clone(),
+            filters: filters
+                .iter()
+                .map(|filter| ExportedFilter::new(filter, group_names))
+                .collect(),
+        }
+    }
+
+    pub fn validate(&mut self, premium: bool) -> Result<(), String> {
+        if self.version > EXPORT_VERSION {
+            return Err("This file was exported by a newer version of Starboard.".to_string());
+        }
+        if self.filters.len() > constants::MAX_FILTERS_PER_GROUP {
+            return Err(format!(
+                "A filter group can only have up to {} filters.",
+                constants::MAX_FILTERS_PER_GROUP
+            ));
+        }
+
+        for (x, filter) in self.filters.iter_mut().enumerate() {
+            filter
+                .validate(premium)
+                .map_err(|why| format!("Filter {}: {}", x + 1, why))?;
+        }
+
+        Ok(())
+    }
+
+    /// Creates the filter group and its filters in the transaction `con`.
+    /// Returns `None` if a filter group with the same name already exists.
+    pub async fn import(
+        self,
+        bot: &StarboardBot,
+        con: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+    ) -> StarboardResult<Option<(FilterGroup, ImportReport)>> {
+        let group = FilterGroup::create(&mut *con, guild_id.get_i64(), name).await?;
+        let Some(group) = group else {
+            return Ok(None);
+        };
+
+        // a group can't reference itself, so leave it out
+        let group_ids: HashMap<_, _> = FilterGroup::list_by_guild(&mut *con, guild_id.get_i64())
+            .await?
+            .into_iter()
+            .filter(|g| g.id != group.id)
+            .map(|g| (g.name, g.id))
+            .collect();
+
+        let mut report = ImportReport::default();
+        self.import_filters(bot, con, guild_id, group.id, &group_ids, &mut report)
+            .await?;
+
+        Ok(Some((group, report)))
+    }
+
+    /// Creates the filters of an imported group. `group_ids` maps the names of
+    /// the filter groups in the server to their ids.
+    pub async fn import_filters(
+        self,
+        bot: &StarboardBot,
+        con: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        guild_id: Id<GuildMarker>,
+        filter_group_id: i32,
+        group_ids: &HashMap<String, i32>,
+        report: &mut ImportReport,
+    ) -> StarboardResult<()> {
+        for (x, exported) in self.filters.into_iter().enumerate() {
+            let filter = Filter::create(&mut *con, filter_group_id, x as i16 + 1).await?;
+            let Some(mut filter) = filter else {
+                continue;
+            };
+
+            exported
+                .apply(bot, guild_id, group_ids, &mut filter, report)
+                .await?;
+            filter.update_settings(&mut *con).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1148 @@
+//! Exporting and importing the entire configuration of a server, for backups
+//! or for copying a setup to another server.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{
+    client::bot::StarboardBot,
+    constants,
+    core::starboard::config::update_from_override,
+    database::{
+        helpers::settings::overrides::call_with_override_settings,
+        models::{
+            autostar_channel_filter_group::AutostarChannelFilterGroup, filter::Filter,
+            filter_group::FilterGroup, starboard_filter_group::StarboardFilterGroup,
+        },
+        validation::{
+            filter_groups::validate_group_references,
+            name::validate_name,
+            starboard_settings::{validate_override_values, validate_xp_multiplier},
+            vote_weight::validate_vote_weight,
+        },
+        AutoStarChannel, DbGuild, ExclusiveGroup, OverrideValues, PermRole, PermRoleStarboard,
+        PosRole, Starboard, StarboardOverride, XPRole,
+    },
+    errors::StarboardResult,
+    utils::{id_as_i64::GetI64, into_id::IntoId},
+};
+
+use super::{
+    award_role_usable, channel_exists, channel_usable, emoji_usable, filters::ExportedFilterGroup,
+    role_exists, ImportReport, EXPORT_VERSION,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct GuildConfig {
+    pub version: u32,
+    #[serde(default)]
+    pub xproles_use_season: bool,
+    #[serde(default)]
+    pub posroles_use_season: bool,
+    #[serde(default)]
+    pub exclusive_groups: Vec<String>,
+    #[serde(default)]
+    pub filter_groups: Vec<ExportedFilterGroup>,
+    #[serde(default)]
+    pub starboards: Vec<ExportedStarboard>,
+    #[serde(default)]
+    pub autostar_channels: Vec<ExportedAutoStarChannel>,
+    #[serde(default)]
+    pub permroles: Vec<ExportedPermRole>,
+    #[serde(default)]
+    pub xproles: Vec<ExportedXPRole>,
+    #[serde(default)]
+    pub posroles: Vec<ExportedPosRole>,
+}
+
+/// A starboard. Exclusive groups and filter groups are referenced by name,
+/// since their ids differ between servers.
+///
+/// When importing into a starboard that already exists, it's merged with the
+/// existing one: settings that are missing keep their current value, while
+/// the exclusive group, filter groups and overrides are replaced.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
+pub struct ExportedStarboard {
+    pub name: String,
+    /// Only used when the starboard is created. Existing starboards aren't
+    /// moved, since that needs their posts to be moved too.
+    pub channel_id: i64,
+    /// Every setting that an override can change. Settings that are missing
+    /// are left at their default for new starboards, and keep their current
+    /// value for existing ones.
+    #[serde(default)]
+    pub settings: OverrideValues,
+    pub private: Option<bool>,
+    pub xp_multiplier: Option<f32>,
+    pub exclusive_group: Option<String>,
+    #[serde(default)]
+    pub filter_groups: Vec<String>,
+    #[serde(default)]
+    pub overrides: Vec<ExportedOverride>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
+pub struct ExportedOverride {
+    pub name: String,
+    pub channel_ids: Vec<i64>,
+    #[serde(default)]
+    pub settings: OverrideValues,
+    pub exclusive_group: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
+pub struct ExportedAutoStarChannel {
+    pub name: String,
+    pub channel_id: i64,
+    #[serde(default)]
+    pub emojis: Vec<String>,
+    #[serde(default)]
+    pub min_chars: i16,
+    pub max_chars: Option<i16>,
+    #[serde(default)]
+    pub require_image: bool,
+    #[serde(default)]
+    pub delete_invalid: bool,
+    #[serde(default)]
+    pub filter_groups: Vec<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
+pub struct ExportedPermRole {
+    pub role_id: i64,
+    pub obtain_xproles: Option<bool>,
+    pub give_votes: Option<bool>,
+    pub receive_votes: Option<bool>,
+    pub vote_weight: Option<f32>,
+    #[serde(default)]
+    pub starboards: Vec<ExportedPermRoleStarboard>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize)]
+pub struct ExportedPermRoleStarboard {
+    pub starboard: String,
+    pub give_votes: Option<bool>,
+    pub receive_votes: Option<bool>,
+    pub vote_weight: Option<f32>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportedXPRole {
+    pub role_id: i64,
+    pub required: i64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportedPosRole {
+    pub role_id: i64,
+    pub max_members: i32,
+}
+
+/// What an import would change for one kind of setting.
+#[derive(Default)]
+pub struct Changes {
+    pub created: Vec<String>,
+    pub updated: Vec<String>,
+    pub deleted: Vec<String>,
+    pub skipped: Vec<String>,
+    /// Existing items that the import leaves alone.
+    pub kept: usize,
+}
+
+impl Changes {
+    fn new(existing: Vec<String>, imported: Vec<String>, replace: bool) -> Self {
+        let mut changes = Self::default();
+        for name in existing.iter() {
+            if imported.contains(name) {
+                changes.updated.push(name.clone());
+            } else if replace {
+                changes.deleted.push(name.clone());
+            } else {
+                changes.kept += 1;
+            }
+        }
+        for name in imported {
+            if !existing.contains(&name) {
+                changes.created.push(name);
+            }
+        }
+
+        changes
+    }
+
+    fn total(&self) -> usize {
+        self.created.len() + self.updated.len() + self.kept
+    }
+
+    fn is_empty(&self) -> bool {
+        self.created.is_empty()
+            && self.updated.is_empty()
+            && self.deleted.is_empty()
+            && self.skipped.is_empty()
+    }
+
+    fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        for (verb, names) in [
+            ("create", &self.created),
+            ("update", &self.updated),
+            ("delete", &self.deleted),
+            ("skip", &self.skipped),
+        ] {
+            if !names.is_empty() {
+                parts.push(format!("{} {}", verb, names.join(", ")));
+            }
+        }
+
+        parts.join("; ")
+    }
+}
+
+pub struct PlanSection {
+    pub title: &'static str,
+    pub changes: Changes,
+    pub limit: usize,
+}
+
+/// A preview of what an import would change.
+pub struct ImportPlan {
+    pub sections: Vec<PlanSection>,
+}
+
+impl ImportPlan {
+    pub fn check_limits(&self) -> Result<(), String> {
+        for section in &self.sections {
+            if section.changes.total() > section.limit {
+                return Err(format!(
+                    "You can only have up to {} {}, but this would leave you with {}.",
+                    section.limit,
+                    section.title.to_lowercase(),
+                    section.changes.total(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sections.iter().all(|s| s.changes.is_empty())
+    }
+
+    pub fn describe(&self) -> String {
+        self.sections
+            .iter()
+            .filter(|s| !s.changes.is_empty())
+            .map(|s| format!("**{}**: {}", s.title, s.changes.describe()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The changes to record in the audit log for this import.
+    pub fn audit_changes(&self, replace: bool) -> serde_json::Value {
+        let mut changes = serde_json::Map::new();
+        let mode = if replace { "replace" } else { "merge" };
+        changes.insert("mode".to_string(), serde_json::json!({ "new": mode }));
+        for section in &self.sections {
+            if !section.changes.is_empty() {
+                changes.insert(
+                    section.title.to_lowercase(),
+                    serde_json::json!({ "new": section.changes.describe() }),
+                );
+            }
+        }
+
+        serde_json::Value::Object(changes)
+    }
+}
+
+/// The result of applying a configuration. The caches should only be updated
+/// once the transaction it was applied in is committed.
+pub struct AppliedConfig {
+    pub report: ImportReport,
+    autostar_channel_ids: Vec<i64>,
+    deleted_channel_ids: Vec<i64>,
+}
+
+impl AppliedConfig {
+    pub fn update_cache(&self, bot: &StarboardBot, guild_id: Id<GuildMarker>) {
+        bot.cache.guild_vote_emojis.remove(&guild_id.get_i64());
+        bot.cache.filter_groups.invalidate_all();
+        for &channel_id in &self.deleted_channel_ids {
+            if !self.autostar_channel_ids.contains(&channel_id) {
+                bot.cache.autostar_channel_ids.remove(&channel_id.into_id());
+            }
+        }
+        for &channel_id in &self.autostar_channel_ids {
+            bot.cache.autostar_channel_ids.insert(channel_id.into_id());
+        }
+    }
+}
+
+fn quoted(name: &str) -> String {
+    format!("'{name}'")
+}
+
+fn role_mention(role_id: i64) -> String {
+    format!("<@&{role_id}>")
+}
+
+fn check_unique<'a>(kind: &str, names: impl Iterator<Item = &'a String>) -> Result<(), String> {
+    let mut seen = HashSet::new();
+    for name in names {
+        if !seen.insert(name) {
+            return Err(format!("There is more than one {kind} named '{name}'."));
+        }
+    }
+
+    Ok(())
+}
+
+fn resolve_exclusive_group(
+    ids: &HashMap<String, i32>,
+    name: Option<String>,
+    report: &mut ImportReport,
+) -> Option<i32> {
+    let name = name?;
+    let id = ids.get(&name).copied();
+    if id.is_none() {
+        report
+            .skipped
+            .push(format!("Exclusive group '{name}' doesn't exist."));
+    }
+
+    id
+}
+
+fn resolve_filter_groups(
+    ids: &HashMap<String, i32>,
+    names: Vec<String>,
+    report: &mut ImportReport,
+) -> Vec<i32> {
+    names
+        .into_iter()
+        .filter_map(|name| match ids.get(&name) {
+            Some(id) => Some(*id),
+            None => {
+                report.missing_groups.insert(name);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Leaves out emojis that don't exist in the server being imported into.
+fn resolve_emojis(
+    bot: &StarboardBot,
+    guild_id: Id<GuildMarker>,
+    emojis: Vec<String>,
+    report: &mut ImportReport,
+) -> Vec<String> {
+    emojis
+        .into_iter()
+        .filter(|emoji| {
+            let usable = emoji_usable(bot, guild_id, emoji);
+            if !usable {
+                report.missing_emojis.insert(emoji.clone());
+            }
+            usable
+        })
+        .collect()
+}
+
+/// Leaves out any emojis in the settings of a starboard or override that
+/// don't exist in the server being imported into.
+fn resolve_settings_emojis(
+    bot: &StarboardBot,
+    guild_id: Id<GuildMarker>,
+    settings: &mut OverrideValues,
+    report: &mut ImportReport,
+) {
+    if let Some(Some(emoji)) = &settings.display_emoji {
+        if !emoji_usable(bot, guild_id, emoji) {
+            report.missing_emojis.insert(emoji.clone());
+            settings.display_emoji = None;
+        }
+    }
+    if let Some(emojis) = settings.upvote_emojis.take() {
+        settings.upvote_emojis = Some(resolve_emojis(bot, guild_id, emojis, report));
+    }
+    if let Some(emojis) = settings.downvote_emojis.take() {
+        settings.downvote_emojis = Some(resolve_emojis(bot, guild_id, emojis, report));
+    }
+    if let Some(serde_json::Value::Object(points)) = &mut settings.emoji_points {
+        points.retain(|emoji, _| {
+            let usable = emoji_usable(bot, guild_id, emoji);
+            if !usable {
+                report.missing_emojis.insert(emoji.clone());
+            }
+            usable
+        });
+    }
+}
+
+/// Converts the settings of a starboard or override so that they can be
+/// exported, replacing the exclusive group id with its name.
+fn export_settings(
+    mut settings: OverrideValues,
+    exclusive_groups: &HashMap<i32, String>,
+) -> (OverrideValues, Option<String>) {
+    let name = match settings.exclusive_group {
+        Some(Some(id)) => {
+            settings.exclusive_group = None;
+            exclusive_groups.get(&id).cloned()
+        }
+        _ => None,
+    };
+
+    (settings, name)
+}
+
+impl GuildConfig {
+    pub async fn export(bot: &StarboardBot, guild_id: i64) -> StarboardResult<Self> {
+        let guild = DbGuild::get(&bot.pool, guild_id).await?;
+
+        let exclusive_groups: HashMap<_, _> = ExclusiveGroup::list_by_guild(&bot.pool, guild_id)
+            .await?
+            .into_iter()
+            .map(|g| (g.id, g.name))
+            .collect();
+
+        let groups = FilterGroup::list_by_guild(&bot.pool, guild_id).await?;
+        let group_names: HashMap<_, _> = groups.iter().map(|g| (g.id, g.name.clone())).collect();
+        let mut filter_groups = Vec::new();
+        for group in &groups {
+            let filters = Filter::list_by_filter(&bot.pool, group.id).await?;
+            filter_groups.push(ExportedFilterGroup::new(group, &filters, &group_names));
+        }
+
+        let mut starboards = Vec::new();
+        let mut starboard_names = HashMap::new();
+        for sb in Starboard::list_by_guild(&bot.pool, guild_id).await? {
+            starboard_names.insert(sb.id, sb.name.clone());
+
+            let mut overrides = Vec::new();
+            for ov in StarboardOverride::list_by_starboard(&bot.pool, sb.id).await? {
+                let (settings, exclusive_group) =
+                    export_settings(ov.get_overrides()?, &exclusive_groups);
+                overrides.push(ExportedOverride {
+                    name: ov.name,
+                    channel_ids: ov.channel_ids,
+                    settings,
+                    exclusive_group,
+                });
+            }
+
+            let filter_groups = StarboardFilterGroup::list_by_starboard(&bot.pool, sb.id)
+                .await?
+                .into_iter()
+                .filter_map(|g| group_names.get(&g.filter_group_id).cloned())
+                .collect();
+
+            let private = sb.settings.private;
+            let xp_multiplier = sb.settings.xp_multiplier;
+            let settings = OverrideValues::from_settings(sb.settings);
+            let (settings, exclusive_group) = export_settings(settings, &exclusive_groups);
+
+            starboards.push(ExportedStarboard {
+                name: sb.name,
+                channel_id: sb.channel_id,
+                settings,
+                private: Some(private),
+                xp_multiplier: Some(xp_multiplier),
+                exclusive_group,
+                filter_groups,
+                overrides,
+            });
+        }
+
+        let mut autostar_channels = Vec::new();
+        for asc in AutoStarChannel::list_by_guild(&bot.pool, guild_id).await? {
+            let filter_groups =
+                AutostarChannelFilterGroup::list_by_autostar_channel(&bot.pool, asc.id)
+                    .await?
+                    .into_iter()
+                    .filter_map(|g| group_names.get(&g.filter_group_id).cloned())
+                    .collect();
+
+            autostar_channels.push(ExportedAutoStarChannel {
+                name: asc.name,
+                channel_id: asc.channel_id,
+                emojis: asc.emojis,
+                min_chars: asc.min_chars,
+                max_chars: asc.max_chars,
+                require_image: asc.require_image,
+                delete_invalid: asc.delete_invalid,
+                filter_groups,
+            });
+        }
+
+        let mut permroles = Vec::new();
+        for pr in PermRole::list_by_guild(&bot.pool, guild_id).await? {
+            let starboards = PermRoleStarboard::list_by_permrole(&bot.pool, pr.role_id)
+                .await?
+                .into_iter()
+                .filter_map(|pr_sb| {
+                    Some(ExportedPermRoleStarboard {
+                        starboard: starboard_names.get(&pr_sb.starboard_id)?.clone(),
+                        give_votes: pr_sb.give_votes,
+                        receive_votes: pr_sb.receive_votes,
+                        vote_weight: pr_sb.vote_weight,
+                    })
+                })
+                .collect();
+
+            permroles.push(ExportedPermRole {
+                role_id: pr.role_id,
+                obtain_xproles: pr.obtain_xproles,
+                give_votes: pr.give_votes,
+                receive_votes: pr.receive_votes,
+                vote_weight: pr.vote_weight,
+                starboards,
+            });
+        }
+
+        let xproles = XPRole::list_by_guild(&bot.pool, guild_id)
+            .await?
+            .into_iter()
+            .map(|r| ExportedXPRole {
+                role_id: r.role_id,
+                required: r.required.into(),
+            })
+            .collect();
+        let posroles = PosRole::list_by_guild(&bot.pool, guild_id)
+            .await?
+            .into_iter()
+            .map(|r| ExportedPosRole {
+                role_id: r.role_id,
+                max_members: r.max_members,
+            })
+            .collect();
+
+        let mut exclusive_groups: Vec<_> = exclusive_groups.into_values().collect();
+        exclusive_groups.sort();
+
+        Ok(Self {
+            version: EXPORT_VERSION,
+            xproles_use_season: guild.as_ref().is_some_and(|g| g.xproles_use_season),
+            posroles_use_season: guild.as_ref().is_some_and(|g| g.posroles_use_season),
+            exclusive_groups,
+            filter_groups,
+            starboards,
+            autostar_channels,
+            permroles,
+            xproles,
+            posroles,
+        })
+    }
+
+    /// Checks everything against the same limits as the commands that would
+    /// normally change it, and normalizes names.
+    pub fn validate(&mut self, premium: bool) -> Result<(), String> {
+        if self.version > EXPORT_VERSION {
+            return Err("This file was exported by a newer version of Starboard.".to_string());
+        }
+
+        for name in self.exclusive_groups.iter_mut() {
+            *name = validate_name(name)?;
+        }
+        check_unique("exclusive group", self.exclusive_groups.iter())?;
+
+        for group in self.filter_groups.iter_mut() {
+            group.name = validate_name(&group.name)?;
+            group
+                .validate(premium)
+                .map_err(|why| format!("Filter group '{}': {}", group.name, why))?;
+        }
+        check_unique("filter group", self.filter_groups.iter().map(|g| &g.name))?;
+
+        for sb in self.starboards.iter_mut() {
+            sb.name = validate_name(&sb.name)?;
+            let err = |why: String| format!("Starboard '{}': {}", sb.name, why);
+
+            validate_override_values(&sb.settings, premium).map_err(err)?;
+            if let Some(xp_multiplier) = sb.xp_multiplier {
+                validate_xp_multiplier(xp_multiplier).map_err(err)?;
+            }
+            if sb.overrides.len() as i64 > constants::MAX_OVERRIDES_PER_STARBOARD {
+                return Err(err(format!(
+                    "You can only have up to {} overrides per starboard.",
+                    constants::MAX_OVERRIDES_PER_STARBOARD
+                )));
+            }
+            if sb.filter_groups.len() > constants::MAX_FILTER_GROUPS {
+                return Err(err("Too many filter groups.".to_string()));
+            }
+
+            for ov in sb.overrides.iter_mut() {
+                ov.name = validate_name(&ov.name)?;
+                let err = |why: String| format!("Override '{}': {}", ov.name, why);
+
+                validate_override_values(&ov.settings, premium).map_err(err)?;
+                StarboardOverride::validate_channels(&ov.channel_ids).map_err(err)?;
+            }
+        }
+        check_unique("starboard", self.starboards.iter().map(|sb| &sb.name))?;
+        check_unique(
+            "override",
+            self.starboards
+                .iter()
+                .flat_map(|sb| sb.overrides.iter().map(|ov| &ov.name)),
+        )?;
+
+        for asc in self.autostar_channels.iter_mut() {
+            asc.name = validate_name(&asc.name)?;
+            if asc.filter_groups.len() > constants::MAX_FILTER_GROUPS {
+                return Err(format!(
+                    "Autostar channel '{}': Too many filter groups.",
+                    asc.name
+                ));
+            }
+        }
+        check_unique(
+            "autostar channel",
+            self.autostar_channels.iter().map(|asc| &asc.name),
+        )?;
+
+        let mut role_ids = HashSet::new();
+        for pr in &self.permroles {
+            if !role_ids.insert(pr.role_id) {
+                return Err(format!("The PermRole {} is listed twice.", pr.role_id));
+            }
+            let weights = [pr.vote_weight]
+                .into_iter()
+                .chain(pr.starboards.iter().map(|pr_sb| pr_sb.vote_weight));
+            for vote_weight in weights.flatten() {
+                validate_vote_weight(vote_weight.to_string())?;
+            }
+        }
+
+        for xpr in &self.xproles {
+            if !(1..=i16::MAX as i64).contains(&xpr.required) {
+                return Err(format!(
+                    "The required XP for XPRoles must be between 1 and {}.",
+                    i16::MAX
+                ));
+            }
+        }
+        for posr in &self.posroles {
+            if posr.max_members < 1 {
+                return Err("The max members for PosRoles must be at least 1.".to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Works out what importing this configuration would change, without
+    /// changing anything.
+    pub async fn plan(
+        &self,
+        bot: &StarboardBot,
+        guild_id: Id<GuildMarker>,
+        replace: bool,
+        premium: bool,
+    ) -> StarboardResult<ImportPlan> {
+        let guild_id_i64 = guild_id.get_i64();
+        let mut sections = Vec::new();
+
+        let existing = ExclusiveGroup::list_by_guild(&bot.pool, guild_id_i64)
+            .await?
+            .into_iter()
+            .map(|g| quoted(&g.name))
+            .collect();
+        let imported = self.exclusive_groups.iter().map(|n| quoted(n)).collect();
+        sections.push(PlanSection {
+            title: "Exclusive Groups",
+            changes: Changes::new(existing, imported, replace),
+            limit: constants::MAX_EXCLUSIVE_GROUPS as usize,
+        });
+
+        let existing = FilterGroup::list_by_guild(&bot.pool, guild_id_i64)
+            .await?
+            .into_iter()
+            .map(|g| quoted(&g.name))
+            .collect();
+        let imported = self.filter_groups.iter().map(|g| quoted(&g.name)).collect();
+        sections.push(PlanSection {
+            title: "Filter Groups",
+            changes: Changes::new(existing, imported, replace),
+            limit: constants::MAX_FILTER_GROUPS,
+        });
+
+        let existing: Vec<_> = Starboard::list_by_guild(&bot.pool, guild_id_i64)
+            .await?
+            .into_iter()
+            .map(|sb| quoted(&sb.name))
+            .collect();
+        let mut imported = Vec::new();
+        let mut skipped = Vec::new();
+        for sb in &self.starboards {
+            let name = quoted(&sb.name);
+            if existing.contains(&name) || channel_usable(bot, guild_id, sb.channel_id).await? {
+                imported.push(name);
+            } else {
+                skipped.push(name);
+            }
+        }
+        let mut changes = Changes::new(existing, imported, replace);
+        changes.skipped = skipped;
+        sections.push(PlanSection {
+            title: "Starboards",
+            changes,
+            limit: if premium {
+                constants::MAX_PREM_STARBOARDS
+            } else {
+                constants::MAX_STARBOARDS
+            } as usize,
+        });
+
+        let existing: Vec<_> = AutoStarChannel::list_by_guild(&bot.pool, guild_id_i64)
+            .await?
+            .into_iter()
+            .map(|asc| quoted(&asc.name))
+            .collect();
+        let mut imported = Vec::new();
+        let mut skipped = Vec::new();
+        for asc in &self.autostar_channels {
+            let name = quoted(&asc.name);
+            if existing.contains(&name) || channel_usable(bot, guild_id, asc.channel_id).await? {
+                imported.push(name);
+            } else {
+                skipped.push(name);
+            }
+        }
+        let mut changes = Changes::new(existing, imported, replace);
+        changes.skipped = skipped;
+        sections.push(PlanSection {
+            title: "Autostar Channels",
+            changes,
+            limit: if premium {
+                constants::MAX_PREM_AUTOSTAR
+            } else {
+                constants::MAX_AUTOSTAR
+            } as usize,
+        });
+
+        // award roles can't be managed roles or @everyone
+        let role_changes = |existing: Vec<i64>, imported: Vec<i64>, award: bool| {
+            let (imported, skipped): (Vec<_>, Vec<_>) = imported.into_iter().partition(|id| {
+                if award {
+                    award_role_usable(bot, guild_id, *id)
+                } else {
+                    role_exists(bot, guild_id, *id)
+                }
+            });
+            let mut changes = Changes::new(
+                existing.into_iter().map(role_mention).collect(),
+                imported.into_iter().map(role_mention).collect(),
+                replace,
+            );
+            changes.skipped = skipped.into_iter().map(role_mention).collect();
+            changes
+        };
+
+        let existing = PermRole::list_by_guild(&bot.pool, guild_id_i64)
+            .await?
+            .into_iter()
+            .map(|pr| pr.role_id)
+            .collect();
+        let imported = self.permroles.iter().map(|pr| pr.role_id).collect();
+        sections.push(PlanSection {
+            title: "PermRoles",
+            changes: role_changes(existing, imported, false),
+            limit: constants::MAX_PERMROLES as usize,
+        });
+
+        // award roles can only be changed by premium servers
+        let (xproles, posroles) = if premium {
+            let existing = XPRole::list_by_guild(&bot.pool, guild_id_i64)
+                .await?
+                .into_iter()
+                .map(|r| r.role_id)
+                .collect();
+            let imported = self.xproles.iter().map(|r| r.role_id).collect();
+            let xproles = role_changes(existing, imported, true);
+
+            let existing = PosRole::list_by_guild(&bot.pool, guild_id_i64)
+                .await?
+                .into_iter()
+                .map(|r| r.role_id)
+                .collect();
+            let imported = self.posroles.iter().map(|r| r.role_id).collect();
+            (xproles, role_changes(existing, imported, true))
+        } else {
+            let skipped = |ids: Vec<i64>| Changes {
+                skipped: ids.into_iter().map(role_mention).collect(),
+                ..Default::default()
+            };
+            (
+                skipped(self.xproles.iter().map(|r| r.role_id).collect()),
+                skipped(self.posroles.iter().map(|r| r.role_id).collect()),
+            )
+        };
+        sections.push(PlanSection {
+            title: "XPRoles",
+            changes: xproles,
+            limit: constants::MAX_XPROLES as usize,
+        });
+        sections.push(PlanSection {
+            title: "PosRoles",
+            changes: posroles,
+            limit: constants::MAX_POSROLES as usize,
+        });
+
+        Ok(ImportPlan { sections })
+    }
+
+    /// Applies the configuration in the transaction `con`. If `replace` is
+    /// true, anything that isn't in the configuration is deleted. Existing
+    /// starboards are merged as described on `ExportedStarboard`. Returns an
+    /// error message if the result would be invalid, in which case the
+    /// transaction shouldn't be committed.
+    pub async fn apply(
+        self,
+        bot: &StarboardBot,
+        con: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        guild_id: Id<GuildMarker>,
+        replace: bool,
+        premium: bool,
+    ) -> StarboardResult<Result<AppliedConfig, String>> {
+        let guild_id_i64 = guild_id.get_i64();
+        let mut report = ImportReport::default();
+
+        DbGuild::set_xproles_use_season(&mut *con, guild_id_i64, self.xproles_use_season).await?;
+        DbGuild::set_posroles_use_season(&mut *con, guild_id_i64, self.posroles_use_season).await?;
+
+        // exclusive groups
+        if replace {
+            for group in ExclusiveGroup::list_by_guild(&bot.pool, guild_id_i64).await? {
+                if !self.exclusive_groups.contains(&group.name) {
+                    ExclusiveGroup::delete(&mut *con, &group.name, guild_id_i64).await?;
+                }
+            }
+        }
+        let mut exclusive_group_ids = HashMap::new();
+        for name in &self.exclusive_groups {
+            ExclusiveGroup::create(&mut *con, name, guild_id_i64).await?;
+            let group = ExclusiveGroup::get_by_name(&mut *con, guild_id_i64, name).await?;
+            if let Some(group) = group {
+                exclusive_group_ids.insert(name.clone(), group.id);
+            }
+        }
+
+        // filter groups
+        if replace {
+            for group in FilterGroup::list_by_guild(&mut *con, guild_id_i64).await? {
+                if !self.filter_groups.iter().any(|g| g.name == group.name) {
+                    Filter::remove_group_references(&mut *con, group.id).await?;
+                    FilterGroup::delete(&mut *con, guild_id_i64, &group.name).await?;
+                }
+            }
+        }
+        for group in &self.filter_groups {
+            FilterGroup::create(&mut *con, guild_id_i64, &group.name).await?;
+        }
+        let group_ids: HashMap<_, _> = FilterGroup::list_by_guild(&mut *con, guild_id_i64)
+            .await?
+            .into_iter()
+            .map(|g| (g.name, g.id))
+            .collect();
+        let mut imported_group_ids = Vec::new();
+        for group in self.filter_groups {
+            let id = group_ids[&group.name];
+            imported_group_ids.push(id);
+
+            // a group can't reference itself, so leave it out
+            let mut other_ids = group_ids.clone();
+            other_ids.remove(&group.name);
+
+            Filter::delete_by_group(&mut *con, id).await?;
+            group
+                .import_filters(bot, con, guild_id, id, &other_ids, &mut report)
+                .await?;
+        }
+        let guild_filters = Filter::list_by_guild(&mut *con, guild_id_i64).await?;
+        for filter in &guild_filters {
+            if !imported_group_ids.contains(&filter.filter_group_id) {
+                continue;
+            }
+            if let Err(why) = validate_group_references(filter, &guild_filters) {
+                return Ok(Err(why));
+            }
+        }
+
+        // starboards
+        if replace {
+            for sb in Starboard::list_by_guild(&bot.pool, guild_id_i64).await? {
+                if !self.starboards.iter().any(|s| s.name == sb.name) {
+                    Starboard::delete(&mut *con, &sb.name, guild_id_i64).await?;
+                }
+            }
+        }
+        for exported in self.starboards {
+            let mut sb = Starboard::get_by_name(&mut *con, &exported.name, guild_id_i64).await?;
+            if sb.is_none() {
+                if !channel_usable(bot, guild_id, exported.channel_id).await? {
+                    report.missing_channels.insert(exported.channel_id);
+                    report.skipped.push(format!(
+                        "Starboard '{}' was left out because its channel doesn't exist or can't have a starboard.",
+                        exported.name
+                    ));
+                    continue;
+                }
+                sb =
+                    Starboard::create(&mut *con, &exported.name, exported.channel_id, guild_id_i64)
+                        .await?;
+            }
+            let Some(mut sb) = sb else {
+                continue;
+            };
+            if sb.channel_id != exported.channel_id {
+                report.skipped.push(format!(
+                    "Starboard '{}' is in <#{}>, so it wasn't moved to <#{}>. Use `/starboards move` to move it.",
+                    sb.name, sb.channel_id, exported.channel_id
+                ));
+            }
+
+            let mut settings = exported.settings;
+            resolve_settings_emojis(bot, guild_id, &mut settings, &mut report);
+            settings.exclusive_group = Some(resolve_exclusive_group(
+                &exclusive_group_ids,
+                exported.exclusive_group,
+                &mut report,
+            ));
+            call_with_override_settings!(update_from_override, sb.settings, settings);
+            if let Some(private) = exported.private {
+                sb.settings.private = private;
+            }
+            if let Some(xp_multiplier) = exported.xp_multiplier {
+                sb.settings.xp_multiplier = xp_multiplier;
+            }
+            let sb_id = sb.id;
+            sb.update_settings(&mut *con).await?;
+
+            for ov in StarboardOverride::list_by_starboard(&mut *con, sb_id).await? {
+                StarboardOverride::delete(&mut *con, guild_id_i64, &ov.name).await?;
+            }
+            for exported_ov in exported.overrides {
+                let mut channel_ids = Vec::new();
+                for channel_id in exported_ov.channel_ids {
+                    if channel_exists(bot, guild_id, channel_id).await? {
+                        channel_ids.push(channel_id);
+                    } else {
+                        report.missing_channels.insert(channel_id);
+                    }
+                }
+
+                let ov =
+                    StarboardOverride::create(&mut *con, guild_id_i64, &exported_ov.name, sb_id)
+                        .await?;
+                let Some(ov) = ov else {
+                    report.skipped.push(format!(
+                        "Override '{}' was left out because another starboard has an override with the same name.",
+                        exported_ov.name
+                    ));
+                    continue;
+                };
+
+                let mut settings = exported_ov.settings;
+                resolve_settings_emojis(bot, guild_id, &mut settings, &mut report);
+                if let Some(name) = exported_ov.exclusive_group {
+                    settings.exclusive_group =
+                        resolve_exclusive_group(&exclusive_group_ids, Some(name), &mut report)
+                            .map(Some);
+                }
+                StarboardOverride::set_channels(&mut *con, guild_id_i64, &ov.name, &channel_ids)
+                    .await?;
+                StarboardOverride::update_settings(&mut *con, ov.id, settings).await?;
+            }
+
+            for group in StarboardFilterGroup::list_by_starboard(&mut *con, sb_id).await? {
+                StarboardFilterGroup::delete(&mut *con, group.filter_group_id, sb_id).await?;
+            }
+            for id in resolve_filter_groups(&group_ids, exported.filter_groups, &mut report) {
+                StarboardFilterGroup::create(&mut *con, id, sb_id).await?;
+            }
+        }
+
+        // autostar channels
+        let mut deleted_channel_ids = Vec::new();
+        if replace {
+            for asc in AutoStarChannel::list_by_guild(&bot.pool, guild_id_i64).await? {
+                if !self.autostar_channels.iter().any(|a| a.name == asc.name) {
+                    AutoStarChannel::delete(&mut *con, &asc.name, guild_id_i64).await?;
+                    deleted_channel_ids.push(asc.channel_id);
+                }
+            }
+        }
+        let mut autostar_channel_ids = Vec::new();
+        for exported in self.autostar_channels {
+            let mut asc =
+                AutoStarChannel::get_by_name(&mut *con, &exported.name, guild_id_i64).await?;
+            if asc.is_none() {
+                if !channel_usable(bot, guild_id, exported.channel_id).await? {
+                    report.missing_channels.insert(exported.channel_id);
+                    report.skipped.push(format!(
+                        "Autostar channel '{}' was left out because its channel doesn't exist or can't be an autostar channel.",
+                        exported.name
+                    ));
+                    continue;
+                }
+                asc = AutoStarChannel::create(
+                    &mut *con,
+                    &exported.name,
+                    exported.channel_id,
+                    guild_id_i64,
+                )
+                .await?;
+            }
+            let Some(mut asc) = asc else {
+                continue;
+            };
+
+            let err = |why: String| format!("Autostar channel '{}': {}", exported.name, why);
+            let emojis = resolve_emojis(bot, guild_id, exported.emojis, &mut report);
+            if let Err(why) = asc.set_emojis(emojis, premium) {
+                return Ok(Err(err(why)));
+            }
+            // clear max-chars first, so that min-chars can't conflict with the old value
+            asc.max_chars = None;
+            if let Err(why) = asc.set_min_chars(exported.min_chars) {
+                return Ok(Err(err(why)));
+            }
+            if let Err(why) = asc.set_max_chars(exported.max_chars) {
+                return Ok(Err(err(why)));
+            }
+            asc.require_image = exported.require_image;
+            asc.delete_invalid = exported.delete_invalid;
+
+            let asc_id = asc.id;
+            autostar_channel_ids.push(asc.channel_id);
+            asc.update_settings(&mut *con).await?;
+
+            let groups =
+                AutostarChannelFilterGroup::list_by_autostar_channel(&mut *con, asc_id).await?;
+            for group in groups {
+                AutostarChannelFilterGroup::delete(&mut *con, group.filter_group_id, asc_id)
+                    .await?;
+            }
+            for id in resolve_filter_groups(&group_ids, exported.filter_groups, &mut report) {
+                AutostarChannelFilterGroup::create(&mut *con, id, asc_id).await?;
+            }
+        }
+
+        // permroles
+        if replace {
+            for pr in PermRole::list_by_guild(&bot.pool, guild_id_i64).await? {
+                if !self.permroles.iter().any(|p| p.role_id == pr.role_id) {
+                    PermRole::delete(&mut *con, pr.role_id).await?;
+                }
+            }
+        }
+        for exported in self.permroles {
+            if !role_exists(bot, guild_id, exported.role_id) {
+                report.missing_roles.insert(exported.role_id);
+                continue;
+            }
+
+            PermRole::create(&mut *con, exported.role_id, guild_id_i64).await?;
+            let pr = PermRole {
+                role_id: exported.role_id,
+                guild_id: guild_id_i64,
+                obtain_xproles: exported.obtain_xproles,
+                give_votes: exported.give_votes,
+                receive_votes: exported.receive_votes,
+                vote_weight: exported.vote_weight,
+            };
+            pr.update(&mut *con).await?;
+
+            for pr_sb in PermRoleStarboard::list_by_permrole(&mut *con, pr.role_id).await? {
+                PermRoleStarboard::delete(&mut *con, pr.role_id, pr_sb.starboard_id).await?;
+            }
+            for exported_sb in exported.starboards {
+                let sb =
+                    Starboard::get_by_name(&mut *con, &exported_sb.starboard, guild_id_i64).await?;
+                let Some(sb) = sb else {
+                    report.skipped.push(format!(
+                        "PermRole settings for starboard '{}' were left out because it doesn't exist.",
+                        exported_sb.starboard
+                    ));
+                    continue;
+                };
+
+                PermRoleStarboard::create(&mut *con, pr.role_id, sb.id).await?;
+                let pr_sb = PermRoleStarboard {
+                    permrole_id: pr.role_id,
+                    starboard_id: sb.id,
+                    give_votes: exported_sb.give_votes,
+                    receive_votes: exported_sb.receive_votes,
+                    vote_weight: exported_sb.vote_weight,
+                };
+                pr_sb.update(&mut *con).await?;
+            }
+        }
+
+        // award roles
+        if !premium {
+            if !self.xproles.is_empty() || !self.posroles.is_empty() {
+                report.skipped.push(
+                    "XPRoles and PosRoles were left out because they require premium.".to_string(),
+                );
+            }
+        } else {
+            if replace {
+                for xpr in XPRole::list_by_guild(&bot.pool, guild_id_i64).await? {
+                    if !self.xproles.iter().any(|r| r.role_id == xpr.role_id) {
+                        XPRole::delete(&mut *con, xpr.role_id).await?;
+                    }
+                }
+                for posr in PosRole::list_by_guild(&bot.pool, guild_id_i64).await? {
+                    if !self.posroles.iter().any(|r| r.role_id == posr.role_id) {
+                        PosRole::delete(&mut *con, posr.role_id).await?;
+                    }
+                }
+            }
+
+            for xpr in self.xproles {
+                if !award_role_usable(bot, guild_id, xpr.role_id) {
+                    report.missing_roles.insert(xpr.role_id);
+                    continue;
+                }
+                // validated to fit in an i16
+                let required = xpr.required as i16;
+                let created =
+                    XPRole::create(&mut *con, xpr.role_id, guild_id_i64, required).await?;
+                if created.is_none() {
+                    XPRole::set_required(&mut *con, xpr.role_id, required).await?;
+                }
+            }
+            for posr in self.posroles {
+                if !award_role_usable(bot, guild_id, posr.role_id) {
+                    report.missing_roles.insert(posr.role_id);
+                    continue;
+                }
+                let created =
+                    PosRole::create(&mut *con, posr.role_id, guild_id_i64, posr.max_members)
+                        .await?;
+                if created.is_none() {
+                    PosRole::set_max_members(&mut *con, posr.role_id, posr.max_members).await?;
+                }
+            }
+        }
+
+        Ok(Ok(AppliedConfig {
+            report,
+            autostar_channel_ids,
+            deleted_channel_ids,
+        }))
+    }
+}
@@ -0,0 +1,181 @@
+//! Portable JSON versions of server settings, so they can be shared or
+//! copied between servers.
+
+pub mod filters;
+pub mod guild;
+
+use std::collections::BTreeSet;
+
+use twilight_model::{
+    channel::ChannelType,
+    id::{
+        marker::{EmojiMarker, GuildMarker},
+        Id,
+    },
+};
+
+use crate::{
+    client::bot::StarboardBot,
+    core::emoji::SimpleEmoji,
+    errors::StarboardResult,
+    utils::{id_as_i64::GetI64, into_id::IntoId},
+};
+
+/// Bumped whenever the exported format changes in a way older versions can't
+/// read.
+pub const EXPORT_VERSION: u32 = 1;
+
+/// The parts of an import that don't exist in the server it was imported
+/// into, and were left out.
+#[derive(Default)]
+pub struct ImportReport {
+    pub missing_roles: BTreeSet<i64>,
+    pub missing_channels: BTreeSet<i64>,
+    pub missing_groups: BTreeSet<String>,
+    pub missing_emojis: BTreeSet<String>,
+    /// Anything else that was left out, with the reason why.
+    pub skipped: Vec<String>,
+}
+
+impl ImportReport {
+    pub fn is_empty(&self) -> bool {
+        self.missing_roles.is_empty()
+            && self.missing_channels.is_empty()
+            && self.missing_groups.is_empty()
+            && self.missing_emojis.is_empty()
+            && self.skipped.is_empty()
+    }
+
+    pub fn describe(&self) -> String {
+        let mut lines = Vec::new();
+        if !self.missing_roles.is_empty() {
+            lines.push(format!(
+                "Roles that don't exist in this server: {}",
+                join_ids(&self.missing_roles)
+            ));
+        }
+        if !self.missing_channels.is_empty() {
+            lines.push(format!(
+                "Channels that don't exist in this server: {}",
+                join_ids(&self.missing_channels)
+            ));
+        }
+        if !self.missing_groups.is_empty() {
+            lines.push(format!(
+                "Filter groups that don't exist in this server: {}",
+                self.missing_groups
+                    .iter()
+                    .map(|name| format!("'{name}'"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        if !self.missing_emojis.is_empty() {
+            lines.push(format!(
+                "Emojis that don't exist in this server: {}",
+                self.missing_emojis
+                    .iter()
+                    .map(|emoji| format!("`{emoji}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        lines.extend(self.skipped.iter().cloned());
+
+        lines.join("\n")
+    }
+}
+
+fn join_ids(ids: &BTreeSet<i64>) -> String {
+    ids.iter()
+        .map(|id| format!("`{id}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The kinds of channels that `/starboards create` and `/autostar create`
+/// allow. Threads are allowed if their parent channel is.
+const USABLE_CHANNEL_KINDS: &[ChannelType] = &[
+    ChannelType::GuildText,
+    ChannelType::GuildVoice,
+    ChannelType::GuildStageVoice,
+    ChannelType::GuildAnnouncement,
+    ChannelType::GuildForum,
+];
+
+/// Whether a role exists and can be used for award roles, like
+/// `/xproles setxp` and `/posroles set-max-members` require.
+pub fn award_role_usable(bot: &StarboardBot, guild_id: Id<GuildMarker>, role_id: i64) -> bool {
+    if role_id == guild_id.get_i64() {
+        return false;
+    }
+
+    bot.cache.guilds.with(&guild_id, |_, guild| {
+        guild
+            .as_ref()
+            .and_then(|guild| guild.roles.get(&role_id.into_id()))
+            .is_some_and(|role| !role.managed)
+    })
+}
+
+pub fn role_exists(bot: &StarboardBot, guild_id: Id<GuildMarker>, role_id: i64) -> bool {
+    bot.cache.guilds.with(&guild_id, |_, guild| {
+        guild
+            .as_ref()
+            .is_some_and(|guild| guild.roles.contains_key(&role_id.into_id()))
+    })
+}
+
+/// Whether a stored emoji is a valid unicode emoji or a custom emoji from
+/// this server, the same way emojis typed into commands are checked.
+pub fn emoji_usable(bot: &StarboardBot, guild_id: Id<GuildMarker>, emoji: &str) -> bool {
+    let input = match emoji.parse::<Id<EmojiMarker>>() {
+        Ok(emoji_id) => format!("<:emoji:{emoji_id}>"),
+        Err(_) => emoji.to_string(),
+    };
+
+    SimpleEmoji::from_user_input(&input, bot, guild_id)
+        .first()
+        .is_some_and(|parsed| parsed == &emoji.to_string())
+}
+
+pub async fn channel_exists(
+    bot: &StarboardBot,
+    guild_id: Id<GuildMarker>,
+    channel_id: i64,
+) -> StarboardResult<bool> {
+    if channel_id <= 0 {
+        return Ok(false);
+    }
+
+    bot.cache
+        .guild_has_channel(bot, guild_id, channel_id.into_id())
+        .await
+}
+
+/// Whether a channel exists and is a kind that starboards and autostar
+/// channels can be created in.
+pub async fn channel_usable(
+    bot: &StarboardBot,
+    guild_id: Id<GuildMarker>,
+    channel_id: i64,
+) -> StarboardResult<bool> {
+    if channel_id <= 0 {
+        return Ok(false);
+    }
+
+    let parent_id = bot
+        .cache
+        .fog_parent_channel_id(bot, guild_id, channel_id.into_id())
+        .await?;
+    let Some(parent_id) = parent_id else {
+        return Ok(false);
+    };
+
+    Ok(bot.cache.guilds.with(&guild_id, |_, guild| {
+        guild
+            .as_ref()
+            .and_then(|guild| guild.channels.get(&parent_id))
+            .is_some_and(|channel| USABLE_CHANNEL_KINDS.contains(&channel.kind))
+    }))
+}
@@ -1,16 +1,28 @@
-use std::sync::Arc;
-
-use twilight_model::id::{
-    marker::{ChannelMarker, GuildMarker, MessageMarker, UserMarker},
-    Id,
+use std::{collections::HashMap, sync::Arc};
+
+use futures::{future::BoxFuture, FutureExt};
+use twilight_model::{
+    id::{
+        marker::{ChannelMarker, GuildMarker, MessageMarker, UserMarker},
+        Id,
+    },
+    util::Timestamp,
 };
 
 use crate::{
     cache::{models::member::CachedMember, MessageResult},
     client::bot::StarboardBot,
+    constants,
     database::{models::filter::Filter, DbUser},
     errors::StarboardResult,
-    utils::{id_as_i64::GetI64, snowflake_age::SnowflakeAge},
+    utils::{
+        id_as_i64::GetI64,
+        message_content::{
+            attachment_extension, attachment_type, contains_invite, domain_in, link_domains,
+        },
+        safe_regex::regex_haystack,
+        snowflake_age::SnowflakeAge,
+    },
 };
 
 use super::premium::is_premium::is_guild_premium;
@@ -35,6 +47,31 @@ fn has_any_role(user_roles: &[i64], required: &[i64]) -> bool {
     false
 }
 
+/// How long ago a member joined, in seconds.
+fn joined_age(joined_at: Timestamp) -> u64 {
+    let age = chrono::Utc::now().timestamp() - joined_at.as_secs();
+    age.max(0) as u64
+}
+
+/// The result of a single filter, as recorded by `FilterEvaluater::trace`.
+pub struct CheckTrace {
+    pub filter_group_id: i32,
+    pub position: i16,
+    /// The first condition that failed, or `None` if the filter passed.
+    pub failed_condition: Option<&'static str>,
+    /// Whether the filter passed and ended the evaluation with instant-pass.
+    pub instant_pass: bool,
+    /// Whether the filter failed and ended the evaluation with instant-fail.
+    pub instant_fail: bool,
+}
+
+pub struct FilterTrace {
+    pub passed: bool,
+    /// Every filter that was checked, in order. Filters after one that
+    /// instantly passed or failed are never checked.
+    pub checks: Vec<CheckTrace>,
+}
+
 pub struct FilterEvaluater<'a> {
     bot: &'a StarboardBot,
     filter_group_ids: Vec<i32>,
@@ -51,7 +88,11 @@ pub struct FilterEvaluater<'a> {
     user_is_bot: Option<Option<bool>>,
     voter: Option<Option<Arc<CachedMember>>>,
     message: Option<MessageResult>,
-    filters: Option<Arc<Vec<Vec<Filter>>>>,
+    filters: Option<Vec<Arc<Vec<Filter>>>>,
+    /// Results of the filter groups referenced by other filters.
+    group_results: HashMap<i32, bool>,
+    /// The referenced filter groups currently being evaluated.
+    evaluating: Vec<i32>,
 }
 
 impl<'a> FilterEvaluater<'a> {
@@ -77,42 +118,95 @@ impl<'a> FilterEvaluater<'a> {
             voter: None,
             message: None,
             filters: None,
+            group_results: HashMap::new(),
+            evaluating: Vec::new(),
         }
     }
 
-    async fn evaluate_check(&mut self, check: &Filter, premium: bool) -> StarboardResult<bool> {
+    /// Returns the name of the first condition of the filter that failed, or
+    /// `None` if every condition passed.
+    async fn evaluate_check(
+        &mut self,
+        check: &Filter,
+        premium: bool,
+    ) -> StarboardResult<Option<&'static str>> {
+        // other filter groups
+        if let Some(req) = &check.passes_all_of_groups {
+            for filter_group_id in req {
+                if !self.sub_group_status(*filter_group_id, premium).await? {
+                    return Ok(Some("passes-all-of-groups"));
+                }
+            }
+        }
+
+        if let Some(req) = &check.passes_some_of_groups {
+            let mut any_passed = false;
+            for filter_group_id in req {
+                if self.sub_group_status(*filter_group_id, premium).await? {
+                    any_passed = true;
+                    break;
+                }
+            }
+            if !any_passed {
+                return Ok(Some("passes-some-of-groups"));
+            }
+        }
+
+        if let Some(req) = &check.passes_none_of_groups {
+            for filter_group_id in req {
+                if self.sub_group_status(*filter_group_id, premium).await? {
+                    return Ok(Some("passes-none-of-groups"));
+                }
+            }
+        }
+
         // user context
         if let Some(req) = check.user_is_bot {
             let Some(is_bot) = self.get_user_is_bot().await? else {
-                return Ok(false);
+                return Ok(Some("user-is-bot"));
             };
 
             if is_bot != req {
-                return Ok(false);
+                return Ok(Some("user-is-bot"));
             }
         }
 
         if let Some(req) = &check.user_has_all_of {
             if !has_all_roles(&self.get_user_roles().await?, req) {
-                return Ok(false);
+                return Ok(Some("user-has-all-of"));
             }
         }
 
         if let Some(req) = &check.user_has_some_of {
             if !has_any_role(&self.get_user_roles().await?, req) {
-                return Ok(false);
+                return Ok(Some("user-has-some-of"));
             }
         }
 
         if let Some(req) = &check.user_missing_all_of {
             if has_any_role(&self.get_user_roles().await?, req) {
-                return Ok(false);
+                return Ok(Some("user-missing-all-of"));
             }
         }
 
         if let Some(req) = &check.user_missing_some_of {
             if has_all_roles(&self.get_user_roles().await?, req) {
-                return Ok(false);
+                return Ok(Some("user-missing-some-of"));
+            }
+        }
+
+        if let Some(req) = check.user_account_older_than {
+            if self.user_id.age().as_secs() < req as u64 {
+                return Ok(Some("user-account-older-than"));
+            }
+        }
+
+        if let Some(req) = check.user_joined_older_than {
+            let Some(user) = self.get_user().await? else {
+                return Ok(Some("user-joined-older-than"));
+            };
+            if joined_age(user.joined_at) < req as u64 {
+                return Ok(Some("user-joined-older-than"));
             }
         }
 
@@ -120,10 +214,10 @@ impl<'a> FilterEvaluater<'a> {
         // if there's no channel_id or message_id, then we're not in the message context,
         // and as such should not attempt validation for it.
         let Some(mut channel_id) = self.channel_id else {
-            return Ok(true);
+            return Ok(None);
         };
         let Some(message_id) = self.message_id else {
-            return Ok(true);
+            return Ok(None);
         };
 
         // the initial message for a forum thread is treated as belonging to the forum
@@ -145,13 +239,13 @@ impl<'a> FilterEvaluater<'a> {
 
         if let Some(req) = &check.in_channel {
             if !req.contains(&channel_id.get_i64()) {
-                return Ok(false);
+                return Ok(Some("in-channel"));
             }
         }
 
         if let Some(req) = &check.not_in_channel {
             if req.contains(&channel_id.get_i64()) {
-                return Ok(false);
+                return Ok(Some("not-in-channel"));
             }
         }
 
@@ -169,7 +263,7 @@ impl<'a> FilterEvaluater<'a> {
                 }
             }
             if !any_valid {
-                return Ok(false);
+                return Ok(Some("in-channel-or-sub-channels"));
             }
         }
 
@@ -187,133 +281,320 @@ impl<'a> FilterEvaluater<'a> {
                 }
             }
             if any_valid {
-                return Ok(false);
+                return Ok(Some("not-in-channel-or-sub-channels"));
             }
         }
 
         if let Some(req) = check.min_length {
             let MessageResult::Ok(message) = self.get_message().await? else {
-                return Ok(false);
+                return Ok(Some("min-length"));
             };
             if message.content.len() < req as usize {
-                return Ok(false);
+                return Ok(Some("min-length"));
             }
         }
 
         if let Some(req) = check.max_length {
             let MessageResult::Ok(message) = self.get_message().await? else {
-                return Ok(false);
+                return Ok(Some("max-length"));
             };
             if message.content.len() > req as usize {
-                return Ok(false);
+                return Ok(Some("max-length"));
             }
         }
 
         if let Some(req) = check.min_attachments {
             let MessageResult::Ok(message) = self.get_message().await? else {
-                return Ok(false);
+                return Ok(Some("min-attachments"));
             };
             let count = message.attachments.len() + message.embeds.len();
             if count < req as usize {
-                return Ok(false);
+                return Ok(Some("min-attachments"));
             }
         }
 
         if let Some(req) = check.max_attachments {
             let MessageResult::Ok(message) = self.get_message().await? else {
-                return Ok(false);
+                return Ok(Some("max-attachments"));
             };
             let count = message.attachments.len() + message.embeds.len();
             if count > req as usize {
-                return Ok(false);
+                return Ok(Some("max-attachments"));
+            }
+        }
+
+        if let Some(req) = check.contains_link {
+            let MessageResult::Ok(message) = self.get_message().await? else {
+                return Ok(Some("contains-link"));
+            };
+            if link_domains(&message.content).is_empty() == req {
+                return Ok(Some("contains-link"));
+            }
+        }
+
+        if let Some(req) = check.contains_invite {
+            let MessageResult::Ok(message) = self.get_message().await? else {
+                return Ok(Some("contains-invite"));
+            };
+            if contains_invite(&message.content) != req {
+                return Ok(Some("contains-invite"));
+            }
+        }
+
+        if let Some(req) = &check.allowed_link_domains {
+            let MessageResult::Ok(message) = self.get_message().await? else {
+                return Ok(Some("allowed-link-domains"));
+            };
+            let domains = link_domains(&message.content);
+            if domains.iter().any(|domain| !domain_in(domain, req)) {
+                return Ok(Some("allowed-link-domains"));
+            }
+        }
+
+        if let Some(req) = &check.denied_link_domains {
+            let MessageResult::Ok(message) = self.get_message().await? else {
+                return Ok(Some("denied-link-domains"));
+            };
+            let domains = link_domains(&message.content);
+            if domains.iter().any(|domain| domain_in(domain, req)) {
+                return Ok(Some("denied-link-domains"));
+            }
+        }
+
+        if let Some(req) = check.mentions_users {
+            let MessageResult::Ok(message) = self.get_message().await? else {
+                return Ok(Some("mentions-users"));
+            };
+            if message.mentions.is_empty() == req {
+                return Ok(Some("mentions-users"));
+            }
+        }
+
+        if let Some(req) = check.mentions_roles {
+            let MessageResult::Ok(message) = self.get_message().await? else {
+                return Ok(Some("mentions-roles"));
+            };
+            if message.mention_roles.is_empty() == req {
+                return Ok(Some("mentions-roles"));
+            }
+        }
+
+        if let Some(req) = check.has_sticker {
+            let MessageResult::Ok(message) = self.get_message().await? else {
+                return Ok(Some("has-sticker"));
+            };
+            if message.stickers.is_empty() == req {
+                return Ok(Some("has-sticker"));
+            }
+        }
+
+        if let Some(req) = &check.attachment_types {
+            let MessageResult::Ok(message) = self.get_message().await? else {
+                return Ok(Some("attachment-types"));
+            };
+            let any_valid = message
+                .attachments
+                .iter()
+                .any(|a| req.iter().any(|t| t == attachment_type(a)));
+            if !any_valid {
+                return Ok(Some("attachment-types"));
+            }
+        }
+
+        if let Some(req) = &check.attachment_extensions {
+            let MessageResult::Ok(message) = self.get_message().await? else {
+                return Ok(Some("attachment-extensions"));
+            };
+            let any_valid = message
+                .attachments
+                .iter()
+                .filter_map(attachment_extension)
+                .any(|ext| req.contains(&ext));
+            if !any_valid {
+                return Ok(Some("attachment-extensions"));
+            }
+        }
+
+        if let Some(req) = check.is_reply {
+            let MessageResult::Ok(message) = self.get_message().await? else {
+                return Ok(Some("is-reply"));
+            };
+            if message.referenced_message.is_some() != req {
+                return Ok(Some("is-reply"));
             }
         }
 
         if premium {
             if let Some(req) = &check.matches {
                 let MessageResult::Ok(message) = self.get_message().await? else {
-                return Ok(false);
-            };
-
-                let re = regex::Regex::new(req)?;
-                if !re.is_match(&message.content) {
-                    return Ok(false);
+                    return Ok(Some("matches"));
+                };
+
+                let Some(re) = self.bot.cache.regex(req).await else {
+                    return Ok(Some("matches"));
+                };
+                if !re.is_match(regex_haystack(&message.content)) {
+                    return Ok(Some("matches"));
                 }
             }
 
             if let Some(req) = &check.not_matches {
                 let MessageResult::Ok(message) = self.get_message().await? else {
-                return Ok(false);
-            };
-
-                let re = regex::Regex::new(req)?;
-                if re.is_match(&message.content) {
-                    return Ok(false);
+                    return Ok(Some("not-matches"));
+                };
+
+                let Some(re) = self.bot.cache.regex(req).await else {
+                    return Ok(Some("not-matches"));
+                };
+                if re.is_match(regex_haystack(&message.content)) {
+                    return Ok(Some("not-matches"));
                 }
             }
         }
 
         // vote context
-        if self.voter_id.is_none() {
-            return Ok(true);
-        }
+        let Some(voter_id) = self.voter_id else {
+            return Ok(None);
+        };
 
         if let Some(req) = &check.voter_has_all_of {
             if !has_all_roles(&self.get_voter_roles().await?, req) {
-                return Ok(false);
+                return Ok(Some("voter-has-all-of"));
             }
         }
 
         if let Some(req) = &check.voter_has_some_of {
             if !has_any_role(&self.get_voter_roles().await?, req) {
-                return Ok(false);
+                return Ok(Some("voter-has-some-of"));
             }
         }
 
         if let Some(req) = &check.voter_missing_all_of {
             if has_any_role(&self.get_voter_roles().await?, req) {
-                return Ok(false);
+                return Ok(Some("voter-missing-all-of"));
             }
         }
 
         if let Some(req) = &check.voter_missing_some_of {
             if has_all_roles(&self.get_voter_roles().await?, req) {
-                return Ok(false);
+                return Ok(Some("voter-missing-some-of"));
+            }
+        }
+
+        if let Some(req) = check.voter_account_older_than {
+            if voter_id.age().as_secs() < req as u64 {
+                return Ok(Some("voter-account-older-than"));
+            }
+        }
+
+        if let Some(req) = check.voter_joined_older_than {
+            let Some(voter) = self.get_voter().await? else {
+                return Ok(Some("voter-joined-older-than"));
+            };
+            if joined_age(voter.joined_at) < req as u64 {
+                return Ok(Some("voter-joined-older-than"));
             }
         }
 
         let age_secs = message_id.age().as_secs();
         if let Some(req) = check.newer_than {
             if age_secs > req as u64 {
-                return Ok(false);
+                return Ok(Some("newer-than"));
             }
         }
         if let Some(req) = check.older_than {
             if age_secs < req as u64 {
-                return Ok(false);
+                return Ok(Some("older-than"));
             }
         }
 
-        Ok(true)
+        Ok(None)
+    }
+
+    /// Evaluates a filter group referenced by another filter. The context
+    /// doesn't change during an evaluation, so the results are remembered.
+    fn sub_group_status(
+        &mut self,
+        filter_group_id: i32,
+        premium: bool,
+    ) -> BoxFuture<'_, StarboardResult<bool>> {
+        async move {
+            if let Some(passed) = self.group_results.get(&filter_group_id) {
+                return Ok(*passed);
+            }
+
+            // cycles are rejected when filters are edited, but a group that
+            // references itself should never recurse forever
+            if self.evaluating.contains(&filter_group_id)
+                || self.evaluating.len() >= constants::MAX_FILTER_GROUP_DEPTH
+            {
+                return Ok(false);
+            }
+
+            let filters = self
+                .bot
+                .cache
+                .fog_filter_group(self.bot, filter_group_id)
+                .await?;
+
+            self.evaluating.push(filter_group_id);
+            let mut passed = true;
+            for check in filters.iter() {
+                let failed = self.evaluate_check(check, premium).await?.is_some();
+                if failed && check.instant_fail {
+                    passed = false;
+                    break;
+                }
+                if !failed && check.instant_pass {
+                    break;
+                }
+            }
+            self.evaluating.pop();
+
+            self.group_results.insert(filter_group_id, passed);
+            Ok(passed)
+        }
+        .boxed()
     }
 
     pub async fn status(&mut self) -> StarboardResult<bool> {
+        Ok(self.trace().await?.passed)
+    }
+
+    /// Evaluates the filters the same way as `status`, but records the result
+    /// of every filter that was checked.
+    pub async fn trace(&mut self) -> StarboardResult<FilterTrace> {
         let filters = self.get_filters().await?;
         let premium = is_guild_premium(self.bot, self.guild_id.get_i64(), true).await?;
-        let filters_iter: &[_] = &filters;
-        for filter in filters_iter {
-            for check in filter {
-                let status = self.evaluate_check(check, premium).await?;
-                if !status && check.instant_fail {
-                    return Ok(false);
+        let mut trace = FilterTrace {
+            passed: true,
+            checks: Vec::new(),
+        };
+
+        for filter in &filters {
+            for check in filter.iter() {
+                let failed_condition = self.evaluate_check(check, premium).await?;
+                let instant_fail = failed_condition.is_some() && check.instant_fail;
+                let instant_pass = failed_condition.is_none() && check.instant_pass;
+                trace.checks.push(CheckTrace {
+                    filter_group_id: check.filter_group_id,
+                    position: check.position,
+                    failed_condition,
+                    instant_pass,
+                    instant_fail,
+                });
+
+                if instant_fail {
+                    trace.passed = false;
+                    return Ok(trace);
                 }
-                if status && check.instant_pass {
-                    return Ok(true);
+                if instant_pass {
+                    return Ok(trace);
                 }
             }
         }
 
-        Ok(true)
+        Ok(trace)
     }
 
     // caching
@@ -415,22 +696,25 @@ impl<'a> FilterEvaluater<'a> {
         Ok(value)
     }
 
-    pub fn set_filters(&mut self, filters: Arc<Vec<Vec<Filter>>>) {
+    pub fn set_filters(&mut self, filters: Vec<Arc<Vec<Filter>>>) {
         self.filters.replace(filters);
     }
 
-    async fn get_filters(&mut self) -> StarboardResult<Arc<Vec<Vec<Filter>>>> {
+    async fn get_filters(&mut self) -> StarboardResult<Vec<Arc<Vec<Filter>>>> {
         if let Some(value) = self.filters.clone() {
             return Ok(value);
         }
 
         let mut filters = Vec::new();
         for filter_id in &self.filter_group_ids {
-            let checks = Filter::list_by_filter(&self.bot.pool, *filter_id).await?;
+            let checks = self
+                .bot
+                .cache
+                .fog_filter_group(self.bot, *filter_id)
+                .await?;
             filters.push(checks);
         }
 
-        let filters = Arc::new(filters);
         self.set_filters(filters.clone());
         Ok(filters)
     }
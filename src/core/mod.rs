@@ -1,6 +1,8 @@
+pub mod audit;
 pub mod autostar;
 pub mod embedder;
 pub mod emoji;
+pub mod export;
 pub mod filters;
 pub mod has_image;
 pub mod permroles;
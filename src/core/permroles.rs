@@ -14,6 +14,8 @@ pub struct Permissions {
     pub give_votes: bool,
     pub receive_votes: bool,
     pub obtain_xproles: bool,
+    /// How many points a vote from this user is worth.
+    pub vote_weight: f32,
 }
 
 impl Default for Permissions {
@@ -28,6 +30,7 @@ impl Permissions {
             give_votes: true,
             receive_votes: true,
             obtain_xproles: true,
+            vote_weight: 1.0,
         }
     }
 
@@ -77,6 +80,9 @@ impl Permissions {
             if let Some(val) = pr.obtain_xproles {
                 perms.obtain_xproles = val;
             }
+            if let Some(val) = pr.vote_weight {
+                perms.vote_weight = val;
+            }
 
             if let Some(sb_id) = starboard_id {
                 let pr_sb = PermRoleStarboard::get(&bot.pool, pr.role_id, sb_id).await?;
@@ -88,6 +94,9 @@ impl Permissions {
                 if let Some(val) = pr_sb.receive_votes {
                     perms.receive_votes = val;
                 }
+                if let Some(val) = pr_sb.vote_weight {
+                    perms.vote_weight = val;
+                }
             }
         }
 
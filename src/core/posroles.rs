@@ -9,7 +9,7 @@ use twilight_model::id::{
 use crate::{
     client::bot::StarboardBot,
     constants,
-    database::{DbMember, PosRole},
+    database::{DbGuild, DbMember, PosRole},
     errors::StarboardResult,
     utils::{id_as_i64::GetI64, into_id::IntoId},
 };
@@ -149,7 +149,14 @@ pub async fn update_posroles_for_guild(
     let lb_size = posroles.iter().map(|pr| pr.max_members).sum::<i32>() * 2;
     let lb_size = lb_size as usize;
     let mut leaderboard = Vec::new();
-    let mut stream = DbMember::stream_by_xp(&bot.pool, guild_id_i64);
+    let use_season = DbGuild::get(&bot.pool, guild_id_i64)
+        .await?
+        .is_some_and(|g| g.posroles_use_season);
+    let mut stream = if use_season {
+        DbMember::stream_by_season_xp(&bot.pool, guild_id_i64)
+    } else {
+        DbMember::stream_by_xp(&bot.pool, guild_id_i64)
+    };
 
     while let Some(member) = stream.try_next().await? {
         let obj = bot
@@ -0,0 +1,226 @@
+//! Scans the history of a channel and backfills a starboard with the messages
+//! that already have votes.
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use chrono::{TimeZone, Utc};
+use twilight_model::{
+    channel::Message,
+    id::{
+        marker::{ChannelMarker, GuildMarker, MessageMarker},
+        Id,
+    },
+};
+use twilight_util::snowflake::Snowflake;
+
+use crate::{
+    client::bot::StarboardBot,
+    constants,
+    core::{emoji::SimpleEmoji, premium::is_premium::is_guild_premium, stats::refresh_xp},
+    database::{DbUser, StarboardMessage},
+    errors::StarboardResult,
+    utils::{id_as_i64::GetI64, into_id::IntoId},
+};
+
+use super::{
+    config::StarboardConfig, handle::RefreshMessage, message::get_or_create_original,
+    recount::recount_votes_reaction,
+};
+
+#[derive(Default, Clone, Copy)]
+pub struct BackfillProgress {
+    /// How many messages in the channel have been looked at.
+    pub scanned: u64,
+    /// How many of the scanned messages have vote reactions.
+    pub found: u64,
+    /// How many of the found messages have had their votes counted.
+    pub processed: u64,
+    /// How many of the processed messages are now on the starboard.
+    pub posted: u64,
+    /// How many of the processed messages couldn't be refreshed because they
+    /// were already being updated. Their votes are still counted.
+    pub skipped: u64,
+    pub done_scanning: bool,
+}
+
+pub struct Backfill {
+    pub bot: Arc<StarboardBot>,
+    pub guild_id: Id<GuildMarker>,
+    pub channel_id: Id<ChannelMarker>,
+    /// The configs for every starboard in the channel.
+    pub configs: Vec<Arc<StarboardConfig>>,
+    /// The starboard being backfilled.
+    pub starboard_id: i32,
+    /// Only scan messages sent after this message.
+    pub after: Option<Id<MessageMarker>>,
+    /// Only scan messages sent before this message.
+    pub before: Option<Id<MessageMarker>>,
+    pub cancelled: Arc<AtomicBool>,
+    pub progress: BackfillProgress,
+}
+
+impl Backfill {
+    /// Run the backfill, calling `report` every so often with the current
+    /// progress. Returns false if the backfill was cancelled.
+    pub async fn run<F, Fut>(&mut self, mut report: F) -> StarboardResult<bool>
+    where
+        F: FnMut(BackfillProgress) -> Fut,
+        Fut: std::future::Future<Output = ()>,
+    {
+        let config = self
+            .configs
+            .iter()
+            .find(|c| c.starboard.id == self.starboard_id);
+        let Some(config) = config.cloned() else {
+            return Ok(true);
+        };
+        let emojis: Vec<_> = config
+            .resolved
+            .upvote_emojis
+            .iter()
+            .chain(config.resolved.downvote_emojis.iter())
+            .cloned()
+            .collect();
+
+        // walk the channel from newest to oldest, remembering which messages
+        // have vote reactions
+        let mut found: Vec<(Id<MessageMarker>, Vec<SimpleEmoji>)> = Vec::new();
+        let mut cursor = self.before;
+        'scan: loop {
+            if self.is_cancelled() {
+                return Ok(false);
+            }
+
+            let page = self.fetch_page(cursor).await?;
+            if page.is_empty() {
+                break;
+            }
+            cursor = page.last().map(|msg| msg.id);
+
+            for msg in page {
+                if self.after.is_some_and(|after| msg.id <= after) {
+                    break 'scan;
+                }
+                self.progress.scanned += 1;
+
+                let vote_emojis: Vec<_> = msg
+                    .reactions
+                    .into_iter()
+                    .map(|r| SimpleEmoji::from(r.emoji))
+                    .filter(|emoji| emojis.iter().any(|e| emoji == e))
+                    .collect();
+                if !vote_emojis.is_empty() {
+                    found.push((msg.id, vote_emojis));
+                }
+            }
+
+            self.progress.found = found.len() as u64;
+            report(self.progress).await;
+            tokio::time::sleep(constants::BACKFILL_PAGE_DELAY).await;
+        }
+
+        self.progress.done_scanning = true;
+        report(self.progress).await;
+
+        // count the votes and post the messages, oldest first
+        let is_premium = is_guild_premium(&self.bot, self.guild_id.get_i64(), true).await?;
+        for (message_id, vote_emojis) in found.into_iter().rev() {
+            if self.is_cancelled() {
+                return Ok(false);
+            }
+
+            let ret = self
+                .backfill_message(message_id, vote_emojis, &config, is_premium)
+                .await;
+            if let Err(why) = ret {
+                self.bot.handle_error(&why).await;
+            }
+
+            self.progress.processed += 1;
+            if self.progress.processed % constants::BACKFILL_REPORT_EVERY == 0 {
+                report(self.progress).await;
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    async fn fetch_page(&self, before: Option<Id<MessageMarker>>) -> StarboardResult<Vec<Message>> {
+        let req = self.bot.http.channel_messages(self.channel_id);
+        let page = match before {
+            Some(before) => req.before(before).limit(100)?.await?,
+            None => req.limit(100)?.await?,
+        };
+
+        Ok(page.models().await?)
+    }
+
+    async fn backfill_message(
+        &mut self,
+        message_id: Id<MessageMarker>,
+        vote_emojis: Vec<SimpleEmoji>,
+        config: &StarboardConfig,
+        is_premium: bool,
+    ) -> StarboardResult<()> {
+        let orig =
+            get_or_create_original(&self.bot, self.guild_id, self.channel_id, message_id).await?;
+        let (Some(orig), author_is_bot) = orig else {
+            return Ok(());
+        };
+        let author_is_bot = match author_is_bot {
+            Some(val) => val,
+            None => {
+                DbUser::get(&self.bot.pool, orig.author_id)
+                    .await?
+                    .unwrap()
+                    .is_bot
+            }
+        };
+
+        // the votes were cast at some point after the message was sent, which
+        // is closer than now for the leaderboards and seasons
+        let sent_at = Utc.timestamp_millis_opt(message_id.timestamp()).single();
+
+        // only count votes for the starboard being backfilled
+        let single = [config.clone()];
+        for emoji in vote_emojis {
+            recount_votes_reaction(
+                &self.bot,
+                (self.channel_id, message_id),
+                &orig,
+                &single,
+                author_is_bot,
+                emoji,
+                sent_at,
+            )
+            .await?;
+        }
+
+        let orig_id = orig.message_id;
+        let author_id = orig.author_id;
+        let mut refresh = RefreshMessage::new(self.bot.clone(), message_id, is_premium);
+        refresh.set_configs(self.configs.clone());
+        refresh.set_sql_message(orig);
+        // only refresh the starboard being backfilled (and the others in its
+        // exclusive group), so other starboards aren't edited
+        if !refresh.refresh_starboard(self.starboard_id, false).await? {
+            self.progress.skipped += 1;
+        } else if StarboardMessage::get_by_starboard(&self.bot.pool, orig_id, self.starboard_id)
+            .await?
+            .is_some()
+        {
+            self.progress.posted += 1;
+        }
+
+        refresh_xp(&self.bot, self.guild_id, author_id.into_id()).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+//! Reconciles the votes on recently active messages with their reactions, so
+//! that reactions added or removed while the bot was offline aren't missed.
+
+use std::{collections::HashSet, sync::Arc};
+
+use chrono::{Duration, Utc};
+use twilight_model::id::{
+    marker::{ChannelMarker, MessageMarker},
+    Id,
+};
+
+use crate::{
+    client::bot::StarboardBot,
+    constants,
+    core::{emoji::SimpleEmoji, premium::is_premium::is_guild_premium, stats::refresh_xp},
+    database::{DbMessage, DbUser, StarboardMessage, Vote},
+    errors::StarboardResult,
+    utils::{id_as_i64::GetI64, into_id::IntoId},
+};
+
+use super::{
+    config::StarboardConfig, handle::RefreshMessage, reaction_events::remove_vote,
+    recount::recount_votes_reaction,
+};
+
+pub async fn loop_catch_up_votes(bot: Arc<StarboardBot>) {
+    // give the shards some time to connect and fill the cache
+    tokio::time::sleep(constants::CATCH_UP_VOTES_STARTUP_DELAY).await;
+
+    loop {
+        if let Err(why) = catch_up_votes(&bot).await {
+            bot.handle_error(&why).await;
+        }
+
+        tokio::time::sleep(constants::CATCH_UP_VOTES_DELAY).await;
+    }
+}
+
+async fn catch_up_votes(bot: &Arc<StarboardBot>) -> StarboardResult<()> {
+    let since = Utc::now() - Duration::seconds(constants::CATCH_UP_VOTES_WINDOW);
+    let messages =
+        DbMessage::list_recently_voted(&bot.pool, since, constants::MAX_CATCH_UP_MESSAGES).await?;
+
+    for msg in messages {
+        if let Err(why) = catch_up_message(bot, msg).await {
+            bot.handle_error(&why).await;
+        }
+
+        tokio::time::sleep(constants::CATCH_UP_VOTES_PAUSE).await;
+    }
+
+    Ok(())
+}
+
+async fn catch_up_message(bot: &Arc<StarboardBot>, orig: DbMessage) -> StarboardResult<()> {
+    let message_id: Id<MessageMarker> = orig.message_id.into_id();
+    let Some(_guard) = bot.locks.vote_recount.lock(message_id) else {
+        return Ok(());
+    };
+
+    let guild_id = orig.guild_id.into_id();
+    if !bot.cache.guilds.contains_key(&guild_id) {
+        return Ok(());
+    }
+
+    let author_is_bot = DbUser::get(&bot.pool, orig.author_id)
+        .await?
+        .is_some_and(|u| u.is_bot);
+    let configs =
+        StarboardConfig::list_for_channel(bot, guild_id, orig.channel_id.into_id()).await?;
+
+    // votes can come from reactions on the original message or on any of its
+    // starboard messages
+    let mut sources: Vec<(Id<ChannelMarker>, Id<MessageMarker>)> =
+        vec![(orig.channel_id.into_id(), message_id)];
+    for sb_msg in StarboardMessage::list_by_message(&bot.pool, orig.message_id).await? {
+        let Some(config) = configs.iter().find(|c| c.starboard.id == sb_msg.starboard_id) else {
+            continue;
+        };
+        sources.push((
+            config.starboard.channel_id.into_id(),
+            sb_msg.starboard_message_id.into_id(),
+        ));
+    }
+
+    // (user_id, starboard_id) for every vote reaction that still exists
+    let mut reacted = HashSet::new();
+    let mut fetched_all = true;
+    for (channel_id, source_id) in sources {
+        let Ok(source) = bot.http.message(channel_id, source_id).await else {
+            fetched_all = false;
+            continue;
+        };
+
+        for reaction in source.model().await?.reactions {
+            let emoji = SimpleEmoji::from(reaction.emoji);
+            if !StarboardConfig::is_guild_vote_emoji(bot, orig.guild_id, &emoji).await? {
+                continue;
+            }
+
+            let starboard_ids: Vec<_> = configs
+                .iter()
+                .filter(|c| {
+                    c.resolved
+                        .upvote_emojis
+                        .iter()
+                        .chain(c.resolved.downvote_emojis.iter())
+                        .any(|e| &emoji == e)
+                })
+                .map(|c| c.starboard.id)
+                .collect();
+
+            let reactors = recount_votes_reaction(
+                bot,
+                (channel_id, source_id),
+                &orig,
+                &configs,
+                author_is_bot,
+                emoji,
+                None,
+            )
+            .await?;
+
+            for user_id in reactors {
+                for starboard_id in &starboard_ids {
+                    reacted.insert((user_id.get_i64(), *starboard_id));
+                }
+            }
+        }
+    }
+
+    // if a message couldn't be fetched we can't know which reactions are
+    // missing, so only remove votes when every message was checked
+    if fetched_all {
+        for vote in Vote::list_by_message(&bot.pool, orig.message_id).await? {
+            // votes cast with buttons have no reaction to check
+            if vote.from_button || reacted.contains(&(vote.user_id, vote.starboard_id)) {
+                continue;
+            }
+            if !configs.iter().any(|c| c.starboard.id == vote.starboard_id) {
+                continue;
+            }
+
+            remove_vote(bot, orig.message_id, vote.starboard_id, vote.user_id, Some(false))
+                .await?;
+        }
+    }
+
+    let author_id = orig.author_id;
+    let is_premium = is_guild_premium(bot, orig.guild_id, true).await?;
+    let mut refresh = RefreshMessage::new(bot.clone(), message_id, is_premium);
+    refresh.set_configs(configs.into_iter().map(Arc::new).collect());
+    refresh.set_sql_message(orig);
+    refresh.refresh(false).await?;
+
+    refresh_xp(bot, guild_id, author_id.into_id()).await?;
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use std::cmp::Ordering;
+use std::{cmp::Ordering, collections::HashMap};
 
 use twilight_model::id::{
     marker::{ChannelMarker, GuildMarker},
@@ -16,7 +16,7 @@ use crate::{
     utils::id_as_i64::GetI64,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct StarboardConfig {
     pub starboard: Starboard,
     pub overrides: Vec<StarboardOverride>,
@@ -36,6 +36,8 @@ macro_rules! update_from_override {
     };
 }
 
+pub(crate) use update_from_override;
+
 impl StarboardConfig {
     pub fn new(
         starboard: Starboard,
@@ -68,6 +70,21 @@ impl StarboardConfig {
         })
     }
 
+    /// Maps each setting that an override sets to the index in `overrides`
+    /// of the override it comes from. Settings that aren't listed come from
+    /// the starboard.
+    pub fn setting_sources(&self) -> HashMap<String, usize> {
+        let mut sources = HashMap::new();
+        // later overrides are more specific, so they replace earlier ones
+        for (idx, ov) in self.overrides.iter().enumerate() {
+            for key in ov.overrides.as_object().into_iter().flat_map(|o| o.keys()) {
+                sources.insert(key.clone(), idx);
+            }
+        }
+
+        sources
+    }
+
     pub async fn list_for_channel(
         bot: &StarboardBot,
         guild_id: Id<GuildMarker>,
@@ -0,0 +1,153 @@
+//! Works out why a message is or isn't on each starboard.
+
+use std::collections::HashMap;
+
+use crate::{
+    cache::MessageResult,
+    client::bot::StarboardBot,
+    core::{
+        filters::{FilterEvaluater, FilterTrace},
+        has_image::has_image,
+        permroles::Permissions,
+        premium::is_premium::is_guild_premium,
+    },
+    database::{
+        models::{filter_group::FilterGroup, starboard_filter_group::StarboardFilterGroup},
+        DbMessage, DbUser, StarboardMessage, Vote,
+    },
+    errors::StarboardResult,
+    utils::into_id::IntoId,
+};
+
+use super::{
+    config::StarboardConfig,
+    msg_status::{get_message_status, validate_regex, MessageStatus, StatusReason},
+    vote_status::{check_message_rules, VoteRejection},
+};
+
+pub struct Explanation {
+    pub config: StarboardConfig,
+    pub points: i32,
+    pub on_starboard: bool,
+    pub status: MessageStatus,
+    pub reason: StatusReason,
+    pub passes_regex: bool,
+    /// Another starboard in the same exclusive group that the message is on.
+    pub exclusive_conflict: Option<String>,
+    /// Why votes on the message don't count, no matter who votes.
+    pub vote_rejection: Option<VoteRejection>,
+    /// How the filters were evaluated without taking a voter into account,
+    /// in the same way as when someone votes.
+    pub filters: FilterTrace,
+    /// The names of the starboard's filter groups, by id.
+    pub filter_group_names: HashMap<i32, String>,
+}
+
+pub async fn explain_message(
+    bot: &StarboardBot,
+    orig: &DbMessage,
+) -> StarboardResult<Vec<Explanation>> {
+    let guild_id = orig.guild_id.into_id();
+    let channel_id = orig.channel_id.into_id();
+    let message_id = orig.message_id.into_id();
+    let author_id = orig.author_id.into_id();
+
+    let configs = StarboardConfig::list_for_channel(bot, guild_id, channel_id).await?;
+    let is_premium = is_guild_premium(bot, orig.guild_id, true).await?;
+    let message_obj = bot.cache.fog_message(bot, channel_id, message_id).await?;
+    let message_has_image = match &message_obj {
+        MessageResult::Ok(msg) => Some(has_image(&msg.embeds, &msg.attachments)),
+        _ => None,
+    };
+    let author_is_bot = DbUser::get(&bot.pool, orig.author_id)
+        .await?
+        .is_some_and(|u| u.is_bot);
+    let posted: Vec<_> = StarboardMessage::list_by_message(&bot.pool, orig.message_id)
+        .await?
+        .into_iter()
+        .map(|m| m.starboard_id)
+        .collect();
+
+    let mut explanations = Vec::new();
+    for config in &configs {
+        let points = Vote::count(&bot.pool, orig.message_id, config.starboard.id).await?;
+
+        let exclusive_conflict = config.resolved.exclusive_group.and_then(|group| {
+            configs
+                .iter()
+                .find(|other| {
+                    other.starboard.id != config.starboard.id
+                        && other.resolved.exclusive_group == Some(group)
+                        && posted.contains(&other.starboard.id)
+                })
+                .map(|other| other.starboard.name.clone())
+        });
+
+        let (status, reason) = get_message_status(
+            bot,
+            config,
+            orig,
+            &message_obj,
+            points,
+            exclusive_conflict.is_some(),
+            is_premium,
+        )
+        .await?;
+
+        let mut vote_rejection = check_message_rules(
+            config,
+            None,
+            message_id,
+            author_id,
+            author_is_bot,
+            message_has_image,
+        );
+        if vote_rejection.is_none() {
+            let author_perms =
+                Permissions::get_permissions(bot, author_id, guild_id, Some(config.starboard.id))
+                    .await?;
+            if !author_perms.receive_votes {
+                vote_rejection = Some(VoteRejection::AuthorCantReceiveVotes);
+            }
+        }
+
+        // the groups are evaluated together, since a filter that instantly
+        // passes skips the groups after it
+        let filter_groups =
+            StarboardFilterGroup::list_by_starboard(&bot.pool, config.starboard.id).await?;
+        let mut filter_group_names = HashMap::new();
+        for group in &filter_groups {
+            let name = FilterGroup::get(&bot.pool, group.filter_group_id)
+                .await?
+                .name;
+            filter_group_names.insert(group.filter_group_id, name);
+        }
+        let mut evaluater = FilterEvaluater::new(
+            bot,
+            guild_id,
+            author_id,
+            None,
+            Some(channel_id),
+            Some(message_id),
+            filter_groups.iter().map(|g| g.filter_group_id).collect(),
+        );
+        evaluater.set_user_is_bot(Some(author_is_bot));
+        evaluater.set_message(message_obj.clone());
+        let filters = evaluater.trace().await?;
+
+        explanations.push(Explanation {
+            config: config.clone(),
+            points,
+            on_starboard: posted.contains(&config.starboard.id),
+            status,
+            reason,
+            passes_regex: validate_regex(bot, config, &message_obj, is_premium).await,
+            exclusive_conflict,
+            vote_rejection,
+            filters,
+            filter_group_names,
+        });
+    }
+
+    Ok(explanations)
+}
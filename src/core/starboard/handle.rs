@@ -1,7 +1,10 @@
 use std::{collections::HashMap, sync::Arc};
 
 use cached::Cached;
-use twilight_model::id::{marker::MessageMarker, Id};
+use twilight_model::{
+    channel::Message,
+    id::{marker::MessageMarker, Id},
+};
 
 use crate::{
     cache::MessageResult,
@@ -18,6 +21,7 @@ use crate::{
 use super::{
     config::StarboardConfig,
     msg_status::{get_message_status, MessageStatus},
+    refresh_queue::RefreshQueue,
 };
 
 async fn refresh_exclusive_group(
@@ -54,6 +58,30 @@ async fn refresh_exclusive_group(
     Ok(())
 }
 
+/// Whether a starboard should have its posts refreshed.
+fn is_active(config: &StarboardConfig) -> bool {
+    config.resolved.enabled && !config.starboard.premium_locked
+}
+
+pub enum RegenerateResult {
+    /// The post was edited in place.
+    Edited,
+    /// The post was sent again and the old one was deleted.
+    Resent,
+    /// There was no post to regenerate.
+    Skipped,
+}
+
+pub enum RepostResult {
+    /// The post was sent in the starboard's current channel.
+    Moved,
+    /// The post can't be sent anymore, because the starboard no longer
+    /// applies to the original message or the original was deleted.
+    NotEligible,
+    /// The message was already being updated.
+    Busy,
+}
+
 #[derive(Clone)]
 pub struct RefreshMessage {
     bot: Arc<StarboardBot>,
@@ -94,7 +122,7 @@ impl RefreshMessage {
         let mut grouped = HashMap::new();
 
         for c in configs.iter() {
-            if !c.resolved.enabled || c.starboard.premium_locked {
+            if !is_active(c) {
                 continue;
             }
 
@@ -133,6 +161,90 @@ impl RefreshMessage {
         Ok(true)
     }
 
+    /// Refreshes the post on a single starboard, along with the other
+    /// starboards in its exclusive group since they depend on each other.
+    /// Returns false if the message was already being updated.
+    pub async fn refresh_starboard(
+        &mut self,
+        starboard_id: i32,
+        force: bool,
+    ) -> StarboardResult<bool> {
+        let orig = self.get_sql_message().await?;
+        let clone = self.bot.clone();
+        let guard = clone.locks.post_update_lock.lock(orig.message_id);
+        if guard.is_none() {
+            return Ok(false);
+        }
+
+        let configs = self.get_configs().await?;
+        let config = configs.iter().find(|c| c.starboard.id == starboard_id);
+        let Some(config) = config.filter(|c| is_active(c)).cloned() else {
+            return Ok(true);
+        };
+
+        if let Some(group_id) = config.resolved.exclusive_group {
+            let group = configs
+                .iter()
+                .filter(|c| is_active(c) && c.resolved.exclusive_group == Some(group_id))
+                .cloned()
+                .collect();
+            refresh_exclusive_group(self.to_owned(), group, force).await?;
+        } else {
+            RefreshStarboard::new(self.to_owned(), config)
+                .refresh(force, false)
+                .await?;
+        }
+
+        Ok(true)
+    }
+
+    /// Rebuilds the post on a single starboard with its current style, even if
+    /// nothing about the original message changed.
+    pub async fn regenerate(&mut self, starboard_id: i32) -> StarboardResult<RegenerateResult> {
+        let orig = self.get_sql_message().await?;
+        let clone = self.bot.clone();
+        let guard = clone.locks.post_update_lock.lock(orig.message_id);
+        if guard.is_none() {
+            return Ok(RegenerateResult::Skipped);
+        }
+
+        let configs = self.get_configs().await?;
+        let config = configs.iter().find(|c| c.starboard.id == starboard_id);
+        let Some(config) = config.cloned() else {
+            return Ok(RegenerateResult::Skipped);
+        };
+
+        RefreshStarboard::new(self.to_owned(), config)
+            .regenerate()
+            .await
+    }
+
+    /// Sends the post on a single starboard again, pointing the starboard
+    /// message at the new post. If `delete_from` is passed, the old post is
+    /// deleted using that config.
+    pub async fn repost(
+        &mut self,
+        starboard_id: i32,
+        delete_from: Option<Arc<StarboardConfig>>,
+    ) -> StarboardResult<RepostResult> {
+        let orig = self.get_sql_message().await?;
+        let clone = self.bot.clone();
+        let guard = clone.locks.post_update_lock.lock(orig.message_id);
+        if guard.is_none() {
+            return Ok(RepostResult::Busy);
+        }
+
+        let configs = self.get_configs().await?;
+        let config = configs.iter().find(|c| c.starboard.id == starboard_id);
+        let Some(config) = config.cloned() else {
+            return Ok(RepostResult::NotEligible);
+        };
+
+        RefreshStarboard::new(self.to_owned(), config)
+            .repost(delete_from)
+            .await
+    }
+
     // caching methods
     pub fn set_configs(&mut self, configs: Vec<Arc<StarboardConfig>>) {
         self.configs.replace(Arc::new(configs));
@@ -230,42 +342,11 @@ impl RefreshStarboard {
         violates_exclusive_group: bool,
     ) -> StarboardResult<(bool, bool)> {
         let orig = self.refresh.get_sql_message().await?;
-        let points = Vote::count(
-            &self.refresh.bot.pool,
-            orig.message_id,
-            self.config.starboard.id,
-        )
-        .await?;
-
-        let orig_message = self.refresh.get_orig_message().await?;
-        let sql_message = self.refresh.get_sql_message().await?;
-        let ref_msg = if let MessageResult::Ok(msg) = &orig_message {
-            if let Some(id) = msg.referenced_message {
-                self.refresh
-                    .bot
-                    .cache
-                    .fog_message(&self.refresh.bot, sql_message.channel_id.into_id(), id)
-                    .await?
-                    .into_option()
-            } else {
-                None
-            }
-        } else {
-            None
-        };
-
+        let embedder = self.get_embedder().await?;
+        let points = embedder.points;
         let sb_msg = self.get_starboard_message().await?;
-        let embedder = Embedder {
-            bot: self.refresh.bot.clone(),
-            points,
-            config: self.config.clone(),
-            orig_message,
-            referenced_message: ref_msg,
-            orig_sql_message: sql_message,
-            is_premium: self.refresh.is_premium,
-        };
 
-        let action = get_message_status(
+        let (action, _) = get_message_status(
             &self.refresh.bot,
             &self.config,
             &orig,
@@ -283,6 +364,33 @@ impl RefreshStarboard {
             {
                 return Ok((false, true));
             }
+
+            // while the starboard is being moved, posts that haven't been
+            // reposted yet are still in the old channel, so they can only be
+            // updated once the move is done. Likewise, if edits to this
+            // starboard are being rate limited, queue a trailing refresh
+            // instead of dropping the update
+            let bot = &self.refresh.bot;
+            let moving = bot.locks.starboard_move.is_locked(&sb_msg.starboard_id);
+            if moving
+                || (!matches!(action, MessageStatus::Remove)
+                    && bot
+                        .cooldowns
+                        .message_edit
+                        .trigger(&self.config.starboard.channel_id.into_id())
+                        .is_some())
+            {
+                RefreshQueue::schedule(
+                    &self.refresh.bot,
+                    sb_msg.starboard_message_id,
+                    orig.message_id,
+                    self.config.starboard.id,
+                    force,
+                    self.refresh.is_premium,
+                );
+                return Ok((false, true));
+            }
+
             StarboardMessage::set_last_point_count(
                 &self.refresh.bot.pool,
                 sb_msg.starboard_message_id,
@@ -304,25 +412,14 @@ impl RefreshStarboard {
                     (false, deleted)
                 }
                 MessageStatus::Send(full_update) | MessageStatus::Update(full_update) => {
-                    if self
-                        .refresh
-                        .bot
-                        .cooldowns
-                        .message_edit
-                        .trigger(&self.config.starboard.channel_id.into_id())
-                        .is_some()
-                    {
-                        (false, false)
-                    } else {
-                        let deleted = embedder
-                            .edit(
-                                &self.refresh.bot,
-                                sb_msg.starboard_message_id.into_id(),
-                                !full_update,
-                            )
-                            .await?;
-                        (deleted, deleted)
-                    }
+                    let deleted = embedder
+                        .edit(
+                            &self.refresh.bot,
+                            sb_msg.starboard_message_id.into_id(),
+                            !full_update,
+                        )
+                        .await?;
+                    (deleted, deleted)
                 }
             };
 
@@ -358,29 +455,149 @@ impl RefreshStarboard {
             )
             .await?;
 
-            let mut to_react: Vec<SimpleEmoji> = Vec::new();
-            if self.config.resolved.autoreact_upvote {
-                to_react.extend(Vec::<SimpleEmoji>::from_stored(
-                    self.config.resolved.upvote_emojis.clone(),
-                ));
-            }
-            if self.config.resolved.autoreact_downvote {
-                to_react.extend(Vec::<SimpleEmoji>::from_stored(
-                    self.config.resolved.downvote_emojis.clone(),
-                ));
-            }
+            self.add_autoreactions(&msg).await;
+
+            Ok((false, true))
+        }
+    }
+
+    async fn regenerate(&mut self) -> StarboardResult<RegenerateResult> {
+        let Some(sb_msg) = self.get_starboard_message().await? else {
+            return Ok(RegenerateResult::Skipped);
+        };
+        let bot = self.refresh.bot.clone();
+        let embedder = self.get_embedder().await?;
+        let sb_message_id = sb_msg.starboard_message_id.into_id();
+
+        // switching between webhooks and the bot can't be done with an edit, so
+        // send the new post before removing the old one
+        if matches!(embedder.orig_message, MessageResult::Ok(_))
+            && embedder.needs_resend(&bot, sb_message_id).await?
+        {
+            let msg = embedder.send(&bot).await?;
+
+            StarboardMessage::set_starboard_message_id(
+                &bot.pool,
+                sb_msg.starboard_message_id,
+                msg.id.get_i64(),
+            )
+            .await?;
+            self.add_autoreactions(&msg).await;
+
+            bot.cache
+                .auto_deleted_posts
+                .write()
+                .await
+                .cache_set(sb_message_id, ());
+            embedder.delete(&bot, sb_message_id).await?;
+
+            return Ok(RegenerateResult::Resent);
+        }
+
+        let deleted = embedder.edit(&bot, sb_message_id, false).await?;
+        if deleted {
+            StarboardMessage::delete(&bot.pool, sb_msg.starboard_message_id).await?;
+            return Ok(RegenerateResult::Skipped);
+        }
+
+        Ok(RegenerateResult::Edited)
+    }
+
+    async fn repost(
+        &mut self,
+        delete_from: Option<Arc<StarboardConfig>>,
+    ) -> StarboardResult<RepostResult> {
+        let Some(sb_msg) = self.get_starboard_message().await? else {
+            return Ok(RepostResult::NotEligible);
+        };
+        let bot = self.refresh.bot.clone();
+        let embedder = self.get_embedder().await?;
+        if !matches!(embedder.orig_message, MessageResult::Ok(_)) {
+            return Ok(RepostResult::NotEligible);
+        }
+
+        let msg = embedder.send(&bot).await?;
+        StarboardMessage::set_starboard_message_id(
+            &bot.pool,
+            sb_msg.starboard_message_id,
+            msg.id.get_i64(),
+        )
+        .await?;
+        self.add_autoreactions(&msg).await;
+
+        if let Some(config) = delete_from {
+            let sb_message_id = sb_msg.starboard_message_id.into_id();
+            bot.cache
+                .auto_deleted_posts
+                .write()
+                .await
+                .cache_set(sb_message_id, ());
+            let embedder = Embedder { config, ..embedder };
+            embedder.delete(&bot, sb_message_id).await?;
+        }
+
+        Ok(RepostResult::Moved)
+    }
+
+    async fn add_autoreactions(&self, msg: &Message) {
+        // vote buttons replace the autoreactions
+        let autoreact = !self.config.resolved.vote_buttons;
+        let mut to_react: Vec<SimpleEmoji> = Vec::new();
+        if autoreact && self.config.resolved.autoreact_upvote {
+            to_react.extend(Vec::<SimpleEmoji>::from_stored(
+                self.config.resolved.upvote_emojis.clone(),
+            ));
+        }
+        if autoreact && self.config.resolved.autoreact_downvote {
+            to_react.extend(Vec::<SimpleEmoji>::from_stored(
+                self.config.resolved.downvote_emojis.clone(),
+            ));
+        }
+
+        for emoji in to_react {
+            let _ = self
+                .refresh
+                .bot
+                .http
+                .create_reaction(msg.channel_id, msg.id, &emoji.reactable())
+                .await;
+        }
+    }
+
+    async fn get_embedder(&mut self) -> StarboardResult<Embedder> {
+        let sql_message = self.refresh.get_sql_message().await?;
+        let points = Vote::count(
+            &self.refresh.bot.pool,
+            sql_message.message_id,
+            self.config.starboard.id,
+        )
+        .await?;
 
-            for emoji in to_react {
-                let _ = self
-                    .refresh
+        let orig_message = self.refresh.get_orig_message().await?;
+        let ref_msg = if let MessageResult::Ok(msg) = &orig_message {
+            if let Some(id) = msg.referenced_message {
+                self.refresh
                     .bot
-                    .http
-                    .create_reaction(msg.channel_id, msg.id, &emoji.reactable())
-                    .await;
+                    .cache
+                    .fog_message(&self.refresh.bot, sql_message.channel_id.into_id(), id)
+                    .await?
+                    .into_option()
+            } else {
+                None
             }
+        } else {
+            None
+        };
 
-            Ok((false, true))
-        }
+        Ok(Embedder {
+            bot: self.refresh.bot.clone(),
+            points,
+            config: self.config.clone(),
+            orig_message,
+            referenced_message: ref_msg,
+            orig_sql_message: sql_message,
+            is_premium: self.refresh.is_premium,
+        })
     }
 
     async fn get_starboard_message(&mut self) -> sqlx::Result<Option<StarboardMessage>> {
@@ -33,6 +33,26 @@ pub async fn handle_message_update(
     Ok(())
 }
 
+pub async fn handle_message_delete_bulk(
+    bot: Arc<StarboardBot>,
+    message_ids: Vec<Id<MessageMarker>>,
+) -> StarboardResult<()> {
+    let message_ids: Vec<i64> = message_ids.into_iter().map(|id| id.get_i64()).collect();
+
+    // most purged messages have nothing to do with starboard, so filter those
+    // out with a single query
+    let tracked = DbMessage::list_tracked(&bot.pool, &message_ids).await?;
+
+    for message_id in tracked {
+        let ret = handle_message_delete(bot.clone(), message_id.into_id()).await;
+        if let Err(why) = ret {
+            bot.handle_error(&why).await;
+        }
+    }
+
+    Ok(())
+}
+
 pub async fn handle_message_delete(
     bot: Arc<StarboardBot>,
     message_id: Id<MessageMarker>,
@@ -1,9 +1,16 @@
+pub mod backfill;
+pub mod catch_up;
 pub mod config;
+pub mod explain;
 pub mod handle;
 pub mod link_events;
 pub mod message;
+pub mod move_posts;
 pub mod msg_status;
+pub mod presets;
 pub mod reaction_events;
 pub mod recount;
+pub mod refresh_queue;
+pub mod regenerate;
 pub mod vote_status;
 pub mod webhooks;
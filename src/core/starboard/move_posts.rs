@@ -0,0 +1,109 @@
+//! Reposts the existing posts of a starboard after it was moved to another
+//! channel.
+
+use std::sync::Arc;
+
+use crate::{
+    client::bot::StarboardBot, constants, core::premium::is_premium::is_guild_premium,
+    database::StarboardMessage, errors::StarboardResult,
+};
+
+use super::{
+    config::StarboardConfig,
+    handle::RepostResult,
+    regenerate::{refresh_for_post, ChannelConfigs},
+};
+
+#[derive(Default, Clone, Copy)]
+pub struct MoveProgress {
+    /// How many posts there are to move.
+    pub total: u64,
+    pub moved: u64,
+    /// Posts that were removed from the starboard because they couldn't be
+    /// sent anymore.
+    pub removed: u64,
+    /// Posts that were left as they were, because they were busy or errored.
+    pub skipped: u64,
+}
+
+pub struct MovePosts {
+    pub bot: Arc<StarboardBot>,
+    pub guild_id: i64,
+    pub starboard_id: i32,
+    /// The config of the starboard before it was moved, used to delete the
+    /// old posts. If this is `None`, the old posts are kept.
+    pub delete_from: Option<Arc<StarboardConfig>>,
+    pub progress: MoveProgress,
+}
+
+impl MovePosts {
+    /// Repost every post in its original order, calling `report` every so
+    /// often with the current progress.
+    pub async fn run<F, Fut>(&mut self, mut report: F) -> StarboardResult<()>
+    where
+        F: FnMut(MoveProgress) -> Fut,
+        Fut: std::future::Future<Output = ()>,
+    {
+        let posts =
+            StarboardMessage::list_by_starboard(&self.bot.pool, self.starboard_id, None).await?;
+        self.progress.total = posts.len() as u64;
+        report(self.progress).await;
+
+        let is_premium = is_guild_premium(&self.bot, self.guild_id, true).await?;
+        let mut channel_configs = ChannelConfigs::new();
+        for post in posts {
+            let ret = self.move_post(post, is_premium, &mut channel_configs).await;
+            match ret {
+                Ok(RepostResult::Moved) => self.progress.moved += 1,
+                Ok(RepostResult::NotEligible) => self.progress.removed += 1,
+                Ok(RepostResult::Busy) => self.progress.skipped += 1,
+                Err(why) => {
+                    self.progress.skipped += 1;
+                    self.bot.handle_error(&why).await;
+                }
+            }
+
+            let processed = self.progress.moved + self.progress.removed + self.progress.skipped;
+            if processed % constants::REGENERATE_REPORT_EVERY == 0 {
+                report(self.progress).await;
+            }
+            tokio::time::sleep(constants::REGENERATE_PAUSE).await;
+        }
+
+        Ok(())
+    }
+
+    /// Reposts a single post, retrying a few times if it's already being
+    /// updated. If it can't be sent anymore, the starboard message is deleted,
+    /// since it still points at the post in the old channel.
+    async fn move_post(
+        &self,
+        post: StarboardMessage,
+        is_premium: bool,
+        channel_configs: &mut ChannelConfigs,
+    ) -> StarboardResult<RepostResult> {
+        let refresh = refresh_for_post(&self.bot, &post, is_premium, channel_configs).await?;
+        let Some(mut refresh) = refresh else {
+            StarboardMessage::delete(&self.bot.pool, post.starboard_message_id).await?;
+            return Ok(RepostResult::NotEligible);
+        };
+
+        let mut attempts = 0;
+        loop {
+            let ret = refresh
+                .repost(self.starboard_id, self.delete_from.clone())
+                .await?;
+            match ret {
+                RepostResult::Busy if attempts < constants::MOVE_BUSY_RETRIES => {
+                    attempts += 1;
+                    tokio::time::sleep(constants::REGENERATE_PAUSE).await;
+                }
+                RepostResult::NotEligible => {
+                    StarboardMessage::delete(&self.bot.pool, post.starboard_message_id).await?;
+                    return Ok(ret);
+                }
+                _ => return Ok(ret),
+            }
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use crate::{
-    cache::MessageResult, client::bot::StarboardBot, database::DbMessage, errors::StarboardResult,
-    utils::into_id::IntoId,
+    cache::MessageResult,
+    client::bot::StarboardBot,
+    database::DbMessage,
+    errors::StarboardResult,
+    utils::{into_id::IntoId, safe_regex::regex_haystack},
 };
 
 use super::config::StarboardConfig;
@@ -14,6 +17,44 @@ pub enum MessageStatus {
     Send(bool),
 }
 
+/// Why a message got the status that it did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusReason {
+    UnknownStarboardChannel,
+    Deleted,
+    NsfwMismatch,
+    Trashed,
+    Forced,
+    ExclusiveGroup,
+    Frozen,
+    BelowRequiredRemove,
+    MeetsRequired,
+    FailedRegex,
+    BelowRequired,
+    NoRequired,
+}
+
+impl StatusReason {
+    pub fn describe(self) -> &'static str {
+        match self {
+            Self::UnknownStarboardChannel => "I can't find the starboard channel.",
+            Self::Deleted => "The message was deleted, and `link-deletes` is enabled.",
+            Self::NsfwMismatch => "The message is in an NSFW channel, but the starboard isn't.",
+            Self::Trashed => "The message was trashed.",
+            Self::Forced => "The message was forced to the starboard.",
+            Self::ExclusiveGroup => {
+                "The message is on another starboard in the same exclusive group."
+            }
+            Self::Frozen => "The message is frozen, so it won't be sent or removed.",
+            Self::BelowRequiredRemove => "The message has `required-remove` points or fewer.",
+            Self::MeetsRequired => "The message has at least `required` points.",
+            Self::FailedRegex => "The message doesn't pass the `matches`/`not-matches` regex.",
+            Self::BelowRequired => "The message has fewer than `required` points.",
+            Self::NoRequired => "`required` is disabled, so messages are never sent.",
+        }
+    }
+}
+
 pub async fn get_message_status(
     bot: &StarboardBot,
     config: &StarboardConfig,
@@ -22,7 +63,7 @@ pub async fn get_message_status(
     points: i32,
     violates_exclusive_group: bool,
     is_premium: bool,
-) -> StarboardResult<MessageStatus> {
+) -> StarboardResult<(MessageStatus, StatusReason)> {
     let deleted = matches!(message_obj, MessageResult::Missing);
 
     let guild_id = config.starboard.guild_id.into_id();
@@ -34,46 +75,67 @@ pub async fn get_message_status(
 
     let sb_is_nsfw = match sb_is_nsfw {
         Some(val) => val,
-        None => return Ok(MessageStatus::Update(config.resolved.link_edits)),
+        None => {
+            return Ok((
+                MessageStatus::Update(config.resolved.link_edits),
+                StatusReason::UnknownStarboardChannel,
+            ))
+        }
     };
 
-    if (deleted && config.resolved.link_deletes)
-        || (message.is_nsfw && !sb_is_nsfw)
-        || message.trashed
-    {
-        return Ok(MessageStatus::Remove);
+    if deleted && config.resolved.link_deletes {
+        return Ok((MessageStatus::Remove, StatusReason::Deleted));
+    }
+    if message.is_nsfw && !sb_is_nsfw {
+        return Ok((MessageStatus::Remove, StatusReason::NsfwMismatch));
+    }
+    if message.trashed {
+        return Ok((MessageStatus::Remove, StatusReason::Trashed));
     }
 
     if message.forced_to.contains(&config.starboard.id) {
-        return Ok(MessageStatus::Send(config.resolved.link_edits));
+        return Ok((
+            MessageStatus::Send(config.resolved.link_edits),
+            StatusReason::Forced,
+        ));
     }
     if violates_exclusive_group {
-        return Ok(MessageStatus::Remove);
+        return Ok((MessageStatus::Remove, StatusReason::ExclusiveGroup));
     }
 
     if message.frozen {
-        return Ok(MessageStatus::Update(false));
+        return Ok((MessageStatus::Update(false), StatusReason::Frozen));
     }
 
     if let Some(required_remove) = config.resolved.required_remove {
         if points <= required_remove as i32 {
-            return Ok(MessageStatus::Remove);
+            return Ok((MessageStatus::Remove, StatusReason::BelowRequiredRemove));
         }
     }
 
-    if let Some(required) = config.resolved.required {
-        if validate_regex(config, message_obj, is_premium) {
-            #[allow(clippy::collapsible_if)]
-            if points >= required as i32 {
-                return Ok(MessageStatus::Send(config.resolved.link_edits));
-            }
-        }
+    let update = MessageStatus::Update(config.resolved.link_edits);
+    let Some(required) = config.resolved.required else {
+        return Ok((update, StatusReason::NoRequired));
+    };
+    if !validate_regex(bot, config, message_obj, is_premium).await {
+        return Ok((update, StatusReason::FailedRegex));
+    }
+    if points >= required as i32 {
+        return Ok((
+            MessageStatus::Send(config.resolved.link_edits),
+            StatusReason::MeetsRequired,
+        ));
     }
 
-    Ok(MessageStatus::Update(config.resolved.link_edits))
+    Ok((update, StatusReason::BelowRequired))
 }
 
-fn validate_regex(config: &StarboardConfig, message_obj: &MessageResult, is_premium: bool) -> bool {
+pub async fn validate_regex(
+    bot: &StarboardBot,
+    config: &StarboardConfig,
+    message_obj: &MessageResult,
+    is_premium: bool,
+) -> bool {
     if !is_premium {
         return true;
     }
@@ -85,17 +147,18 @@ fn validate_regex(config: &StarboardConfig, message_obj: &MessageResult, is_prem
     let MessageResult::Ok(message_obj) = message_obj else {
         return false;
     };
+    let content = regex_haystack(&message_obj.content);
 
     if let Some(re) = &config.resolved.matches {
-        if let Ok(re) = regex::Regex::new(re) {
-            if !re.is_match(&message_obj.content) {
+        if let Some(re) = bot.cache.regex(re).await {
+            if !re.is_match(content) {
                 return false;
             }
         }
     }
     if let Some(re) = &config.resolved.not_matches {
-        if let Ok(re) = regex::Regex::new(re) {
-            if re.is_match(&message_obj.content) {
+        if let Some(re) = bot.cache.regex(re).await {
+            if re.is_match(content) {
                 return false;
             }
         }
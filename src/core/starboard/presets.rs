@@ -0,0 +1,287 @@
+//! Templates for the settings of new starboards, either built in or saved
+//! from an existing starboard.
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{
+    client::bot::StarboardBot,
+    constants,
+    core::{
+        export::{channel_exists, ImportReport},
+        starboard::config::update_from_override,
+    },
+    database::{
+        helpers::settings::overrides::call_with_override_settings,
+        validation::{
+            name::validate_name,
+            starboard_settings::{validate_override_values, validate_xp_multiplier},
+        },
+        OverrideValues, Starboard, StarboardOverride, StarboardPreset,
+    },
+    errors::StarboardResult,
+    utils::id_as_i64::GetI64,
+};
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Default)]
+pub struct PresetValues {
+    /// The settings of the starboard. Settings that are missing are left at
+    /// their default.
+    #[serde(default)]
+    pub settings: OverrideValues,
+    pub private: Option<bool>,
+    pub xp_multiplier: Option<f32>,
+    #[serde(default)]
+    pub overrides: Vec<PresetOverride>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PresetOverride {
+    /// The name of the override, without the name of the starboard.
+    pub name: String,
+    pub channel_ids: Vec<i64>,
+    #[serde(default)]
+    pub settings: OverrideValues,
+}
+
+pub struct BuiltinPreset {
+    pub name: &'static str,
+    pub description: &'static str,
+    values: fn() -> PresetValues,
+}
+
+impl BuiltinPreset {
+    pub fn values(&self) -> PresetValues {
+        (self.values)()
+    }
+}
+
+fn emojis(emojis: &[&str]) -> Option<Vec<String>> {
+    Some(emojis.iter().map(|e| e.to_string()).collect())
+}
+
+pub const BUILTIN_PRESETS: &[BuiltinPreset] = &[
+    BuiltinPreset {
+        name: "classic",
+        description: "The classic starboard, with 3 ⭐ reactions required.",
+        values: || PresetValues {
+            settings: OverrideValues {
+                display_emoji: Some(Some("⭐".to_string())),
+                upvote_emojis: emojis(&["⭐"]),
+                required: Some(Some(3)),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    },
+    BuiltinPreset {
+        name: "meme",
+        description: "A meme board, with 👍 to upvote and 👎 to downvote.",
+        values: || PresetValues {
+            settings: OverrideValues {
+                display_emoji: Some(Some("😂".to_string())),
+                upvote_emojis: emojis(&["👍"]),
+                downvote_emojis: emojis(&["👎"]),
+                required: Some(Some(5)),
+                autoreact_upvote: Some(true),
+                autoreact_downvote: Some(true),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    },
+    BuiltinPreset {
+        name: "hall-of-fame",
+        description: "A private hall of fame, with a high threshold.",
+        values: || PresetValues {
+            settings: OverrideValues {
+                display_emoji: Some(Some("🏆".to_string())),
+                upvote_emojis: emojis(&["⭐"]),
+                required: Some(Some(15)),
+                required_remove: Some(Some(10)),
+                color: Some(Some(0xFFD700)),
+                ..Default::default()
+            },
+            private: Some(true),
+            ..Default::default()
+        },
+    },
+    BuiltinPreset {
+        name: "art",
+        description: "An art board, which only accepts messages with images.",
+        values: || PresetValues {
+            settings: OverrideValues {
+                display_emoji: Some(Some("🎨".to_string())),
+                upvote_emojis: emojis(&["🎨"]),
+                require_image: Some(true),
+                attachments_list: Some(true),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    },
+];
+
+pub fn builtin_preset(name: &str) -> Option<&'static BuiltinPreset> {
+    BUILTIN_PRESETS.iter().find(|preset| preset.name == name)
+}
+
+impl PresetValues {
+    /// Gets a built-in preset, or else one of the guild's custom presets.
+    pub async fn get(
+        bot: &StarboardBot,
+        guild_id: i64,
+        name: &str,
+    ) -> StarboardResult<Option<Self>> {
+        if let Some(preset) = builtin_preset(name) {
+            return Ok(Some(preset.values()));
+        }
+
+        let Some(preset) = StarboardPreset::get_by_name(&bot.pool, guild_id, name).await? else {
+            return Ok(None);
+        };
+        Ok(Some(serde_json::from_value(preset.preset)?))
+    }
+
+    pub async fn from_starboard(
+        bot: &StarboardBot,
+        starboard: &Starboard,
+        include_overrides: bool,
+    ) -> StarboardResult<Self> {
+        let mut settings = OverrideValues::from_settings(starboard.settings.clone());
+        // exclusive groups are too specific to the starboard to be reused
+        settings.exclusive_group = None;
+        settings.exclusive_group_priority = None;
+
+        let mut overrides = Vec::new();
+        if include_overrides {
+            for ov in StarboardOverride::list_by_starboard(&bot.pool, starboard.id).await? {
+                let mut settings = ov.get_overrides()?;
+                settings.exclusive_group = None;
+                settings.exclusive_group_priority = None;
+
+                let name = ov
+                    .name
+                    .strip_prefix(&format!("{}-", starboard.name))
+                    .unwrap_or(&ov.name)
+                    .to_string();
+                overrides.push(PresetOverride {
+                    name,
+                    channel_ids: ov.channel_ids,
+                    settings,
+                });
+            }
+        }
+
+        Ok(Self {
+            settings,
+            private: Some(starboard.settings.private),
+            xp_multiplier: Some(starboard.settings.xp_multiplier),
+            overrides,
+        })
+    }
+
+    /// Validates the preset, since a saved preset may have been created when
+    /// the guild had premium, or before a limit was lowered.
+    pub fn validate(&mut self, premium: bool) -> Result<(), String> {
+        self.settings.exclusive_group = None;
+        self.settings.exclusive_group_priority = None;
+        validate_override_values(&self.settings, premium)?;
+        if let Some(xp_multiplier) = self.xp_multiplier {
+            validate_xp_multiplier(xp_multiplier)?;
+        }
+
+        if self.overrides.len() > constants::MAX_OVERRIDES_PER_STARBOARD as usize {
+            return Err(format!(
+                "A preset can only have up to {} overrides.",
+                constants::MAX_OVERRIDES_PER_STARBOARD
+            ));
+        }
+        for ov in &mut self.overrides {
+            ov.settings.exclusive_group = None;
+            ov.settings.exclusive_group_priority = None;
+            validate_override_values(&ov.settings, premium)
+                .map_err(|why| format!("Override '{}': {}", ov.name, why))?;
+            StarboardOverride::validate_channels(&ov.channel_ids)?;
+        }
+
+        Ok(())
+    }
+
+    /// Creates a starboard with the settings of this preset in the
+    /// transaction `con`. Returns `None` if a starboard with the same name
+    /// already exists.
+    pub async fn create_starboard(
+        self,
+        bot: &StarboardBot,
+        con: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        guild_id: Id<GuildMarker>,
+        name: &String,
+        channel_id: i64,
+    ) -> StarboardResult<Option<(Starboard, ImportReport)>> {
+        let guild_id_i64 = guild_id.get_i64();
+        let mut report = ImportReport::default();
+
+        let Some(mut starboard) =
+            Starboard::create(&mut *con, name, channel_id, guild_id_i64).await?
+        else {
+            return Ok(None);
+        };
+
+        call_with_override_settings!(update_from_override, starboard.settings, self.settings);
+        if let Some(private) = self.private {
+            starboard.settings.private = private;
+        }
+        if let Some(xp_multiplier) = self.xp_multiplier {
+            starboard.settings.xp_multiplier = xp_multiplier;
+        }
+        let Some(starboard) = starboard.update_settings(&mut *con).await? else {
+            return Ok(None);
+        };
+
+        for preset_ov in self.overrides {
+            let ov_name = match validate_name(&format!("{}-{}", name, preset_ov.name)) {
+                Ok(ov_name) => ov_name,
+                Err(_) => {
+                    report.skipped.push(format!(
+                        "Override '{}' was left out because its name would be too long.",
+                        preset_ov.name
+                    ));
+                    continue;
+                }
+            };
+
+            let mut channel_ids = Vec::new();
+            let mut missing = 0;
+            for channel_id in preset_ov.channel_ids {
+                if channel_exists(bot, guild_id, channel_id).await? {
+                    channel_ids.push(channel_id);
+                } else {
+                    missing += 1;
+                }
+            }
+            if missing != 0 {
+                report.skipped.push(format!(
+                    "Override '{ov_name}' is missing {missing} channel(s) that don't exist in this server."
+                ));
+            }
+
+            let ov =
+                StarboardOverride::create(&mut *con, guild_id_i64, &ov_name, starboard.id).await?;
+            let Some(ov) = ov else {
+                report.skipped.push(format!(
+                    "Override '{ov_name}' was left out because an override with that name already exists."
+                ));
+                continue;
+            };
+
+            StarboardOverride::set_channels(&mut *con, guild_id_i64, &ov.name, &channel_ids)
+                .await?;
+            StarboardOverride::update_settings(&mut *con, ov.id, preset_ov.settings).await?;
+        }
+
+        Ok(Some((starboard, report)))
+    }
+}
@@ -1,11 +1,19 @@
 use std::sync::Arc;
 
-use twilight_model::gateway::payload::incoming::{ReactionAdd, ReactionRemove};
+use twilight_model::{
+    gateway::payload::incoming::{
+        ReactionAdd, ReactionRemove, ReactionRemoveAll, ReactionRemoveEmoji,
+    },
+    id::{
+        marker::{GuildMarker, MessageMarker},
+        Id,
+    },
+};
 
 use crate::{
     client::bot::StarboardBot,
     core::{emoji::SimpleEmoji, premium::is_premium::is_guild_premium, stats::refresh_xp},
-    database::{DbMember, DbMessage, DbUser, Vote},
+    database::{DbMember, DbMessage, DbUser, Vote, VoteEvent},
     errors::StarboardResult,
     utils::{id_as_i64::GetI64, into_id::IntoId},
 };
@@ -14,7 +22,7 @@ use super::{
     config::StarboardConfig,
     handle::RefreshMessage,
     message::get_or_create_original,
-    vote_status::{VoteContext, VoteStatus},
+    vote_status::{VoteContext, VoteStatus, WeightedConfigs},
 };
 
 pub async fn handle_reaction_add(
@@ -70,8 +78,9 @@ pub async fn handle_reaction_add(
         }
     };
 
-    let configs =
-        StarboardConfig::list_for_channel(&bot, guild_id, orig_msg.channel_id.into_id()).await?;
+    let configs = reaction_configs(
+        StarboardConfig::list_for_channel(&bot, guild_id, orig_msg.channel_id.into_id()).await?,
+    );
     let vote = VoteContext {
         emoji: &emoji,
         reactor_id: event.user_id,
@@ -88,8 +97,8 @@ pub async fn handle_reaction_add(
     let author_id = orig_msg.author_id;
 
     match status {
-        VoteStatus::Ignore => (),
-        VoteStatus::Remove => {
+        VoteStatus::Ignore(_) => (),
+        VoteStatus::Remove(_) => {
             let _ = bot
                 .http
                 .delete_reaction(
@@ -106,29 +115,7 @@ pub async fn handle_reaction_add(
             DbUser::create(&bot.pool, reactor_user_id, reactor_member.user.bot).await?;
             DbMember::create(&bot.pool, reactor_user_id, guild_id.get_i64()).await?;
 
-            // create the votes
-            for config in &upvote {
-                Vote::create(
-                    &bot.pool,
-                    orig_msg.message_id,
-                    config.starboard.id,
-                    reactor_user_id,
-                    orig_msg.author_id,
-                    false,
-                )
-                .await?;
-            }
-            for config in &downvote {
-                Vote::create(
-                    &bot.pool,
-                    orig_msg.message_id,
-                    config.starboard.id,
-                    reactor_user_id,
-                    orig_msg.author_id,
-                    true,
-                )
-                .await?;
-            }
+            save_votes(&bot, &orig_msg, reactor_user_id, &upvote, &downvote, false).await?;
 
             let is_premium = is_guild_premium(&bot, guild_id.get_i64(), true).await?;
             let mut refresh = RefreshMessage::new(bot.clone(), event.message_id, is_premium);
@@ -177,8 +164,11 @@ pub async fn handle_reaction_remove(
         VoteStatus::Valid((upvote, downvote)) => {
             let user_id = event.user_id.get_i64();
             let all_configs: Vec<_> = upvote.into_iter().chain(downvote).collect();
-            for config in &all_configs {
-                Vote::delete(&bot.pool, orig.message_id, config.starboard.id, user_id).await?;
+            // only votes cast with reactions are removed, since button votes
+            // don't have a reaction behind them
+            for (config, _) in &all_configs {
+                remove_vote(&bot, orig.message_id, config.starboard.id, user_id, Some(false))
+                    .await?;
             }
 
             let is_premim = is_guild_premium(&bot, guild_id.get_i64(), true).await?;
@@ -187,10 +177,187 @@ pub async fn handle_reaction_remove(
             refresh.set_configs(configs.into_iter().map(Arc::new).collect());
             refresh.refresh(false).await?;
         }
-        VoteStatus::Ignore | VoteStatus::Remove => (),
+        VoteStatus::Ignore(_) | VoteStatus::Remove(_) => (),
     }
 
     refresh_xp(&bot, guild_id, author.user_id.into_id()).await?;
 
     Ok(())
 }
+
+/// Leaves out the starboards that are voted on with buttons, since reactions
+/// don't count as votes on them.
+pub fn reaction_configs(configs: Vec<StarboardConfig>) -> Vec<StarboardConfig> {
+    configs
+        .into_iter()
+        .filter(|c| !c.resolved.vote_buttons)
+        .collect()
+}
+
+pub async fn handle_reaction_remove_all(
+    bot: Arc<StarboardBot>,
+    event: ReactionRemoveAll,
+) -> StarboardResult<()> {
+    let Some(guild_id) = event.guild_id else {
+        return Ok(());
+    };
+
+    // votes aren't tied to the reaction they came from, so clearing the
+    // reactions removes every reaction vote on the message
+    clear_votes(bot, guild_id, event.message_id, None).await
+}
+
+pub async fn handle_reaction_remove_emoji(
+    bot: Arc<StarboardBot>,
+    event: ReactionRemoveEmoji,
+) -> StarboardResult<()> {
+    let emoji = SimpleEmoji::from(event.emoji);
+    if !StarboardConfig::is_guild_vote_emoji(&bot, event.guild_id.get_i64(), &emoji).await? {
+        return Ok(());
+    }
+
+    clear_votes(bot, event.guild_id, event.message_id, Some(&emoji)).await
+}
+
+/// Deletes the reaction votes on a message after its reactions were cleared
+/// by a moderator. If `emoji` is set, only the votes that the emoji counts as
+/// on each starboard are deleted. Votes cast with buttons are kept.
+async fn clear_votes(
+    bot: Arc<StarboardBot>,
+    guild_id: Id<GuildMarker>,
+    message_id: Id<MessageMarker>,
+    emoji: Option<&SimpleEmoji>,
+) -> StarboardResult<()> {
+    let Some(orig) = DbMessage::get_original(&bot.pool, message_id.get_i64()).await? else {
+        return Ok(());
+    };
+    // clearing the reactions on a starboard message doesn't affect the votes
+    // on the original, and frozen messages keep their votes
+    if orig.message_id != message_id.get_i64() || orig.frozen {
+        return Ok(());
+    }
+
+    let configs =
+        StarboardConfig::list_for_channel(&bot, guild_id, orig.channel_id.into_id()).await?;
+
+    let mut removed = Vec::new();
+    for config in &configs {
+        let is_downvote = match emoji {
+            None => None,
+            Some(emoji) if config.resolved.upvote_emojis.iter().any(|e| emoji == e) => Some(false),
+            Some(emoji) if config.resolved.downvote_emojis.iter().any(|e| emoji == e) => Some(true),
+            Some(_) => continue,
+        };
+
+        removed.extend(
+            Vote::delete_reactions_by_message(
+                &bot.pool,
+                orig.message_id,
+                &[config.starboard.id],
+                is_downvote,
+            )
+            .await?,
+        );
+    }
+
+    if removed.is_empty() {
+        return Ok(());
+    }
+
+    for vote in removed {
+        VoteEvent::create(
+            &bot.pool,
+            vote.message_id,
+            vote.starboard_id,
+            vote.user_id,
+            VoteEvent::REMOVE,
+            vote.is_downvote,
+            vote.weight,
+            None,
+        )
+        .await?;
+    }
+
+    let author_id = orig.author_id;
+    let is_premium = is_guild_premium(&bot, guild_id.get_i64(), true).await?;
+    let mut refresh = RefreshMessage::new(bot.clone(), orig.message_id.into_id(), is_premium);
+    refresh.set_configs(configs.into_iter().map(Arc::new).collect());
+    refresh.set_sql_message(orig);
+    refresh.refresh(false).await?;
+
+    refresh_xp(&bot, guild_id, author_id.into_id()).await?;
+
+    Ok(())
+}
+
+/// Saves a valid vote to every starboard it applies to, and logs the change.
+pub async fn save_votes(
+    bot: &StarboardBot,
+    orig_msg: &DbMessage,
+    user_id: i64,
+    upvote: &WeightedConfigs<'_>,
+    downvote: &WeightedConfigs<'_>,
+    from_button: bool,
+) -> StarboardResult<()> {
+    let votes = upvote
+        .iter()
+        .map(|vote| (vote, false))
+        .chain(downvote.iter().map(|vote| (vote, true)));
+    for ((config, weight), is_downvote) in votes {
+        let change = Vote::create(
+            &bot.pool,
+            orig_msg.message_id,
+            config.starboard.id,
+            user_id,
+            orig_msg.author_id,
+            is_downvote,
+            *weight,
+            from_button,
+            None,
+        )
+        .await?;
+        VoteEvent::create_from_change(
+            &bot.pool,
+            change,
+            orig_msg.message_id,
+            config.starboard.id,
+            user_id,
+            is_downvote,
+            *weight,
+            None,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Removes a user's vote from a starboard, and logs the removal. If
+/// `from_button` is set, the vote is only removed if it was cast that way.
+/// Returns the vote that was removed, if any.
+pub async fn remove_vote(
+    bot: &StarboardBot,
+    message_id: i64,
+    starboard_id: i32,
+    user_id: i64,
+    from_button: Option<bool>,
+) -> StarboardResult<Option<Vote>> {
+    let vote = Vote::delete(&bot.pool, message_id, starboard_id, user_id, from_button).await?;
+    let Some(vote) = vote else {
+        return Ok(None);
+    };
+
+    VoteEvent::create(
+        &bot.pool,
+        vote.message_id,
+        vote.starboard_id,
+        vote.user_id,
+        VoteEvent::REMOVE,
+        vote.is_downvote,
+        vote.weight,
+        None,
+    )
+    .await?;
+
+    Ok(Some(vote))
+}
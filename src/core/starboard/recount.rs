@@ -1,14 +1,16 @@
 use std::{sync::Arc, time::Duration};
 
+use chrono::{DateTime, Utc};
+
 use twilight_model::id::{
-    marker::{ChannelMarker, GuildMarker, MessageMarker},
+    marker::{ChannelMarker, GuildMarker, MessageMarker, UserMarker},
     Id,
 };
 
 use crate::{
     client::bot::StarboardBot,
     core::{emoji::SimpleEmoji, premium::is_premium::is_guild_premium},
-    database::{DbMember, DbMessage, DbUser, Vote},
+    database::{DbMember, DbMessage, DbUser, Vote, VoteEvent},
     errors::StarboardResult,
     utils::{id_as_i64::GetI64, into_id::IntoId},
 };
@@ -17,6 +19,7 @@ use super::{
     config::StarboardConfig,
     handle::RefreshMessage,
     message::get_or_create_original,
+    reaction_events::reaction_configs,
     vote_status::{VoteContext, VoteStatus},
 };
 
@@ -76,9 +79,9 @@ pub async fn recount_votes(
                 (channel_id, message_id),
                 &orig,
                 &configs,
-                guild_id,
                 author_is_bot,
                 emoji,
+                None,
             )
             .await?;
         }
@@ -92,15 +95,20 @@ pub async fn recount_votes(
     Ok(RecountResult::Done)
 }
 
-async fn recount_votes_reaction(
+/// Recounts the votes for a single reaction, and returns the users (excluding
+/// bots) that reacted with it. Votes that didn't exist yet are dated by
+/// `voted_at` if it's set, or now otherwise.
+pub async fn recount_votes_reaction(
     bot: &StarboardBot,
     refreshing: (Id<ChannelMarker>, Id<MessageMarker>),
     orig: &DbMessage,
     configs: &[StarboardConfig],
-    guild_id: Id<GuildMarker>,
     author_is_bot: bool,
     emoji: SimpleEmoji,
-) -> StarboardResult<()> {
+    voted_at: Option<DateTime<Utc>>,
+) -> StarboardResult<Vec<Id<UserMarker>>> {
+    let configs = reaction_configs(configs.to_vec());
+    let mut reactors = Vec::new();
     let mut last_user = None;
     let reactable = emoji.reactable();
     loop {
@@ -119,6 +127,7 @@ async fn recount_votes_reaction(
             if user.bot {
                 continue;
             }
+            reactors.push(user.id);
 
             let vote = VoteContext {
                 emoji: &emoji,
@@ -130,7 +139,7 @@ async fn recount_votes_reaction(
                 message_has_image: None,
                 message_is_frozen: orig.frozen,
             };
-            let status = VoteStatus::get_vote_status(bot, vote, configs).await?;
+            let status = VoteStatus::get_vote_status(bot, vote, &configs).await?;
 
             let VoteStatus::Valid((upvotes, downvotes)) = status else {
             continue;
@@ -138,31 +147,38 @@ async fn recount_votes_reaction(
 
             let user_id = user.id.get_i64();
             DbUser::create(&bot.pool, user_id, user.bot).await?;
-            DbMember::create(&bot.pool, user_id, guild_id.get_i64()).await?;
-
-            for config in &upvotes {
-                Vote::create(
+            DbMember::create(&bot.pool, user_id, orig.guild_id).await?;
+
+            let votes = upvotes
+                .iter()
+                .map(|vote| (vote, false))
+                .chain(downvotes.iter().map(|vote| (vote, true)));
+            for ((config, weight), is_downvote) in votes {
+                let change = Vote::create(
                     &bot.pool,
                     orig.message_id,
                     config.starboard.id,
                     user_id,
                     orig.author_id,
+                    is_downvote,
+                    *weight,
                     false,
+                    voted_at,
                 )
                 .await?;
-            }
-            for config in &downvotes {
-                Vote::create(
+                VoteEvent::create_from_change(
                     &bot.pool,
+                    change,
                     orig.message_id,
                     config.starboard.id,
                     user_id,
-                    orig.author_id,
-                    true,
+                    is_downvote,
+                    *weight,
+                    voted_at,
                 )
                 .await?;
             }
         }
     }
-    Ok(())
+    Ok(reactors)
 }
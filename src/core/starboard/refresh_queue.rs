@@ -0,0 +1,84 @@
+//! Trailing refreshes for starboard messages that couldn't be edited right
+//! away because of the message edit cooldown.
+
+use std::sync::Arc;
+
+use dashmap::{mapref::entry::Entry, DashMap};
+
+use crate::{client::bot::StarboardBot, constants, utils::into_id::IntoId};
+
+use super::handle::RefreshMessage;
+
+#[derive(Clone, Copy)]
+struct QueuedRefresh {
+    /// The id of the original message.
+    message_id: i64,
+    starboard_id: i32,
+    force: bool,
+}
+
+#[derive(Default)]
+pub struct RefreshQueue {
+    /// Maps the id of a starboard message to the refresh queued for it.
+    pending: DashMap<i64, QueuedRefresh>,
+}
+
+impl RefreshQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a refresh for a single starboard message. If a refresh is already
+    /// queued for that message the two are merged, so a burst of votes results
+    /// in a single trailing edit. Posts on other starboards aren't affected.
+    pub fn schedule(
+        bot: &Arc<StarboardBot>,
+        starboard_message_id: i64,
+        message_id: i64,
+        starboard_id: i32,
+        force: bool,
+        is_premium: bool,
+    ) {
+        match bot.refresh_queue.pending.entry(starboard_message_id) {
+            Entry::Occupied(mut entry) => {
+                entry.get_mut().force |= force;
+                return;
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(QueuedRefresh {
+                    message_id,
+                    starboard_id,
+                    force,
+                });
+            }
+        }
+
+        let bot = bot.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(constants::REFRESH_QUEUE_DELAY).await;
+
+            let Some((_, queued)) = bot.refresh_queue.pending.remove(&starboard_message_id) else {
+                return;
+            };
+
+            let mut refresh =
+                RefreshMessage::new(bot.clone(), queued.message_id.into_id(), is_premium);
+            match refresh
+                .refresh_starboard(queued.starboard_id, queued.force)
+                .await
+            {
+                Ok(true) => (),
+                // another refresh was already running, so try again later
+                Ok(false) => Self::schedule(
+                    &bot,
+                    starboard_message_id,
+                    queued.message_id,
+                    queued.starboard_id,
+                    queued.force,
+                    is_premium,
+                ),
+                Err(why) => bot.handle_error(&why).await,
+            }
+        });
+    }
+}
@@ -0,0 +1,133 @@
+//! Rebuilds the existing posts on a starboard, so that changes to its style
+//! apply to old posts too.
+
+use std::{collections::HashMap, sync::Arc};
+
+use crate::{
+    client::bot::StarboardBot,
+    constants,
+    core::premium::is_premium::is_guild_premium,
+    database::{DbMessage, StarboardMessage},
+    errors::StarboardResult,
+    utils::into_id::IntoId,
+};
+
+use super::{
+    config::StarboardConfig,
+    handle::{RefreshMessage, RegenerateResult},
+};
+
+#[derive(Default, Clone, Copy)]
+pub struct RegenerateProgress {
+    /// How many posts there are to regenerate.
+    pub total: u64,
+    pub edited: u64,
+    pub resent: u64,
+    pub skipped: u64,
+}
+
+impl RegenerateProgress {
+    pub fn processed(&self) -> u64 {
+        self.edited + self.resent + self.skipped
+    }
+}
+
+pub struct Regenerate {
+    pub bot: Arc<StarboardBot>,
+    pub guild_id: i64,
+    pub starboard_id: i32,
+    /// Only regenerate posts for messages sent after this message.
+    pub since: Option<i64>,
+    pub progress: RegenerateProgress,
+}
+
+impl Regenerate {
+    /// Regenerate every post, calling `report` every so often with the current
+    /// progress.
+    pub async fn run<F, Fut>(&mut self, mut report: F) -> StarboardResult<()>
+    where
+        F: FnMut(RegenerateProgress) -> Fut,
+        Fut: std::future::Future<Output = ()>,
+    {
+        let posts =
+            StarboardMessage::list_by_starboard(&self.bot.pool, self.starboard_id, self.since)
+                .await?;
+        self.progress.total = posts.len() as u64;
+        report(self.progress).await;
+
+        let is_premium = is_guild_premium(&self.bot, self.guild_id, true).await?;
+        let mut channel_configs = ChannelConfigs::new();
+        for post in posts {
+            let ret = self
+                .regenerate_post(post, is_premium, &mut channel_configs)
+                .await;
+            match ret {
+                Ok(RegenerateResult::Edited) => self.progress.edited += 1,
+                Ok(RegenerateResult::Resent) => self.progress.resent += 1,
+                Ok(RegenerateResult::Skipped) => self.progress.skipped += 1,
+                Err(why) => {
+                    self.progress.skipped += 1;
+                    self.bot.handle_error(&why).await;
+                }
+            }
+
+            if self.progress.processed() % constants::REGENERATE_REPORT_EVERY == 0 {
+                report(self.progress).await;
+            }
+            tokio::time::sleep(constants::REGENERATE_PAUSE).await;
+        }
+
+        Ok(())
+    }
+
+    async fn regenerate_post(
+        &self,
+        post: StarboardMessage,
+        is_premium: bool,
+        channel_configs: &mut ChannelConfigs,
+    ) -> StarboardResult<RegenerateResult> {
+        let refresh = refresh_for_post(&self.bot, &post, is_premium, channel_configs).await?;
+        let Some(mut refresh) = refresh else {
+            return Ok(RegenerateResult::Skipped);
+        };
+
+        refresh.regenerate(self.starboard_id).await
+    }
+}
+
+/// The configs for each channel that posts have been found in, so that they
+/// don't have to be fetched for every post.
+pub type ChannelConfigs = HashMap<i64, Vec<Arc<StarboardConfig>>>;
+
+/// Prepares a `RefreshMessage` for the original message of a post.
+pub async fn refresh_for_post(
+    bot: &Arc<StarboardBot>,
+    post: &StarboardMessage,
+    is_premium: bool,
+    channel_configs: &mut ChannelConfigs,
+) -> StarboardResult<Option<RefreshMessage>> {
+    let Some(orig) = DbMessage::get(&bot.pool, post.message_id).await? else {
+        return Ok(None);
+    };
+
+    let configs = match channel_configs.get(&orig.channel_id) {
+        Some(configs) => configs.clone(),
+        None => {
+            let configs = StarboardConfig::list_for_channel(
+                bot,
+                orig.guild_id.into_id(),
+                orig.channel_id.into_id(),
+            )
+            .await?;
+            let configs: Vec<_> = configs.into_iter().map(Arc::new).collect();
+            channel_configs.insert(orig.channel_id, configs.clone());
+            configs
+        }
+    };
+
+    let mut refresh = RefreshMessage::new(bot.clone(), orig.message_id.into_id(), is_premium);
+    refresh.set_configs(configs);
+    refresh.set_sql_message(orig);
+
+    Ok(Some(refresh))
+}
@@ -30,11 +30,84 @@ pub struct VoteContext<'a> {
     pub message_is_frozen: bool,
 }
 
+/// Configs that a vote applies to, along with the weight of the vote (the
+/// voter's weight multiplied by the points of the emoji that was used).
+pub type WeightedConfigs<'a> = Vec<(&'a StarboardConfig, f32)>;
+
+/// Why a vote didn't count towards a starboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoteRejection {
+    Frozen,
+    SelfVote,
+    AuthorIsBot,
+    MissingImage,
+    TooNew,
+    TooOld,
+    VoterCantGiveVotes,
+    AuthorCantReceiveVotes,
+    FailedFilters,
+    Cooldown,
+}
+
+impl VoteRejection {
+    pub fn describe(self) -> &'static str {
+        match self {
+            Self::Frozen => "The message is frozen.",
+            Self::SelfVote => "Users can't vote on their own messages.",
+            Self::AuthorIsBot => "Messages from bots can't be voted on.",
+            Self::MissingImage => "Only messages with images can be voted on.",
+            Self::TooNew => "The message is too new to be voted on.",
+            Self::TooOld => "The message is too old to be voted on.",
+            Self::VoterCantGiveVotes => "The voter doesn't have permission to vote.",
+            Self::AuthorCantReceiveVotes => "The author doesn't have permission to receive votes.",
+            Self::FailedFilters => "The vote didn't pass the filters.",
+            Self::Cooldown => "The voter is on cooldown.",
+        }
+    }
+}
+
+/// Checks the settings of a starboard that decide whether a message can be
+/// voted on. `voter_id` is only used to check for self-votes.
+pub fn check_message_rules(
+    config: &StarboardConfig,
+    voter_id: Option<Id<UserMarker>>,
+    message_id: Id<MessageMarker>,
+    message_author_id: Id<UserMarker>,
+    message_author_is_bot: bool,
+    message_has_image: Option<bool>,
+) -> Option<VoteRejection> {
+    // message age in seconds
+    let message_age = message_id.age().as_secs();
+
+    let min_age = config.resolved.older_than;
+    let max_age = config.resolved.newer_than;
+
+    if !config.resolved.self_vote && voter_id == Some(message_author_id) {
+        return Some(VoteRejection::SelfVote);
+    }
+    if !config.resolved.allow_bots && message_author_is_bot {
+        return Some(VoteRejection::AuthorIsBot);
+    }
+    if config.resolved.require_image && message_has_image != Some(true) {
+        return Some(VoteRejection::MissingImage);
+    }
+    if min_age > 0 && message_age <= min_age as u64 {
+        return Some(VoteRejection::TooNew);
+    }
+    if max_age > 0 && message_age >= max_age as u64 {
+        return Some(VoteRejection::TooOld);
+    }
+
+    None
+}
+
 #[derive(Debug)]
 pub enum VoteStatus<'a> {
-    Ignore,
-    Remove,
-    Valid((Vec<&'a StarboardConfig>, Vec<&'a StarboardConfig>)),
+    /// The vote should be ignored, along with the reasons it didn't count.
+    Ignore(Vec<VoteRejection>),
+    /// The vote should be removed, along with the reasons it didn't count.
+    Remove(Vec<VoteRejection>),
+    Valid((WeightedConfigs<'a>, WeightedConfigs<'a>)),
 }
 
 impl<'a> VoteStatus<'a> {
@@ -44,7 +117,7 @@ impl<'a> VoteStatus<'a> {
         configs: &'a [StarboardConfig],
     ) -> StarboardResult<VoteStatus<'a>> {
         if vote.message_is_frozen {
-            return Ok(VoteStatus::Ignore);
+            return Ok(VoteStatus::Ignore(vec![VoteRejection::Frozen]));
         }
 
         let message_has_image = match vote.message_has_image {
@@ -59,7 +132,7 @@ impl<'a> VoteStatus<'a> {
                 .map(|msg| has_image(&msg.embeds, &msg.attachments)),
         };
 
-        let mut invalid_exists = false;
+        let mut rejections = Vec::new();
         let mut allow_remove = true;
 
         #[derive(Clone, Copy, PartialEq, Eq)]
@@ -97,47 +170,28 @@ impl<'a> VoteStatus<'a> {
                 allow_remove = false;
             }
 
-            // message age in seconds
-            let message_age = vote.message_id.age().as_secs();
-
-            let min_age = config.resolved.older_than;
-            let max_age = config.resolved.newer_than;
-
-            let self_vote_valid =
-                config.resolved.self_vote || vote.reactor_id != vote.message_author_id;
-
-            let bots_valid = config.resolved.allow_bots || !vote.message_author_is_bot;
-
-            let images_valid = !config.resolved.require_image || (message_has_image == Some(true));
-
-            let time_valid = {
-                let min_age_valid = if min_age <= 0 {
-                    true
-                } else {
-                    message_age > min_age as u64
-                };
-                let max_age_valid = if max_age <= 0 {
-                    true
-                } else {
-                    message_age < max_age as u64
-                };
-                min_age_valid && max_age_valid
-            };
-
-            if self_vote_valid && bots_valid && images_valid && time_valid {
-                Some((config, vote_type))
-            } else {
-                invalid_exists = true;
-                None
+            let rejection = check_message_rules(
+                config,
+                Some(vote.reactor_id),
+                vote.message_id,
+                vote.message_author_id,
+                vote.message_author_is_bot,
+                message_has_image,
+            );
+            match rejection {
+                None => Some((config, vote_type)),
+                Some(rejection) => {
+                    rejections.push(rejection);
+                    None
+                }
             }
         };
 
         let mut upvote = Vec::new();
         let mut downvote = Vec::new();
 
-        let mut invalid_exists_2 = false;
-
-        for (config, vote_type) in configs.iter().filter_map(eval_config) {
+        let valid_configs: Vec<_> = configs.iter().filter_map(eval_config).collect();
+        for (config, vote_type) in valid_configs {
             // check reactor/author role permissions
             let reactor_perms = Permissions::get_permissions(
                 bot,
@@ -154,8 +208,12 @@ impl<'a> VoteStatus<'a> {
             )
             .await?;
 
-            if !reactor_perms.give_votes || !author_perms.receive_votes {
-                invalid_exists_2 = true;
+            if !reactor_perms.give_votes {
+                rejections.push(VoteRejection::VoterCantGiveVotes);
+                continue;
+            }
+            if !author_perms.receive_votes {
+                rejections.push(VoteRejection::AuthorCantReceiveVotes);
                 continue;
             }
 
@@ -175,7 +233,7 @@ impl<'a> VoteStatus<'a> {
             let status = evaluater.status().await?;
 
             if !status {
-                invalid_exists_2 = true;
+                rejections.push(VoteRejection::FailedFilters);
                 continue;
             }
 
@@ -191,22 +249,24 @@ impl<'a> VoteStatus<'a> {
                     )
                     .is_some()
             {
-                invalid_exists_2 = true;
+                rejections.push(VoteRejection::Cooldown);
                 continue;
             }
 
+            let points = vote.emoji.points(&config.resolved.emoji_points);
+            let weight = reactor_perms.vote_weight * points;
             if vote_type == VoteType::Upvote {
-                upvote.push(config);
+                upvote.push((config, weight));
             } else {
-                downvote.push(config);
+                downvote.push((config, weight));
             }
         }
 
         if upvote.is_empty() && downvote.is_empty() {
-            if (invalid_exists || invalid_exists_2) && allow_remove {
-                Ok(VoteStatus::Remove)
+            if !rejections.is_empty() && allow_remove {
+                Ok(VoteStatus::Remove(rejections))
             } else {
-                Ok(VoteStatus::Ignore)
+                Ok(VoteStatus::Ignore(rejections))
             }
         } else {
             Ok(VoteStatus::Valid((upvote, downvote)))
@@ -69,3 +69,26 @@ pub async fn get_valid_webhook(
 
     Ok(Some(wh))
 }
+
+/// Gets a valid webhook for a starboard, reusing the webhook of another
+/// starboard in the same channel if there is one, before creating one.
+pub async fn get_shared_webhook(
+    bot: &StarboardBot,
+    starboard: &Starboard,
+) -> StarboardResult<Option<Arc<Webhook>>> {
+    if let Some(wh) = get_valid_webhook(bot, starboard, false, false).await? {
+        return Ok(Some(wh));
+    }
+
+    for other in Starboard::list_by_guild(&bot.pool, starboard.guild_id).await? {
+        if other.id == starboard.id || other.channel_id != starboard.channel_id {
+            continue;
+        }
+        if let Some(wh) = get_valid_webhook(bot, &other, false, true).await? {
+            Starboard::set_webhook(&bot.pool, starboard.id, Some(wh.id.get_i64())).await?;
+            return Ok(Some(wh));
+        }
+    }
+
+    get_valid_webhook(bot, starboard, true, false).await
+}
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use twilight_model::id::{
     marker::{GuildMarker, UserMarker},
     Id,
@@ -5,7 +6,7 @@ use twilight_model::id::{
 
 use crate::{
     client::bot::StarboardBot,
-    database::{DbMember, Starboard},
+    database::{DbGuild, DbMember, Starboard},
     errors::StarboardResult,
     utils::id_as_i64::GetI64,
 };
@@ -15,6 +16,7 @@ use super::{premium::is_premium::is_guild_premium, xproles::refresh_xpr};
 #[derive(Default)]
 pub struct MemberStats {
     pub xp: f32,
+    pub season_xp: f32,
     pub given_upvotes: i64,
     pub given_downvotes: i64,
     pub received_upvotes: i64,
@@ -29,6 +31,10 @@ impl MemberStats {
     ) -> StarboardResult<Option<Self>> {
         let mut stats = Self::default();
 
+        let season_start = DbGuild::get(pool, guild_id)
+            .await?
+            .and_then(|g| g.season_started_at);
+
         let starboards = Starboard::list_by_guild(pool, guild_id).await?;
         if starboards.is_empty() {
             return Ok(None);
@@ -43,13 +49,16 @@ impl MemberStats {
             let given_downvotes = Self::given_downvotes(pool, user_id, sb.id).await?;
             let received_upvotes = Self::received_upvotes(pool, user_id, sb.id).await?;
             let received_downvotes = Self::received_downvotes(pool, user_id, sb.id).await?;
+            let received_points = Self::received_points(pool, user_id, sb.id, None).await?;
+            let season_points = Self::received_points(pool, user_id, sb.id, season_start).await?;
 
             stats.given_upvotes += given_upvotes;
             stats.given_downvotes += given_downvotes;
             stats.received_upvotes += received_upvotes;
             stats.received_downvotes += received_downvotes;
 
-            stats.xp += (received_upvotes - received_downvotes) as f32 * sb.settings.xp_multiplier;
+            stats.xp += received_points * sb.settings.xp_multiplier;
+            stats.season_xp += season_points * sb.settings.xp_multiplier;
         }
 
         Ok(Some(stats))
@@ -106,6 +115,27 @@ impl MemberStats {
         .unwrap())
     }
 
+    /// The weighted total of upvotes minus downvotes a user has received,
+    /// optionally only counting votes cast after `since`.
+    async fn received_points(
+        pool: &sqlx::PgPool,
+        user_id: i64,
+        starboard_id: i32,
+        since: Option<DateTime<Utc>>,
+    ) -> StarboardResult<f32> {
+        Ok(sqlx::query!(
+            r#"SELECT COALESCE(SUM(CASE WHEN is_downvote THEN -weight ELSE weight END), 0)
+            as "points!" FROM votes WHERE starboard_id=$1 AND target_author_id=$2
+            AND ($3::timestamptz IS NULL OR created_at >= $3)"#,
+            starboard_id,
+            user_id,
+            since,
+        )
+        .fetch_one(pool)
+        .await?
+        .points)
+    }
+
     async fn received_upvotes(
         pool: &sqlx::PgPool,
         user_id: i64,
@@ -142,7 +172,14 @@ pub async fn refresh_xp(
         return Ok(());
     };
 
-    DbMember::set_xp(&bot.pool, user_id.get_i64(), guild_id.get_i64(), stats.xp).await?;
+    DbMember::set_xp(
+        &bot.pool,
+        user_id.get_i64(),
+        guild_id.get_i64(),
+        stats.xp,
+        stats.season_xp,
+    )
+    .await?;
 
     if is_guild_premium(bot, guild_id.get_i64(), true).await? {
         refresh_xpr(bot, guild_id, user_id).await?;
@@ -5,7 +5,7 @@ use twilight_model::id::{
 
 use crate::{
     client::bot::StarboardBot,
-    database::{DbMember, XPRole},
+    database::{DbGuild, DbMember, XPRole},
     errors::StarboardResult,
     utils::{id_as_i64::GetI64, into_id::IntoId},
 };
@@ -24,10 +24,18 @@ pub async fn refresh_xpr(
     let Some(member) = DbMember::get(&bot.pool, guild_id.get_i64(), user_id.get_i64()).await? else {
         return Ok(());
     };
+    let use_season = DbGuild::get(&bot.pool, guild_id.get_i64())
+        .await?
+        .is_some_and(|g| g.xproles_use_season);
+    let xp = if use_season {
+        member.season_xp
+    } else {
+        member.xp
+    };
 
     for xpr in xproles {
         let role_id = xpr.role_id.into_id();
-        if member.xp >= xpr.required as f32 {
+        if xp >= xpr.required as f32 {
             if member_roles.contains(&role_id) {
                 continue;
             }
@@ -6,11 +6,16 @@ macro_rules! call_with_filters_settings {
             )*
             instant_pass,
             instant_fail,
+            passes_all_of_groups,
+            passes_some_of_groups,
+            passes_none_of_groups,
             user_has_all_of,
             user_has_some_of,
             user_missing_all_of,
             user_missing_some_of,
             user_is_bot,
+            user_account_older_than,
+            user_joined_older_than,
 
             in_channel,
             not_in_channel,
@@ -22,13 +27,25 @@ macro_rules! call_with_filters_settings {
             max_length,
             matches,
             not_matches,
+            contains_link,
+            contains_invite,
+            allowed_link_domains,
+            denied_link_domains,
+            mentions_users,
+            mentions_roles,
+            has_sticker,
+            attachment_types,
+            attachment_extensions,
+            is_reply,
 
             voter_has_all_of,
             voter_has_some_of,
             voter_missing_all_of,
             voter_missing_some_of,
             older_than,
-            newer_than
+            newer_than,
+            voter_account_older_than,
+            voter_joined_older_than
         )
     }
 }
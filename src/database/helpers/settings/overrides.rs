@@ -17,6 +17,7 @@ macro_rules! call_with_override_settings {
             required_remove,
             upvote_emojis,
             downvote_emojis,
+            emoji_points,
             self_vote,
             allow_bots,
             require_image,
@@ -27,6 +28,7 @@ macro_rules! call_with_override_settings {
             enabled,
             autoreact_upvote,
             autoreact_downvote,
+            vote_buttons,
             remove_invalid_reactions,
             link_deletes,
             link_edits,
@@ -5,8 +5,9 @@ pub mod validation;
 pub use models::{
     autostar_channel::AutoStarChannel, exclusive_group::ExclusiveGroup, guild::DbGuild,
     member::DbMember, message::DbMessage, patron::Patron, permrole::PermRole,
-    permrole_starboard::PermRoleStarboard, posrole::PosRole, starboard::Starboard,
-    starboard_message::StarboardMessage, starboard_override::StarboardOverride,
-    starboard_override_values::OverrideValues, starboard_settings::StarboardSettings, user::DbUser,
-    vote::Vote, xprole::XPRole,
+    permrole_starboard::PermRoleStarboard, posrole::PosRole, season_standing::SeasonStanding,
+    starboard::Starboard, starboard_message::StarboardMessage,
+    starboard_override::StarboardOverride, starboard_override_values::OverrideValues,
+    starboard_preset::StarboardPreset, starboard_settings::StarboardSettings, user::DbUser,
+    vote::Vote, vote_event::VoteEvent, xprole::XPRole,
 };
@@ -0,0 +1,113 @@
+use chrono::{DateTime, Utc};
+
+pub struct AuditLogEntry {
+    pub id: i32,
+    pub guild_id: i64,
+    pub user_id: i64,
+    /// The full name of the command, such as "starboards edit behavior".
+    pub command: String,
+    pub action: String,
+    pub target_kind: String,
+    pub target_id: i64,
+    /// The name of the target at the time of the change.
+    pub target_name: String,
+    /// The settings that changed, as `{"setting": {"old": .., "new": ..}}`.
+    pub changes: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+    /// Whether the change was reverted by `/utils undo`.
+    pub undone: bool,
+}
+
+impl AuditLogEntry {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create<'c, E>(
+        executor: E,
+        guild_id: i64,
+        user_id: i64,
+        command: &str,
+        action: &str,
+        target_kind: &str,
+        target_id: i64,
+        target_name: &str,
+        changes: serde_json::Value,
+    ) -> sqlx::Result<Self>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
+        sqlx::query_as!(
+            Self,
+            "INSERT INTO audit_log
+            (guild_id, user_id, command, action, target_kind, target_id, target_name, changes)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8) RETURNING *",
+            guild_id,
+            user_id,
+            command,
+            action,
+            target_kind,
+            target_id,
+            target_name,
+            changes,
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    /// Lists the newest entries first, optionally only for a target name or
+    /// a user. Filters match the name of their group.
+    pub async fn list_by_guild(
+        pool: &sqlx::PgPool,
+        guild_id: i64,
+        target_name: Option<&str>,
+        user_id: Option<i64>,
+        limit: i64,
+    ) -> sqlx::Result<Vec<Self>> {
+        sqlx::query_as!(
+            Self,
+            "SELECT * FROM audit_log WHERE guild_id=$1
+            AND ($2::TEXT IS NULL OR target_name=$2
+                OR (target_kind='filter' AND starts_with(target_name, $2 || ' (filter ')))
+            AND ($3::BIGINT IS NULL OR user_id=$3)
+            ORDER BY created_at DESC, id DESC LIMIT $4",
+            guild_id,
+            target_name,
+            user_id,
+            limit,
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Lists the newest edits to the given kinds of targets that haven't been
+    /// undone yet.
+    pub async fn list_undoable(
+        pool: &sqlx::PgPool,
+        guild_id: i64,
+        target_kinds: &[String],
+        limit: i64,
+    ) -> sqlx::Result<Vec<Self>> {
+        sqlx::query_as!(
+            Self,
+            "SELECT * FROM audit_log WHERE guild_id=$1 AND action='edit' AND NOT undone
+            AND target_kind=ANY($2) ORDER BY created_at DESC, id DESC LIMIT $3",
+            guild_id,
+            target_kinds,
+            limit,
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Marks the entry as undone. Returns false if it already was.
+    pub async fn set_undone<'c, E>(executor: E, id: i32) -> sqlx::Result<bool>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
+        sqlx::query!(
+            "UPDATE audit_log SET undone=true WHERE id=$1 AND NOT undone RETURNING id",
+            id
+        )
+        .fetch_optional(executor)
+        .await
+        .map(|row| row.is_some())
+    }
+}
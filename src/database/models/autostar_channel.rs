@@ -1,3 +1,4 @@
+use serde::Serialize;
 use sqlx::FromRow;
 
 use crate::{
@@ -7,7 +8,7 @@ use crate::{
     },
 };
 
-#[derive(Debug, FromRow)]
+#[derive(Debug, Clone, FromRow, Serialize)]
 pub struct AutoStarChannel {
     /// serial
     pub id: i32,
@@ -25,12 +26,15 @@ pub struct AutoStarChannel {
 }
 
 impl AutoStarChannel {
-    pub async fn create(
-        pool: &sqlx::PgPool,
+    pub async fn create<'c, E>(
+        executor: E,
         name: &String,
         channel_id: i64,
         guild_id: i64,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "INSERT INTO autostar_channels (name, channel_id, guild_id) VALUES ($1, $2, $3)
@@ -39,26 +43,32 @@ impl AutoStarChannel {
             channel_id,
             guild_id
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn delete(
-        pool: &sqlx::PgPool,
+    pub async fn delete<'c, E>(
+        executor: E,
         name: &String,
         guild_id: i64,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "DELETE FROM autostar_channels WHERE name=$1 AND guild_id=$2 RETURNING *",
             name,
             guild_id,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn update_settings(self, pool: &sqlx::PgPool) -> sqlx::Result<Option<Self>> {
+    pub async fn update_settings<'c, E>(self, executor: E) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         let mut builder =
             sqlx::QueryBuilder::<sqlx::Postgres>::new("UPDATE autostar_channels SET ");
 
@@ -71,7 +81,7 @@ impl AutoStarChannel {
             .push_bind(self.guild_id)
             .push(" RETURNING *");
 
-        let ret = builder.build().fetch_optional(pool).await?;
+        let ret = builder.build().fetch_optional(executor).await?;
 
         if let Some(ret) = ret {
             Ok(Some(AutoStarChannel::from_row(&ret)?))
@@ -80,12 +90,15 @@ impl AutoStarChannel {
         }
     }
 
-    pub async fn rename(
-        pool: &sqlx::PgPool,
+    pub async fn rename<'c, E>(
+        executor: E,
         name: &String,
         guild_id: i64,
         new_name: &String,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "UPDATE autostar_channels SET name=$1 WHERE name=$2 AND guild_id=$3
@@ -94,7 +107,7 @@ impl AutoStarChannel {
             name,
             guild_id,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
@@ -128,18 +141,21 @@ impl AutoStarChannel {
         .await
     }
 
-    pub async fn get_by_name(
-        pool: &sqlx::PgPool,
+    pub async fn get_by_name<'c, E>(
+        executor: E,
         name: &String,
         guild_id: i64,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "SELECT * FROM autostar_channels WHERE guild_id=$1 AND name=$2",
             guild_id,
             name,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
@@ -4,11 +4,14 @@ pub struct AutostarChannelFilterGroup {
 }
 
 impl AutostarChannelFilterGroup {
-    pub async fn create(
-        pool: &sqlx::PgPool,
+    pub async fn create<'c, E>(
+        executor: E,
         filter_group_id: i32,
         autostar_channel_id: i32,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "INSERT INTO autostar_channel_filter_groups (filter_group_id, autostar_channel_id)
@@ -16,15 +19,18 @@ impl AutostarChannelFilterGroup {
             filter_group_id,
             autostar_channel_id,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn delete(
-        pool: &sqlx::PgPool,
+    pub async fn delete<'c, E>(
+        executor: E,
         filter_group_id: i32,
         autostar_channel_id: i32,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "DELETE FROM autostar_channel_filter_groups WHERE filter_group_id=$1 AND
@@ -32,20 +38,23 @@ impl AutostarChannelFilterGroup {
             filter_group_id,
             autostar_channel_id,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn list_by_autostar_channel(
-        pool: &sqlx::PgPool,
+    pub async fn list_by_autostar_channel<'c, E>(
+        executor: E,
         autostar_channel_id: i32,
-    ) -> sqlx::Result<Vec<Self>> {
+    ) -> sqlx::Result<Vec<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "SELECT * FROM autostar_channel_filter_groups WHERE autostar_channel_id=$1",
             autostar_channel_id
         )
-        .fetch_all(pool)
+        .fetch_all(executor)
         .await
     }
 }
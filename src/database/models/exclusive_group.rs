@@ -5,11 +5,10 @@ pub struct ExclusiveGroup {
 }
 
 impl ExclusiveGroup {
-    pub async fn create(
-        pool: &sqlx::PgPool,
-        name: &str,
-        guild_id: i64,
-    ) -> sqlx::Result<Option<Self>> {
+    pub async fn create<'c, E>(executor: E, name: &str, guild_id: i64) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "INSERT INTO exclusive_groups (name, guild_id) VALUES ($1, $2)
@@ -17,31 +16,33 @@ impl ExclusiveGroup {
             name,
             guild_id
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn delete(
-        pool: &sqlx::PgPool,
-        name: &str,
-        guild_id: i64,
-    ) -> sqlx::Result<Option<Self>> {
+    pub async fn delete<'c, E>(executor: E, name: &str, guild_id: i64) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "DELETE FROM exclusive_groups WHERE guild_id=$1 AND name=$2 RETURNING *",
             guild_id,
             name
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn rename(
-        pool: &sqlx::PgPool,
+    pub async fn rename<'c, E>(
+        executor: E,
         guild_id: i64,
         old_name: &str,
         new_name: &str,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "UPDATE exclusive_groups SET name=$1 WHERE guild_id=$2 AND name=$3 RETURNING *",
@@ -49,7 +50,7 @@ impl ExclusiveGroup {
             guild_id,
             old_name
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
@@ -59,18 +60,21 @@ impl ExclusiveGroup {
             .await
     }
 
-    pub async fn get_by_name(
-        pool: &sqlx::PgPool,
+    pub async fn get_by_name<'c, E>(
+        executor: E,
         guild_id: i64,
         name: &str,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "SELECT * FROM exclusive_groups WHERE guild_id=$1 AND name=$2",
             guild_id,
             name
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
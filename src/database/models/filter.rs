@@ -1,10 +1,11 @@
+use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
 use crate::database::helpers::{
     query::build_update::build_update, settings::filters::call_with_filters_settings,
 };
 
-#[derive(FromRow)]
+#[derive(FromRow, Clone, Serialize, Deserialize)]
 pub struct Filter {
     pub id: i32,
 
@@ -14,12 +15,19 @@ pub struct Filter {
     pub instant_pass: bool,
     pub instant_fail: bool,
 
+    // other filter groups
+    pub passes_all_of_groups: Option<Vec<i32>>,
+    pub passes_some_of_groups: Option<Vec<i32>>,
+    pub passes_none_of_groups: Option<Vec<i32>>,
+
     // default context
     pub user_has_all_of: Option<Vec<i64>>,
     pub user_has_some_of: Option<Vec<i64>>,
     pub user_missing_all_of: Option<Vec<i64>>,
     pub user_missing_some_of: Option<Vec<i64>>,
     pub user_is_bot: Option<bool>,
+    pub user_account_older_than: Option<i64>,
+    pub user_joined_older_than: Option<i64>,
 
     // message context
     pub in_channel: Option<Vec<i64>>,
@@ -32,6 +40,16 @@ pub struct Filter {
     pub max_length: Option<i32>,
     pub matches: Option<String>,
     pub not_matches: Option<String>,
+    pub contains_link: Option<bool>,
+    pub contains_invite: Option<bool>,
+    pub allowed_link_domains: Option<Vec<String>>,
+    pub denied_link_domains: Option<Vec<String>>,
+    pub mentions_users: Option<bool>,
+    pub mentions_roles: Option<bool>,
+    pub has_sticker: Option<bool>,
+    pub attachment_types: Option<Vec<String>>,
+    pub attachment_extensions: Option<Vec<String>>,
+    pub is_reply: Option<bool>,
 
     // vote context
     pub voter_has_all_of: Option<Vec<i64>>,
@@ -40,14 +58,33 @@ pub struct Filter {
     pub voter_missing_some_of: Option<Vec<i64>>,
     pub older_than: Option<i64>,
     pub newer_than: Option<i64>,
+    pub voter_account_older_than: Option<i64>,
+    pub voter_joined_older_than: Option<i64>,
 }
 
 impl Filter {
-    pub async fn create(
-        pool: &sqlx::PgPool,
+    /// Every filter group that this filter references.
+    pub fn referenced_groups(&self) -> Vec<i32> {
+        [
+            &self.passes_all_of_groups,
+            &self.passes_some_of_groups,
+            &self.passes_none_of_groups,
+        ]
+        .into_iter()
+        .flatten()
+        .flatten()
+        .copied()
+        .collect()
+    }
+
+    pub async fn create<'c, E>(
+        executor: E,
         filter_group_id: i32,
         position: i16,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "INSERT INTO filters (filter_group_id, position) VALUES ($1, $2)
@@ -55,26 +92,46 @@ impl Filter {
             filter_group_id,
             position,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn delete(
-        pool: &sqlx::PgPool,
+    pub async fn delete<'c, E>(
+        executor: E,
         filter_group_id: i32,
         position: i16,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "DELETE FROM filters WHERE filter_group_id=$1 AND position=$2 RETURNING *",
             filter_group_id,
             position,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn update_settings(self, pool: &sqlx::PgPool) -> sqlx::Result<Option<Self>> {
+    pub async fn delete_by_group<'c, E>(executor: E, filter_group_id: i32) -> sqlx::Result<()>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
+        sqlx::query!(
+            "DELETE FROM filters WHERE filter_group_id=$1",
+            filter_group_id,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn update_settings<'c, E>(self, executor: E) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new("UPDATE filters SET ");
 
         call_with_filters_settings!(build_update, self, builder);
@@ -84,7 +141,7 @@ impl Filter {
             .push_bind(self.id)
             .push(" RETURNING *");
 
-        let ret = builder.build().fetch_optional(pool).await?;
+        let ret = builder.build().fetch_optional(executor).await?;
 
         if let Some(row) = ret {
             Ok(Some(Filter::from_row(&row)?))
@@ -93,28 +150,40 @@ impl Filter {
         }
     }
 
-    pub async fn get_last_position(pool: &sqlx::PgPool, filter_group_id: i32) -> sqlx::Result<i16> {
+    pub async fn get_last_position<'c, E>(executor: E, filter_group_id: i32) -> sqlx::Result<i16>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query!(
             "SELECT MAX(position) as position FROM filters WHERE filter_group_id=$1",
             filter_group_id
         )
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await
         .map(|r| r.position.unwrap_or(0))
     }
 
-    pub async fn get_by_position(
-        pool: &sqlx::PgPool,
+    pub async fn get(pool: &sqlx::PgPool, id: i32) -> sqlx::Result<Option<Self>> {
+        sqlx::query_as!(Self, "SELECT * FROM filters WHERE id=$1", id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    pub async fn get_by_position<'c, E>(
+        executor: E,
         filter_group_id: i32,
         position: i16,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "SELECT * FROM filters WHERE filter_group_id=$1 AND position=$2",
             filter_group_id,
             position
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
@@ -144,7 +213,7 @@ impl Filter {
     }
 
     pub async fn set_position(
-        pool: &sqlx::PgPool,
+        con: &mut sqlx::Transaction<'_, sqlx::Postgres>,
         filter_group_id: i32,
         current: i16,
         new: i16,
@@ -153,18 +222,16 @@ impl Filter {
             return Ok(Some(()));
         }
 
-        let mut tx = pool.begin().await?;
-
         // select for update, locking these rows
         sqlx::query!(
             "SELECT FROM filters WHERE filter_group_id=$1 FOR UPDATE",
             filter_group_id
         )
-        .execute(&mut tx)
+        .execute(&mut *con)
         .await?;
 
         // fetch the item that we're moving
-        let to_move = Self::get_by_position(pool, filter_group_id, current).await?;
+        let to_move = Self::get_by_position(&mut *con, filter_group_id, current).await?;
         let Some(to_move) = to_move else {
             return Ok(None);
         };
@@ -175,7 +242,7 @@ impl Filter {
         } else {
             (current, new, -1)
         };
-        Filter::shift(&mut tx, filter_group_id, start, Some(end), dir).await?;
+        Filter::shift(&mut *con, filter_group_id, start, Some(end), dir).await?;
 
         // update the position
         let ret = sqlx::query!(
@@ -184,12 +251,9 @@ impl Filter {
             to_move.id,
             filter_group_id
         )
-        .execute(&mut tx)
+        .execute(&mut *con)
         .await?;
 
-        // commit & return
-        tx.commit().await?;
-
         if ret.rows_affected() == 0 {
             Ok(None)
         } else {
@@ -197,6 +261,45 @@ impl Filter {
         }
     }
 
+    /// Lists every filter of every filter group in a guild.
+    pub async fn list_by_guild<'c, E>(executor: E, guild_id: i64) -> sqlx::Result<Vec<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
+        sqlx::query_as!(
+            Self,
+            "SELECT filters.* FROM filters JOIN filter_groups
+            ON filters.filter_group_id=filter_groups.id
+            WHERE filter_groups.guild_id=$1 ORDER BY filter_group_id, position ASC",
+            guild_id
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    /// Removes a deleted filter group from the filters that referenced it.
+    pub async fn remove_group_references<'c, E>(
+        executor: E,
+        filter_group_id: i32,
+    ) -> sqlx::Result<()>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
+        sqlx::query!(
+            "UPDATE filters SET
+            passes_all_of_groups=NULLIF(array_remove(passes_all_of_groups, $1), '{}'),
+            passes_some_of_groups=NULLIF(array_remove(passes_some_of_groups, $1), '{}'),
+            passes_none_of_groups=NULLIF(array_remove(passes_none_of_groups, $1), '{}')
+            WHERE $1=ANY(passes_all_of_groups) OR $1=ANY(passes_some_of_groups)
+            OR $1=ANY(passes_none_of_groups)",
+            filter_group_id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
     pub async fn list_by_filter(
         pool: &sqlx::PgPool,
         filter_group_id: i32,
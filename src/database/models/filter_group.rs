@@ -5,11 +5,10 @@ pub struct FilterGroup {
 }
 
 impl FilterGroup {
-    pub async fn create(
-        pool: &sqlx::PgPool,
-        guild_id: i64,
-        name: &str,
-    ) -> sqlx::Result<Option<Self>> {
+    pub async fn create<'c, E>(executor: E, guild_id: i64, name: &str) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "INSERT INTO filter_groups (guild_id, name) VALUES ($1, $2) ON CONFLICT DO NOTHING
@@ -17,33 +16,35 @@ impl FilterGroup {
             guild_id,
             name
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn delete(
-        pool: &sqlx::PgPool,
-        guild_id: i64,
-        name: &str,
-    ) -> sqlx::Result<Option<Self>> {
+    pub async fn delete<'c, E>(executor: E, guild_id: i64, name: &str) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "DELETE FROM filter_groups WHERE guild_id=$1 AND name=$2 RETURNING *",
             guild_id,
             name
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn rename(pool: &sqlx::PgPool, id: i32, new_name: &str) -> sqlx::Result<Self> {
+    pub async fn rename<'c, E>(executor: E, id: i32, new_name: &str) -> sqlx::Result<Self>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "UPDATE filter_groups SET name=$1 WHERE id=$2 RETURNING *",
             new_name,
             id
         )
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await
     }
 
@@ -53,28 +54,34 @@ impl FilterGroup {
             .await
     }
 
-    pub async fn get_by_name(
-        pool: &sqlx::PgPool,
+    pub async fn get_by_name<'c, E>(
+        executor: E,
         guild_id: i64,
         name: &str,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "SELECT * FROM filter_groups WHERE guild_id=$1 AND name=$2",
             guild_id,
             name
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn list_by_guild(pool: &sqlx::PgPool, guild_id: i64) -> sqlx::Result<Vec<Self>> {
+    pub async fn list_by_guild<'c, E>(executor: E, guild_id: i64) -> sqlx::Result<Vec<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "SELECT * FROM filter_groups WHERE guild_id=$1",
             guild_id
         )
-        .fetch_all(pool)
+        .fetch_all(executor)
         .await
     }
 }
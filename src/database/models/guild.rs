@@ -4,16 +4,23 @@ use chrono::{DateTime, Utc};
 pub struct DbGuild {
     pub guild_id: i64,
     pub premium_end: Option<DateTime<Utc>>,
+    pub season: i32,
+    pub season_started_at: Option<DateTime<Utc>>,
+    pub xproles_use_season: bool,
+    pub posroles_use_season: bool,
 }
 
 impl DbGuild {
-    pub async fn create(pool: &sqlx::PgPool, guild_id: i64) -> sqlx::Result<Option<Self>> {
+    pub async fn create<'c, E>(executor: E, guild_id: i64) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "INSERT INTO guilds (guild_id) VALUES ($1) ON CONFLICT DO NOTHING RETURNING *",
             guild_id
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
@@ -22,4 +29,83 @@ impl DbGuild {
             .fetch_optional(pool)
             .await
     }
+
+    pub async fn set_xproles_use_season<'c, E>(
+        executor: E,
+        guild_id: i64,
+        use_season: bool,
+    ) -> sqlx::Result<()>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
+        sqlx::query!(
+            "UPDATE guilds SET xproles_use_season=$1 WHERE guild_id=$2",
+            use_season,
+            guild_id,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_posroles_use_season<'c, E>(
+        executor: E,
+        guild_id: i64,
+        use_season: bool,
+    ) -> sqlx::Result<()>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
+        sqlx::query!(
+            "UPDATE guilds SET posroles_use_season=$1 WHERE guild_id=$2",
+            use_season,
+            guild_id,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Archives the season XP of every member, resets it, and starts the next
+    /// season. Returns the guild as it is after the new season has started.
+    pub async fn end_season(pool: &sqlx::PgPool, guild_id: i64) -> sqlx::Result<Option<Self>> {
+        let mut tx = pool.begin().await?;
+
+        let guild = sqlx::query!(
+            "SELECT season FROM guilds WHERE guild_id=$1 FOR UPDATE",
+            guild_id,
+        )
+        .fetch_optional(&mut tx)
+        .await?;
+        let Some(guild) = guild else {
+            return Ok(None);
+        };
+
+        sqlx::query!(
+            "INSERT INTO season_standings (guild_id, season, user_id, xp)
+            SELECT guild_id, $1, user_id, season_xp FROM members
+            WHERE guild_id=$2 AND season_xp > 0",
+            guild.season,
+            guild_id,
+        )
+        .fetch_all(&mut tx)
+        .await?;
+        sqlx::query!("UPDATE members SET season_xp=0 WHERE guild_id=$1", guild_id)
+            .fetch_all(&mut tx)
+            .await?;
+        let guild = sqlx::query_as!(
+            Self,
+            "UPDATE guilds SET season=season + 1, season_started_at=now()
+            WHERE guild_id=$1 RETURNING *",
+            guild_id,
+        )
+        .fetch_one(&mut tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(guild))
+    }
 }
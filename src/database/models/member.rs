@@ -6,6 +6,7 @@ pub struct DbMember {
     pub guild_id: i64,
     pub xp: f32,
     pub autoredeem_enabled: bool,
+    pub season_xp: f32,
 }
 
 impl DbMember {
@@ -30,11 +31,14 @@ impl DbMember {
         user_id: i64,
         guild_id: i64,
         xp: f32,
+        season_xp: f32,
     ) -> sqlx::Result<Option<Self>> {
         sqlx::query_as!(
             Self,
-            "UPDATE members SET xp=$1 WHERE user_id=$2 AND guild_id=$3 RETURNING *",
+            "UPDATE members SET xp=$1, season_xp=$2 WHERE user_id=$3 AND guild_id=$4
+            RETURNING *",
             xp,
+            season_xp,
             user_id,
             guild_id
         )
@@ -69,6 +73,18 @@ impl DbMember {
         .fetch(pool)
     }
 
+    pub fn stream_by_season_xp(
+        pool: &sqlx::PgPool,
+        guild_id: i64,
+    ) -> BoxStream<'_, sqlx::Result<Self>> {
+        sqlx::query_as!(
+            Self,
+            "SELECT * FROM members WHERE guild_id=$1 AND season_xp > 0 ORDER BY season_xp DESC",
+            guild_id
+        )
+        .fetch(pool)
+    }
+
     pub async fn list_by_xp(
         pool: &sqlx::PgPool,
         guild_id: i64,
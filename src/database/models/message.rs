@@ -1,3 +1,5 @@
+use chrono::{DateTime, Utc};
+
 use crate::database::StarboardMessage;
 
 #[derive(Debug)]
@@ -96,6 +98,43 @@ impl DbMessage {
         .await
     }
 
+    /// List messages that have been voted on (or had a vote removed) since
+    /// `since`, most recently active first. Trashed and frozen messages are
+    /// skipped, since their votes can't change.
+    pub async fn list_recently_voted(
+        pool: &sqlx::PgPool,
+        since: DateTime<Utc>,
+        limit: i64,
+    ) -> sqlx::Result<Vec<Self>> {
+        sqlx::query_as!(
+            Self,
+            "SELECT messages.* FROM messages JOIN (
+                SELECT message_id, max(created_at) AS last_vote FROM vote_events
+                WHERE created_at >= $1 GROUP BY message_id
+            ) recent ON recent.message_id=messages.message_id
+            WHERE trashed=false AND frozen=false
+            ORDER BY recent.last_vote DESC LIMIT $2",
+            since,
+            limit,
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Of the given message ids, lists the ones that belong to original
+    /// messages or starboard messages.
+    pub async fn list_tracked(pool: &sqlx::PgPool, message_ids: &[i64]) -> sqlx::Result<Vec<i64>> {
+        sqlx::query!(
+            r#"SELECT message_id as "message_id!" FROM messages WHERE message_id=ANY($1)
+            UNION SELECT starboard_message_id FROM starboard_messages
+            WHERE starboard_message_id=ANY($1)"#,
+            message_ids,
+        )
+        .fetch_all(pool)
+        .await
+        .map(|rows| rows.into_iter().map(|r| r.message_id).collect())
+    }
+
     pub async fn get_original(pool: &sqlx::PgPool, message_id: i64) -> sqlx::Result<Option<Self>> {
         let orig = if let Some(sb_msg) = StarboardMessage::get(pool, message_id).await? {
             sb_msg.message_id
@@ -1,3 +1,4 @@
+pub mod audit_log_entry;
 pub mod autostar_channel;
 pub mod autostar_channel_filter_group;
 pub mod exclusive_group;
@@ -10,12 +11,15 @@ pub mod patron;
 pub mod permrole;
 pub mod permrole_starboard;
 pub mod posrole;
+pub mod season_standing;
 pub mod starboard;
 pub mod starboard_filter_group;
 pub mod starboard_message;
 pub mod starboard_override;
 pub mod starboard_override_values;
+pub mod starboard_preset;
 pub mod starboard_settings;
 pub mod user;
 pub mod vote;
+pub mod vote_event;
 pub mod xprole;
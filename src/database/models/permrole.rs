@@ -1,8 +1,9 @@
+use serde::Serialize;
 use twilight_model::id::{marker::GuildMarker, Id};
 
 use crate::{client::bot::StarboardBot, utils::into_id::IntoId};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PermRole {
     pub role_id: i64,
     pub guild_id: i64,
@@ -10,14 +11,18 @@ pub struct PermRole {
     pub obtain_xproles: Option<bool>,
     pub give_votes: Option<bool>,
     pub receive_votes: Option<bool>,
+    pub vote_weight: Option<f32>,
 }
 
 impl PermRole {
-    pub async fn create(
-        pool: &sqlx::PgPool,
+    pub async fn create<'c, E>(
+        executor: E,
         role_id: i64,
         guild_id: i64,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "INSERT INTO permroles (role_id, guild_id) VALUES ($1, $2)
@@ -25,31 +30,38 @@ impl PermRole {
             role_id,
             guild_id,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn delete(pool: &sqlx::PgPool, role_id: i64) -> sqlx::Result<Option<Self>> {
+    pub async fn delete<'c, E>(executor: E, role_id: i64) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "DELETE FROM permroles WHERE role_id=$1 RETURNING *",
             role_id
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn update(&self, pool: &sqlx::PgPool) -> sqlx::Result<Option<Self>> {
+    pub async fn update<'c, E>(&self, executor: E) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             r#"UPDATE permroles SET obtain_xproles=$1, give_votes=$2,
-            receive_votes=$3 WHERE role_id=$4 RETURNING *"#,
+            receive_votes=$3, vote_weight=$4 WHERE role_id=$5 RETURNING *"#,
             self.obtain_xproles,
             self.give_votes,
             self.receive_votes,
+            self.vote_weight,
             self.role_id,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
@@ -1,18 +1,24 @@
-#[derive(Debug)]
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
 pub struct PermRoleStarboard {
     pub permrole_id: i64,
     pub starboard_id: i32,
 
     pub give_votes: Option<bool>,
     pub receive_votes: Option<bool>,
+    pub vote_weight: Option<f32>,
 }
 
 impl PermRoleStarboard {
-    pub async fn create(
-        pool: &sqlx::PgPool,
+    pub async fn create<'c, E>(
+        executor: E,
         permrole_id: i64,
         starboard_id: i32,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "INSERT INTO permrole_starboards (permrole_id, starboard_id) VALUES ($1, $2)
@@ -20,40 +26,47 @@ impl PermRoleStarboard {
             permrole_id,
             starboard_id,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn delete(
-        pool: &sqlx::PgPool,
+    pub async fn delete<'c, E>(
+        executor: E,
         permrole_id: i64,
         starboard_id: i32,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "DELETE FROM permrole_starboards WHERE permrole_id=$1 AND starboard_id=$2 RETURNING *",
             permrole_id,
             starboard_id,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn update(&self, pool: &sqlx::PgPool) -> sqlx::Result<Option<Self>> {
-        if self.give_votes.is_none() && self.receive_votes.is_none() {
-            return Self::delete(pool, self.permrole_id, self.starboard_id).await;
+    pub async fn update<'c, E>(&self, executor: E) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
+        if self.give_votes.is_none() && self.receive_votes.is_none() && self.vote_weight.is_none() {
+            return Self::delete(executor, self.permrole_id, self.starboard_id).await;
         }
 
         sqlx::query_as!(
             Self,
-            r#"UPDATE permrole_starboards SET give_votes=$1, receive_votes=$2 WHERE permrole_id=$3
-            AND starboard_id=$4 RETURNING *"#,
+            r#"UPDATE permrole_starboards SET give_votes=$1, receive_votes=$2, vote_weight=$3
+            WHERE permrole_id=$4 AND starboard_id=$5 RETURNING *"#,
             self.give_votes,
             self.receive_votes,
+            self.vote_weight,
             self.permrole_id,
             self.starboard_id,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
@@ -72,16 +85,29 @@ impl PermRoleStarboard {
         .await
     }
 
-    pub async fn list_by_permrole(
-        pool: &sqlx::PgPool,
-        permrole_id: i64,
-    ) -> sqlx::Result<Vec<Self>> {
+    pub async fn list_by_permrole<'c, E>(executor: E, permrole_id: i64) -> sqlx::Result<Vec<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "SELECT * FROM permrole_starboards WHERE permrole_id=$1",
             permrole_id,
         )
-        .fetch_all(pool)
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn list_by_starboard<'c, E>(executor: E, starboard_id: i32) -> sqlx::Result<Vec<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
+        sqlx::query_as!(
+            Self,
+            "SELECT * FROM permrole_starboards WHERE starboard_id=$1",
+            starboard_id,
+        )
+        .fetch_all(executor)
         .await
     }
 }
@@ -6,12 +6,15 @@ pub struct PosRole {
 }
 
 impl PosRole {
-    pub async fn create(
-        pool: &sqlx::PgPool,
+    pub async fn create<'c, E>(
+        executor: E,
         role_id: i64,
         guild_id: i64,
         max_members: i32,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "INSERT INTO posroles (role_id, guild_id, max_members) VALUES ($1, $2, $3)
@@ -20,32 +23,38 @@ impl PosRole {
             guild_id,
             max_members,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn set_max_members(
-        pool: &sqlx::PgPool,
+    pub async fn set_max_members<'c, E>(
+        executor: E,
         role_id: i64,
         max_members: i32,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "UPDATE posroles SET max_members=$1 WHERE role_id=$2 RETURNING *",
             max_members,
             role_id,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn delete(pool: &sqlx::PgPool, role_id: i64) -> sqlx::Result<Option<Self>> {
+    pub async fn delete<'c, E>(executor: E, role_id: i64) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "DELETE FROM posroles WHERE role_id=$1 RETURNING *",
             role_id,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
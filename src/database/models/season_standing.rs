@@ -0,0 +1,27 @@
+use futures::stream::BoxStream;
+
+/// A member's final XP for a season that has ended.
+#[derive(Debug)]
+pub struct SeasonStanding {
+    pub guild_id: i64,
+    pub season: i32,
+    pub user_id: i64,
+    pub xp: f32,
+}
+
+impl SeasonStanding {
+    pub fn stream_by_xp(
+        pool: &sqlx::PgPool,
+        guild_id: i64,
+        season: i32,
+    ) -> BoxStream<'_, sqlx::Result<Self>> {
+        sqlx::query_as!(
+            Self,
+            "SELECT * FROM season_standings WHERE guild_id=$1 AND season=$2
+            ORDER BY xp DESC",
+            guild_id,
+            season,
+        )
+        .fetch(pool)
+    }
+}
@@ -61,12 +61,15 @@ macro_rules! starboard_from_row {
 pub(crate) use starboard_from_row;
 
 impl Starboard {
-    pub async fn create(
-        pool: &sqlx::PgPool,
+    pub async fn create<'c, E>(
+        executor: E,
         name: &String,
         channel_id: i64,
         guild_id: i64,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         let starboard = sqlx::query!(
             "INSERT INTO STARBOARDS (name, channel_id, guild_id) VALUES ($1, $2, $3)
             ON CONFLICT DO NOTHING RETURNING *",
@@ -74,7 +77,7 @@ impl Starboard {
             channel_id,
             guild_id,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await?;
 
         if let Some(row) = starboard {
@@ -84,22 +87,28 @@ impl Starboard {
         }
     }
 
-    pub async fn delete(
-        pool: &sqlx::PgPool,
+    pub async fn delete<'c, E>(
+        executor: E,
         name: &String,
         guild_id: i64,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query!(
             "DELETE FROM starboards WHERE name=$1 AND guild_id=$2 RETURNING *",
             name,
             guild_id,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
         .map(|row| row.map(|row| starboard_from_record!(row)))
     }
 
-    pub async fn update_settings(self, pool: &sqlx::PgPool) -> sqlx::Result<Option<Self>> {
+    pub async fn update_settings<'c, E>(self, executor: E) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new("UPDATE starboards SET ");
 
         call_with_starboard_settings!(build_update, self.settings, builder);
@@ -113,7 +122,7 @@ impl Starboard {
 
         builder
             .build()
-            .fetch_optional(pool)
+            .fetch_optional(executor)
             .await
             .map(|r| r.map(|r| starboard_from_row!(r)))
     }
@@ -133,6 +142,27 @@ impl Starboard {
         .map(|_| ())
     }
 
+    /// Moves the starboard to another channel. The webhook belongs to the old
+    /// channel, so it's cleared.
+    pub async fn set_channel<'c, E>(
+        executor: E,
+        starboard_id: i32,
+        channel_id: i64,
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
+        sqlx::query!(
+            "UPDATE starboards SET channel_id=$1, webhook_id=NULL WHERE id=$2
+            RETURNING *",
+            channel_id,
+            starboard_id,
+        )
+        .fetch_optional(executor)
+        .await
+        .map(|r| r.map(|r| starboard_from_record!(r)))
+    }
+
     pub async fn disable_webhooks(pool: &sqlx::PgPool, starboard_id: i32) -> sqlx::Result<()> {
         sqlx::query!(
             "UPDATE starboards SET use_webhook=false WHERE id=$1",
@@ -143,12 +173,15 @@ impl Starboard {
         .map(|_| ())
     }
 
-    pub async fn rename(
-        pool: &sqlx::PgPool,
+    pub async fn rename<'c, E>(
+        executor: E,
         name: &String,
         guild_id: i64,
         new_name: &String,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query!(
             "UPDATE starboards SET name=$1 WHERE name=$2 AND guild_id=$3
             RETURNING *",
@@ -156,7 +189,7 @@ impl Starboard {
             name,
             guild_id,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
         .map(|r| r.map(|r| starboard_from_record!(r)))
     }
@@ -169,17 +202,20 @@ impl Starboard {
         Ok(result.map(|sb| starboard_from_record!(sb)))
     }
 
-    pub async fn get_by_name(
-        pool: &sqlx::PgPool,
+    pub async fn get_by_name<'c, E>(
+        executor: E,
         name: &str,
         guild_id: i64,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         let result = sqlx::query!(
             "SELECT * FROM starboards WHERE name=$1 AND guild_id=$2",
             name,
             guild_id
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await?;
 
         Ok(result.map(|sb| starboard_from_record!(sb)))
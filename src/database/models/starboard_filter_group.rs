@@ -4,11 +4,14 @@ pub struct StarboardFilterGroup {
 }
 
 impl StarboardFilterGroup {
-    pub async fn create(
-        pool: &sqlx::PgPool,
+    pub async fn create<'c, E>(
+        executor: E,
         filter_group_id: i32,
         starboard_id: i32,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "INSERT INTO starboard_filter_groups (filter_group_id, starboard_id) VALUES ($1, $2)
@@ -16,15 +19,18 @@ impl StarboardFilterGroup {
             filter_group_id,
             starboard_id
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn delete(
-        pool: &sqlx::PgPool,
+    pub async fn delete<'c, E>(
+        executor: E,
         filter_group_id: i32,
         starboard_id: i32,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "DELETE FROM starboard_filter_groups WHERE filter_group_id=$1 AND starboard_id=$2
@@ -32,20 +38,20 @@ impl StarboardFilterGroup {
             filter_group_id,
             starboard_id
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn list_by_starboard(
-        pool: &sqlx::PgPool,
-        starboard_id: i32,
-    ) -> sqlx::Result<Vec<Self>> {
+    pub async fn list_by_starboard<'c, E>(executor: E, starboard_id: i32) -> sqlx::Result<Vec<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "SELECT * FROM starboard_filter_groups WHERE starboard_id=$1",
             starboard_id
         )
-        .fetch_all(pool)
+        .fetch_all(executor)
         .await
     }
 }
@@ -54,6 +54,33 @@ impl StarboardMessage {
         .await
     }
 
+    pub async fn list_by_message(pool: &sqlx::PgPool, message_id: i64) -> sqlx::Result<Vec<Self>> {
+        sqlx::query_as!(
+            Self,
+            "SELECT * FROM starboard_messages WHERE message_id=$1",
+            message_id,
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn list_by_starboard(
+        pool: &sqlx::PgPool,
+        starboard_id: i32,
+        since: Option<i64>,
+    ) -> sqlx::Result<Vec<Self>> {
+        sqlx::query_as!(
+            Self,
+            "SELECT * FROM starboard_messages WHERE starboard_id=$1
+            AND ($2::BIGINT IS NULL OR message_id >= $2)
+            ORDER BY message_id",
+            starboard_id,
+            since,
+        )
+        .fetch_all(pool)
+        .await
+    }
+
     pub async fn get_by_starboard(
         pool: &sqlx::PgPool,
         message_id: i64,
@@ -69,6 +96,22 @@ impl StarboardMessage {
         .await
     }
 
+    pub async fn set_starboard_message_id(
+        pool: &sqlx::PgPool,
+        starboard_message_id: i64,
+        new_starboard_message_id: i64,
+    ) -> sqlx::Result<Option<Self>> {
+        sqlx::query_as!(
+            Self,
+            "UPDATE starboard_messages SET starboard_message_id=$1 WHERE starboard_message_id=$2
+            RETURNING *",
+            new_starboard_message_id,
+            starboard_message_id,
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
     pub async fn set_last_point_count(
         pool: &sqlx::PgPool,
         starboard_message_id: i64,
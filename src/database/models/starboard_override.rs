@@ -1,6 +1,6 @@
 use crate::{concat_format, constants, database::OverrideValues};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct StarboardOverride {
     // serial
     pub id: i32,
@@ -14,12 +14,15 @@ pub struct StarboardOverride {
 }
 
 impl StarboardOverride {
-    pub async fn create(
-        pool: &sqlx::PgPool,
+    pub async fn create<'c, E>(
+        executor: E,
         guild_id: i64,
         name: &String,
         starboard_id: i32,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "INSERT INTO overrides (guild_id, name, starboard_id) VALUES ($1, $2, $3)
@@ -28,31 +31,37 @@ impl StarboardOverride {
             name,
             starboard_id,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn delete(
-        pool: &sqlx::PgPool,
+    pub async fn delete<'c, E>(
+        executor: E,
         guild_id: i64,
         name: &String,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "DELETE FROM overrides WHERE guild_id=$1 AND name=$2 RETURNING *",
             guild_id,
             name,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn rename(
-        pool: &sqlx::PgPool,
+    pub async fn rename<'c, E>(
+        executor: E,
         guild_id: i64,
         old_name: &str,
         new_name: &str,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "UPDATE overrides SET name=$1 WHERE name=$2 AND guild_id=$3 RETURNING *",
@@ -60,7 +69,7 @@ impl StarboardOverride {
             old_name,
             guild_id,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
@@ -77,12 +86,15 @@ impl StarboardOverride {
         }
     }
 
-    pub async fn set_channels(
-        pool: &sqlx::PgPool,
+    pub async fn set_channels<'c, E>(
+        executor: E,
         guild_id: i64,
         name: &str,
         channel_ids: &[i64],
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "UPDATE overrides SET channel_ids=$1 WHERE name=$2 AND guild_id=$3 RETURNING *",
@@ -90,31 +102,37 @@ impl StarboardOverride {
             name,
             guild_id,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn update_settings(
-        pool: &sqlx::PgPool,
+    pub async fn update_settings<'c, E>(
+        executor: E,
         id: i32,
         settings: OverrideValues,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         let settings = serde_json::to_value(&settings).unwrap();
-        Self::update_settings_raw(pool, id, settings).await
+        Self::update_settings_raw(executor, id, settings).await
     }
 
-    pub async fn update_settings_raw(
-        pool: &sqlx::PgPool,
+    pub async fn update_settings_raw<'c, E>(
+        executor: E,
         id: i32,
         settings: serde_json::Value,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "UPDATE overrides SET overrides=$1 WHERE id=$2 RETURNING *",
             settings,
             id
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
@@ -129,6 +147,12 @@ impl StarboardOverride {
         .await
     }
 
+    pub async fn get_by_id(pool: &sqlx::PgPool, id: i32) -> sqlx::Result<Option<Self>> {
+        sqlx::query_as!(Self, "SELECT * FROM overrides WHERE id=$1", id)
+            .fetch_optional(pool)
+            .await
+    }
+
     pub async fn list_by_guild(pool: &sqlx::PgPool, guild_id: i64) -> sqlx::Result<Vec<Self>> {
         sqlx::query_as!(Self, "SELECT * FROM overrides WHERE guild_id=$1", guild_id)
             .fetch_all(pool)
@@ -160,16 +184,16 @@ impl StarboardOverride {
         .map(|r| r.count.unwrap())
     }
 
-    pub async fn list_by_starboard(
-        pool: &sqlx::PgPool,
-        starboard_id: i32,
-    ) -> sqlx::Result<Vec<Self>> {
+    pub async fn list_by_starboard<'c, E>(executor: E, starboard_id: i32) -> sqlx::Result<Vec<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "SELECT * FROM overrides WHERE starboard_id=$1",
             starboard_id,
         )
-        .fetch_all(pool)
+        .fetch_all(executor)
         .await
     }
 
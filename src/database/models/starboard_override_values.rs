@@ -1,6 +1,21 @@
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_with::skip_serializing_none;
 
+use crate::database::{
+    helpers::settings::overrides::call_with_override_settings, StarboardSettings,
+};
+
+macro_rules! override_from_settings {
+    ($settings: expr, $($field: ident),*) => {{
+        let settings: StarboardSettings = $settings;
+        OverrideValues {
+            $(
+                $field: Some(settings.$field),
+            )*
+        }
+    }};
+}
+
 fn null_to_some_none<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
 where
     D: Deserializer<'de>,
@@ -12,7 +27,7 @@ where
 }
 
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct OverrideValues {
     // General Style
     #[serde(deserialize_with = "null_to_some_none", default)]
@@ -36,6 +51,7 @@ pub struct OverrideValues {
     pub required_remove: Option<Option<i16>>,
     pub upvote_emojis: Option<Vec<String>>,
     pub downvote_emojis: Option<Vec<String>>,
+    pub emoji_points: Option<serde_json::Value>,
     pub self_vote: Option<bool>,
     pub allow_bots: Option<bool>,
     pub require_image: Option<bool>,
@@ -50,6 +66,7 @@ pub struct OverrideValues {
     pub enabled: Option<bool>,
     pub autoreact_upvote: Option<bool>,
     pub autoreact_downvote: Option<bool>,
+    pub vote_buttons: Option<bool>,
     pub remove_invalid_reactions: Option<bool>,
     pub link_deletes: Option<bool>,
     pub link_edits: Option<bool>,
@@ -61,3 +78,11 @@ pub struct OverrideValues {
     pub exclusive_group: Option<Option<i32>>,
     pub exclusive_group_priority: Option<i16>,
 }
+
+impl OverrideValues {
+    /// Sets every value, so that applying these to any starboard results in
+    /// exactly `settings`.
+    pub fn from_settings(settings: StarboardSettings) -> Self {
+        call_with_override_settings!(override_from_settings, settings)
+    }
+}
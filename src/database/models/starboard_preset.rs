@@ -0,0 +1,78 @@
+pub struct StarboardPreset {
+    pub id: i32,
+    pub guild_id: i64,
+    pub name: String,
+    pub preset: serde_json::Value,
+}
+
+impl StarboardPreset {
+    pub async fn create<'c, E>(
+        executor: E,
+        guild_id: i64,
+        name: &str,
+        preset: serde_json::Value,
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
+        sqlx::query_as!(
+            Self,
+            "INSERT INTO starboard_presets (guild_id, name, preset) VALUES ($1, $2, $3)
+            ON CONFLICT DO NOTHING RETURNING *",
+            guild_id,
+            name,
+            preset,
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    pub async fn delete<'c, E>(executor: E, guild_id: i64, name: &str) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
+        sqlx::query_as!(
+            Self,
+            "DELETE FROM starboard_presets WHERE guild_id=$1 AND name=$2 RETURNING *",
+            guild_id,
+            name
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    pub async fn get_by_name(
+        pool: &sqlx::PgPool,
+        guild_id: i64,
+        name: &str,
+    ) -> sqlx::Result<Option<Self>> {
+        sqlx::query_as!(
+            Self,
+            "SELECT * FROM starboard_presets WHERE guild_id=$1 AND name=$2",
+            guild_id,
+            name
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn list_by_guild(pool: &sqlx::PgPool, guild_id: i64) -> sqlx::Result<Vec<Self>> {
+        sqlx::query_as!(
+            Self,
+            "SELECT * FROM starboard_presets WHERE guild_id=$1 ORDER BY name",
+            guild_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn count_by_guild(pool: &sqlx::PgPool, guild_id: i64) -> sqlx::Result<i64> {
+        sqlx::query!(
+            "SELECT count(*) as count FROM starboard_presets WHERE guild_id=$1",
+            guild_id
+        )
+        .fetch_one(pool)
+        .await
+        .map(|r| r.count.unwrap())
+    }
+}
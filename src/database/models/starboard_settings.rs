@@ -1,4 +1,6 @@
-#[derive(Clone, Debug)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct StarboardSettings {
     // General Style
     pub display_emoji: Option<String>,
@@ -18,6 +20,9 @@ pub struct StarboardSettings {
     pub required_remove: Option<i16>,
     pub upvote_emojis: Vec<String>,
     pub downvote_emojis: Vec<String>,
+    /// Maps stored vote emojis to the number of points they're worth.
+    /// Emojis that aren't listed are worth 1 point.
+    pub emoji_points: serde_json::Value,
     pub self_vote: bool,
     pub allow_bots: bool,
     pub require_image: bool,
@@ -30,6 +35,7 @@ pub struct StarboardSettings {
     pub enabled: bool,
     pub autoreact_upvote: bool,
     pub autoreact_downvote: bool,
+    pub vote_buttons: bool,
     pub remove_invalid_reactions: bool,
     pub link_deletes: bool,
     pub link_edits: bool,
@@ -1,3 +1,6 @@
+use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
+
 #[derive(Debug)]
 pub struct Vote {
     pub message_id: i64,
@@ -6,9 +9,35 @@ pub struct Vote {
 
     pub target_author_id: i64,
     pub is_downvote: bool,
+    pub weight: f32,
+    pub created_at: DateTime<Utc>,
+    /// Whether the vote was cast with a button rather than a reaction.
+    pub from_button: bool,
+}
+
+/// The total points a user received.
+#[derive(Debug)]
+pub struct ReceivedPoints {
+    pub user_id: i64,
+    pub points: f32,
+}
+
+/// What happened to a vote when it was created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoteChange {
+    /// The vote didn't exist before.
+    Added,
+    /// The vote already existed, but it was an upvote and is now a downvote (or
+    /// the other way around).
+    Flipped,
+    /// The vote already existed in the same direction.
+    Unchanged,
 }
 
 impl Vote {
+    /// Create or update a vote. `created_at` defaults to now, and is only used
+    /// if the vote didn't exist yet.
+    #[allow(clippy::too_many_arguments)]
     pub async fn create(
         pool: &sqlx::PgPool,
         message_id: i64,
@@ -16,77 +45,152 @@ impl Vote {
         user_id: i64,
         target_author_id: i64,
         is_downvote: bool,
-    ) -> sqlx::Result<Option<()>> {
+        weight: f32,
+        from_button: bool,
+        created_at: Option<DateTime<Utc>>,
+    ) -> sqlx::Result<VoteChange> {
         let create = sqlx::query!(
-            "INSERT INTO VOTES (message_id, starboard_id, user_id, target_author_id, is_downvote)
-            VALUES ($1, $2, $3, $4, $5)
-            ON CONFLICT DO NOTHING",
+            "INSERT INTO VOTES (message_id, starboard_id, user_id, target_author_id, is_downvote,
+            weight, from_button, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, COALESCE($8, now()))
+            ON CONFLICT DO NOTHING RETURNING message_id",
             message_id,
             starboard_id,
             user_id,
             target_author_id,
             is_downvote,
+            weight,
+            from_button,
+            created_at,
         )
         .fetch_optional(pool)
         .await?;
 
         if create.is_some() {
-            return Ok(Some(()));
+            return Ok(VoteChange::Added);
         }
 
-        sqlx::query!(
-            "UPDATE votes SET is_downvote=$1 WHERE message_id=$2 AND starboard_id=$3 AND user_id=$4",
+        let old = sqlx::query!(
+            "UPDATE votes SET is_downvote=$1, weight=$2, from_button=$6 FROM votes old
+            WHERE votes.message_id=$3 AND votes.starboard_id=$4 AND votes.user_id=$5
+            AND old.message_id=votes.message_id AND old.starboard_id=votes.starboard_id
+            AND old.user_id=votes.user_id
+            RETURNING old.is_downvote",
             is_downvote,
+            weight,
             message_id,
             starboard_id,
             user_id,
+            from_button,
         )
         .fetch_optional(pool)
         .await?;
 
-        Ok(Some(()))
+        match old {
+            Some(old) if old.is_downvote != is_downvote => Ok(VoteChange::Flipped),
+            _ => Ok(VoteChange::Unchanged),
+        }
     }
 
+    /// The weighted point count of a message, rounded down to a whole number.
     pub async fn count(
         pool: &sqlx::PgPool,
         message_id: i64,
         starboard_id: i32,
     ) -> sqlx::Result<i32> {
-        let upvotes = sqlx::query!(
-            "SELECT COUNT(*) as count FROM votes WHERE message_id=$1 AND starboard_id=$2
-            AND is_downvote=false",
+        let points = sqlx::query!(
+            r#"SELECT COALESCE(SUM(CASE WHEN is_downvote THEN -weight ELSE weight END), 0)
+            as "points!" FROM votes WHERE message_id=$1 AND starboard_id=$2"#,
             message_id,
             starboard_id
         )
         .fetch_one(pool)
         .await?;
-        let downvotes = sqlx::query!(
-            "SELECT COUNT(*) as count FROM votes WHERE message_id=$1 AND starboard_id=$2
-            AND is_downvote=true",
+
+        Ok(points.points.floor() as i32)
+    }
+
+    /// Stream the users that received the most points on a set of starboards,
+    /// optionally only counting votes that were created after `since`.
+    pub fn stream_received_points<'a>(
+        pool: &'a sqlx::PgPool,
+        starboard_ids: &'a [i32],
+        since: Option<DateTime<Utc>>,
+    ) -> BoxStream<'a, sqlx::Result<ReceivedPoints>> {
+        sqlx::query_as!(
+            ReceivedPoints,
+            r#"SELECT target_author_id as "user_id!",
+            SUM(CASE WHEN is_downvote THEN -weight ELSE weight END) as "points!"
+            FROM votes WHERE starboard_id=ANY($1) AND ($2::timestamptz IS NULL OR created_at >= $2)
+            GROUP BY target_author_id
+            HAVING SUM(CASE WHEN is_downvote THEN -weight ELSE weight END) > 0
+            ORDER BY 2 DESC"#,
+            starboard_ids,
+            since,
+        )
+        .fetch(pool)
+    }
+
+    pub async fn list_by_message(pool: &sqlx::PgPool, message_id: i64) -> sqlx::Result<Vec<Self>> {
+        sqlx::query_as!(Self, "SELECT * FROM votes WHERE message_id=$1", message_id)
+            .fetch_all(pool)
+            .await
+    }
+
+    pub async fn get(
+        pool: &sqlx::PgPool,
+        message_id: i64,
+        starboard_id: i32,
+        user_id: i64,
+    ) -> sqlx::Result<Option<Self>> {
+        sqlx::query_as!(
+            Self,
+            "SELECT * FROM votes WHERE message_id=$1 AND starboard_id=$2 AND user_id=$3",
             message_id,
-            starboard_id
+            starboard_id,
+            user_id,
         )
-        .fetch_one(pool)
-        .await?;
+        .fetch_optional(pool)
+        .await
+    }
 
-        Ok({ upvotes.count.unwrap() - downvotes.count.unwrap() }
-            .try_into()
-            .unwrap())
+    /// Delete the votes cast with reactions on a message for a set of
+    /// starboards, optionally only deleting upvotes or downvotes.
+    pub async fn delete_reactions_by_message(
+        pool: &sqlx::PgPool,
+        message_id: i64,
+        starboard_ids: &[i32],
+        is_downvote: Option<bool>,
+    ) -> sqlx::Result<Vec<Self>> {
+        sqlx::query_as!(
+            Self,
+            "DELETE FROM votes WHERE message_id=$1 AND starboard_id=ANY($2)
+            AND ($3::boolean IS NULL OR is_downvote=$3) AND NOT from_button RETURNING *",
+            message_id,
+            starboard_ids,
+            is_downvote,
+        )
+        .fetch_all(pool)
+        .await
     }
 
+    /// Delete a user's vote, optionally only if it was (or wasn't) cast with
+    /// a button.
     pub async fn delete(
         pool: &sqlx::PgPool,
         message_id: i64,
         starboard_id: i32,
         user_id: i64,
+        from_button: Option<bool>,
     ) -> sqlx::Result<Option<Self>> {
         sqlx::query_as!(
             Self,
             "DELETE FROM votes WHERE message_id=$1 AND starboard_id=$2 AND user_id=$3
-            RETURNING *",
+            AND ($4::boolean IS NULL OR from_button=$4) RETURNING *",
             message_id,
             starboard_id,
             user_id,
+            from_button,
         )
         .fetch_optional(pool)
         .await
@@ -0,0 +1,94 @@
+use chrono::{DateTime, Utc};
+
+use super::vote::VoteChange;
+
+#[derive(Debug)]
+pub struct VoteEvent {
+    pub id: i32,
+    pub message_id: i64,
+    pub starboard_id: i32,
+    pub user_id: i64,
+
+    /// 0=add, 1=remove, 2=flip
+    pub kind: i16,
+    pub is_downvote: bool,
+    pub weight: f32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl VoteEvent {
+    pub const ADD: i16 = 0;
+    pub const REMOVE: i16 = 1;
+    pub const FLIP: i16 = 2;
+
+    /// Record a change to a vote. `created_at` defaults to now.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create(
+        pool: &sqlx::PgPool,
+        message_id: i64,
+        starboard_id: i32,
+        user_id: i64,
+        kind: i16,
+        is_downvote: bool,
+        weight: f32,
+        created_at: Option<DateTime<Utc>>,
+    ) -> sqlx::Result<Self> {
+        sqlx::query_as!(
+            Self,
+            "INSERT INTO vote_events
+            (message_id, starboard_id, user_id, kind, is_downvote, weight, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, COALESCE($7, now())) RETURNING *",
+            message_id,
+            starboard_id,
+            user_id,
+            kind,
+            is_downvote,
+            weight,
+            created_at,
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Record the result of `Vote::create`. Does nothing if the vote was unchanged.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_from_change(
+        pool: &sqlx::PgPool,
+        change: VoteChange,
+        message_id: i64,
+        starboard_id: i32,
+        user_id: i64,
+        is_downvote: bool,
+        weight: f32,
+        created_at: Option<DateTime<Utc>>,
+    ) -> sqlx::Result<Option<Self>> {
+        let kind = match change {
+            VoteChange::Added => Self::ADD,
+            VoteChange::Flipped => Self::FLIP,
+            VoteChange::Unchanged => return Ok(None),
+        };
+
+        Self::create(
+            pool,
+            message_id,
+            starboard_id,
+            user_id,
+            kind,
+            is_downvote,
+            weight,
+            created_at,
+        )
+        .await
+        .map(Some)
+    }
+
+    pub async fn list_by_message(pool: &sqlx::PgPool, message_id: i64) -> sqlx::Result<Vec<Self>> {
+        sqlx::query_as!(
+            Self,
+            "SELECT * FROM vote_events WHERE message_id=$1 ORDER BY created_at, id",
+            message_id,
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -6,12 +6,15 @@ pub struct XPRole {
 }
 
 impl XPRole {
-    pub async fn create(
-        pool: &sqlx::PgPool,
+    pub async fn create<'c, E>(
+        executor: E,
         role_id: i64,
         guild_id: i64,
         required: i16,
-    ) -> sqlx::Result<Option<Self>> {
+    ) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "INSERT INTO xproles (role_id, guild_id, required) VALUES ($1, $2, $3)
@@ -20,32 +23,34 @@ impl XPRole {
             guild_id,
             required,
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn delete(pool: &sqlx::PgPool, role_id: i64) -> sqlx::Result<Option<Self>> {
+    pub async fn delete<'c, E>(executor: E, role_id: i64) -> sqlx::Result<Option<Self>>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "DELETE FROM xproles WHERE role_id=$1 RETURNING *",
             role_id
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await
     }
 
-    pub async fn set_required(
-        pool: &sqlx::PgPool,
-        role_id: i64,
-        required: i16,
-    ) -> sqlx::Result<Self> {
+    pub async fn set_required<'c, E>(executor: E, role_id: i64, required: i16) -> sqlx::Result<Self>
+    where
+        E: sqlx::PgExecutor<'c>,
+    {
         sqlx::query_as!(
             Self,
             "UPDATE xproles SET required=$1 WHERE role_id=$2 RETURNING *",
             required,
             role_id
         )
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await
     }
 
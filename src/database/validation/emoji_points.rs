@@ -0,0 +1,85 @@
+use lazy_static::lazy_static;
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{
+    client::bot::StarboardBot,
+    constants,
+    core::emoji::{EmojiCommon, SimpleEmoji},
+};
+
+/// Parses `emoji=points` pairs. Every emoji must be one of `vote_emojis`
+/// (stored upvote and downvote emojis).
+pub fn parse_emoji_points(
+    inp: &str,
+    bot: &StarboardBot,
+    guild_id: Id<GuildMarker>,
+    vote_emojis: &[String],
+) -> Result<serde_json::Value, String> {
+    lazy_static! {
+        static ref RE: regex::Regex =
+            regex::Regex::new(r#"(?P<emoji>[^\s,=]+)\s*=\s*(?P<points>[^\s,]+)"#).unwrap();
+    }
+
+    let mut emoji_points = serde_json::Map::new();
+    if inp == "none" {
+        return Ok(emoji_points.into());
+    }
+
+    for found in RE.captures_iter(inp) {
+        let emoji = found.name("emoji").unwrap().as_str();
+        let Some(parsed) = SimpleEmoji::from_user_input(emoji, bot, guild_id).pop() else {
+            return Err(format!("I couldn't find the emoji {emoji}."));
+        };
+        let stored = parsed.into_stored();
+        if !vote_emojis.contains(&stored) {
+            return Err(format!(
+                "{emoji} is not an upvote or downvote emoji, so it can't be worth points."
+            ));
+        }
+
+        let points = found.name("points").unwrap().as_str();
+        // f64, so that values such as 0.1 are stored exactly as written
+        let points = match points.parse::<f64>() {
+            Ok(points) if points.is_finite() => points,
+            _ => return Err(format!("{points} is not a valid number.")),
+        };
+        if points < constants::MIN_EMOJI_POINTS as f64 {
+            return Err(format!(
+                "Emoji points cannot be less than {}.",
+                constants::MIN_EMOJI_POINTS
+            ));
+        }
+        if points > constants::MAX_EMOJI_POINTS as f64 {
+            return Err(format!(
+                "Emoji points cannot be greater than {}.",
+                constants::MAX_EMOJI_POINTS
+            ));
+        }
+
+        emoji_points.insert(stored, points.into());
+    }
+
+    if emoji_points.is_empty() {
+        return Err(concat!(
+            "I couldn't parse the emoji points you passed. The correct format is ",
+            "`emoji=points` (e.x. `⭐=1 🌟=5`)."
+        )
+        .to_string());
+    }
+    if emoji_points.len() > constants::MAX_PREM_VOTE_EMOJIS {
+        return Err(format!(
+            "You can only set the points for up to {} emojis.",
+            constants::MAX_PREM_VOTE_EMOJIS
+        ));
+    }
+
+    Ok(emoji_points.into())
+}
+
+/// Removes the points of emojis that are no longer vote emojis, so that they
+/// don't come back if the emoji is added again later.
+pub fn prune_emoji_points(emoji_points: &mut serde_json::Value, vote_emojis: &[String]) {
+    if let Some(emoji_points) = emoji_points.as_object_mut() {
+        emoji_points.retain(|emoji, _| vote_emojis.contains(emoji));
+    }
+}
@@ -0,0 +1,123 @@
+//! Parsing and validation for the content conditions of filters.
+
+use crate::{constants, utils::message_content::ATTACHMENT_TYPES};
+
+fn split_list(inp: &str) -> impl Iterator<Item = &str> {
+    inp.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|item| !item.is_empty())
+}
+
+/// Parse a list of domains. Returns `None` if the list should be cleared.
+pub fn parse_domains(inp: &str) -> Result<Option<Vec<String>>, String> {
+    if inp.trim() == "none" {
+        return Ok(None);
+    }
+
+    let mut domains = Vec::new();
+    for item in split_list(inp) {
+        let item = item.to_lowercase();
+        let item = item
+            .strip_prefix("https://")
+            .or_else(|| item.strip_prefix("http://"))
+            .unwrap_or(&item);
+        let item = item.split('/').next().unwrap_or(item);
+        let item = item.strip_prefix("www.").unwrap_or(item);
+
+        let is_valid = item.contains('.')
+            && !item.starts_with('.')
+            && !item.ends_with('.')
+            && item
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-');
+        if !is_valid {
+            return Err(format!("`{item}` is not a valid domain."));
+        }
+
+        if !domains.iter().any(|d| d == item) {
+            domains.push(item.to_string());
+        }
+    }
+
+    if domains.len() > constants::MAX_FILTER_DOMAINS {
+        return Err(format!(
+            "You can only have up to {} domains in a list.",
+            constants::MAX_FILTER_DOMAINS
+        ));
+    }
+
+    if domains.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(domains))
+    }
+}
+
+/// Parse a list of attachment types. Returns `None` if the list should be
+/// cleared.
+pub fn parse_attachment_types(inp: &str) -> Result<Option<Vec<String>>, String> {
+    if inp.trim() == "none" {
+        return Ok(None);
+    }
+
+    let mut types = Vec::new();
+    for item in split_list(inp) {
+        let item = item.to_lowercase();
+        if !ATTACHMENT_TYPES.contains(&item.as_str()) {
+            return Err(format!(
+                "`{}` is not an attachment type. The types are {}.",
+                item,
+                ATTACHMENT_TYPES
+                    .iter()
+                    .map(|t| format!("`{t}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+
+        if !types.contains(&item) {
+            types.push(item);
+        }
+    }
+
+    if types.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(types))
+    }
+}
+
+/// Parse a list of file extensions. Returns `None` if the list should be
+/// cleared.
+pub fn parse_extensions(inp: &str) -> Result<Option<Vec<String>>, String> {
+    if inp.trim() == "none" {
+        return Ok(None);
+    }
+
+    let mut extensions = Vec::new();
+    for item in split_list(inp) {
+        let item = item.trim_start_matches('.').to_lowercase();
+        if item.is_empty()
+            || item.len() > constants::MAX_EXTENSION_LENGTH
+            || !item.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            return Err(format!("`{item}` is not a valid file extension."));
+        }
+
+        if !extensions.contains(&item) {
+            extensions.push(item);
+        }
+    }
+
+    if extensions.len() > constants::MAX_FILTER_EXTENSIONS {
+        return Err(format!(
+            "You can only have up to {} file extensions in a list.",
+            constants::MAX_FILTER_EXTENSIONS
+        ));
+    }
+
+    if extensions.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(extensions))
+    }
+}
@@ -0,0 +1,117 @@
+//! Validation for filters that reference other filter groups.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    constants,
+    database::models::{filter::Filter, filter_group::FilterGroup},
+};
+
+/// Parse filter group names from user input. Returns `None` if the list
+/// should be cleared.
+pub fn parse_filter_groups(groups: &[FilterGroup], inp: &str) -> Result<Option<Vec<i32>>, String> {
+    if inp.trim() == "none" {
+        return Ok(None);
+    }
+
+    let mut ids = Vec::new();
+    for name in inp
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|name| !name.is_empty())
+    {
+        let Some(group) = groups.iter().find(|g| g.name == name) else {
+            return Err(format!("No filter group named '{name}' exists."));
+        };
+        if !ids.contains(&group.id) {
+            ids.push(group.id);
+        }
+    }
+
+    if ids.len() > constants::MAX_FILTER_SUB_GROUPS {
+        return Err(format!(
+            "You can only reference up to {} filter groups in a list.",
+            constants::MAX_FILTER_SUB_GROUPS
+        ));
+    }
+
+    if ids.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(ids))
+    }
+}
+
+/// Checks that, with the edited filter, no filter group ends up referencing
+/// itself either directly or through other filter groups, and that groups
+/// aren't nested deeper than `MAX_FILTER_GROUP_DEPTH`.
+pub fn validate_group_references(edited: &Filter, guild_filters: &[Filter]) -> Result<(), String> {
+    let mut references: HashMap<i32, HashSet<i32>> = HashMap::new();
+    for filter in guild_filters.iter().filter(|f| f.id != edited.id) {
+        references
+            .entry(filter.filter_group_id)
+            .or_default()
+            .extend(filter.referenced_groups());
+    }
+    references
+        .entry(edited.filter_group_id)
+        .or_default()
+        .extend(edited.referenced_groups());
+
+    let mut seen = HashSet::new();
+    let mut stack = edited.referenced_groups();
+    while let Some(group_id) = stack.pop() {
+        if group_id == edited.filter_group_id {
+            return Err(
+                "A filter group can't reference itself, either directly or through other groups."
+                    .to_string(),
+            );
+        }
+        if !seen.insert(group_id) {
+            continue;
+        }
+        if let Some(refs) = references.get(&group_id) {
+            stack.extend(refs);
+        }
+    }
+
+    // any group can be used directly by a starboard, so the longest chain
+    // passing through the edited group is what's evaluated in the worst case
+    let mut referenced_by: HashMap<i32, HashSet<i32>> = HashMap::new();
+    for (group_id, refs) in &references {
+        for referenced in refs {
+            referenced_by
+                .entry(*referenced)
+                .or_default()
+                .insert(*group_id);
+        }
+    }
+    let depth = longest_chain(edited.filter_group_id, &references, &mut HashMap::new())
+        + longest_chain(edited.filter_group_id, &referenced_by, &mut HashMap::new());
+    if depth > constants::MAX_FILTER_GROUP_DEPTH {
+        return Err(format!(
+            "Filter groups can only be nested up to {} levels deep.",
+            constants::MAX_FILTER_GROUP_DEPTH
+        ));
+    }
+
+    Ok(())
+}
+
+/// The number of links in the longest chain of references starting at a
+/// group. The references must not contain any cycles.
+fn longest_chain(
+    group_id: i32,
+    references: &HashMap<i32, HashSet<i32>>,
+    known: &mut HashMap<i32, usize>,
+) -> usize {
+    if let Some(length) = known.get(&group_id) {
+        return *length;
+    }
+
+    let mut length = 0;
+    for referenced in references.get(&group_id).into_iter().flatten() {
+        length = length.max(longest_chain(*referenced, references, known) + 1);
+    }
+    known.insert(group_id, length);
+    length
+}
@@ -1,7 +1,11 @@
 pub mod color;
 pub mod cooldown;
+pub mod emoji_points;
+pub mod filter_content;
+pub mod filter_groups;
 pub mod mentions;
 pub mod name;
 pub mod regex;
 pub mod starboard_settings;
 pub mod time_delta;
+pub mod vote_weight;
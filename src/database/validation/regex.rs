@@ -1,4 +1,4 @@
-use crate::constants;
+use crate::{constants, utils::safe_regex::build_regex};
 
 pub fn validate_regex(input: String, is_premium: bool) -> Result<Option<String>, String> {
     if !is_premium {
@@ -15,7 +15,7 @@ pub fn validate_regex(input: String, is_premium: bool) -> Result<Option<String>,
     if input == ".*" {
         Ok(None)
     } else {
-        match regex::Regex::new(&input) {
+        match build_regex(&input) {
             Ok(_) => Ok(Some(input)),
             Err(why) => Err(format!("```\n{why}\n```")),
         }
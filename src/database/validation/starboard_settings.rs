@@ -4,7 +4,13 @@
 
 use std::collections::HashSet;
 
-use crate::constants;
+use crate::{
+    constants,
+    database::{
+        validation::{regex::validate_regex, time_delta::validate_relative_duration},
+        OverrideValues,
+    },
+};
 
 pub fn none_or_number(val: String) -> Result<Option<i16>, String> {
     if val == "none" {
@@ -141,3 +147,93 @@ pub fn validate_vote_emojis(
 
     Ok(())
 }
+
+/// Validates every setting in a set of override values, for values that come
+/// from somewhere other than the commands (such as imports and presets).
+pub fn validate_override_values(settings: &OverrideValues, premium: bool) -> Result<(), String> {
+    if let Some(Some(color)) = settings.color {
+        if !(0..=0xFFFFFF).contains(&color) {
+            return Err("Color codes must be between `#000000` and `#FFFFFF`.".to_string());
+        }
+    }
+    if let Some(go_to_message) = settings.go_to_message {
+        if !(0..=3).contains(&go_to_message) {
+            return Err("Invalid value for `go-to-message`.".to_string());
+        }
+    }
+    if let Some(on_delete) = settings.on_delete {
+        if !(0..=3).contains(&on_delete) {
+            return Err("Invalid value for `on-delete`.".to_string());
+        }
+    }
+
+    if let Some(Some(required)) = settings.required {
+        if !(constants::MIN_REQUIRED..=constants::MAX_REQUIRED).contains(&required) {
+            return Err(format!(
+                "`required` must be between {} and {}.",
+                constants::MIN_REQUIRED,
+                constants::MAX_REQUIRED
+            ));
+        }
+    }
+    if let Some(Some(required_remove)) = settings.required_remove {
+        let range = constants::MIN_REQUIRED_REMOVE..=constants::MAX_REQUIRED_REMOVE;
+        if !range.contains(&required_remove) {
+            return Err(format!(
+                "`required-remove` must be between {} and {}.",
+                constants::MIN_REQUIRED_REMOVE,
+                constants::MAX_REQUIRED_REMOVE
+            ));
+        }
+    }
+    if let (Some(Some(required)), Some(Some(required_remove))) =
+        (settings.required, settings.required_remove)
+    {
+        if required <= required_remove {
+            return Err("`required` must be greater than `required-remove`.".to_string());
+        }
+    }
+
+    validate_vote_emojis(
+        settings.upvote_emojis.as_deref().unwrap_or_default(),
+        settings.downvote_emojis.as_deref().unwrap_or_default(),
+        premium,
+    )?;
+    if let Some(emoji_points) = &settings.emoji_points {
+        let Some(emoji_points) = emoji_points.as_object() else {
+            return Err("Invalid `emoji-points`.".to_string());
+        };
+        if emoji_points.len() > constants::MAX_PREM_VOTE_EMOJIS {
+            return Err(format!(
+                "You can only set the points for up to {} emojis.",
+                constants::MAX_PREM_VOTE_EMOJIS
+            ));
+        }
+        for points in emoji_points.values() {
+            let valid = points.as_f64().is_some_and(|points| {
+                (constants::MIN_EMOJI_POINTS as f64..=constants::MAX_EMOJI_POINTS as f64)
+                    .contains(&points)
+            });
+            if !valid {
+                return Err(format!(
+                    "Emoji points must be between {} and {}.",
+                    constants::MIN_EMOJI_POINTS,
+                    constants::MAX_EMOJI_POINTS
+                ));
+            }
+        }
+    }
+
+    validate_relative_duration(settings.newer_than, settings.older_than)?;
+    for regex in [&settings.matches, &settings.not_matches] {
+        if let Some(Some(regex)) = regex {
+            validate_regex(regex.clone(), premium)?;
+        }
+    }
+
+    if let (Some(count), Some(period)) = (settings.cooldown_count, settings.cooldown_period) {
+        validate_cooldown(count, period)?;
+    }
+
+    Ok(())
+}
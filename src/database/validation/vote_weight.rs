@@ -0,0 +1,26 @@
+use crate::constants;
+
+pub fn validate_vote_weight(input: String) -> Result<Option<f32>, String> {
+    if input == "none" {
+        return Ok(None);
+    }
+
+    let val = match input.parse::<f32>() {
+        Ok(val) if val.is_finite() => val,
+        _ => return Err(format!("I couldn't interpret {input} as a number.")),
+    };
+
+    if val < constants::MIN_VOTE_WEIGHT {
+        Err(format!(
+            "`vote-weight` cannot be less than {}.",
+            constants::MIN_VOTE_WEIGHT
+        ))
+    } else if val > constants::MAX_VOTE_WEIGHT {
+        Err(format!(
+            "`vote-weight` cannot be greater than {}.",
+            constants::MAX_VOTE_WEIGHT
+        ))
+    } else {
+        Ok(Some(val))
+    }
+}
@@ -132,9 +132,18 @@ async fn match_events(
         Event::ReactionRemove(event) => {
             core::starboard::reaction_events::handle_reaction_remove(bot, event).await?;
         }
+        Event::ReactionRemoveAll(event) => {
+            core::starboard::reaction_events::handle_reaction_remove_all(bot, event).await?;
+        }
+        Event::ReactionRemoveEmoji(event) => {
+            core::starboard::reaction_events::handle_reaction_remove_emoji(bot, event).await?;
+        }
         Event::MessageDelete(event) => {
             core::starboard::link_events::handle_message_delete(bot, event.id).await?;
         }
+        Event::MessageDeleteBulk(event) => {
+            core::starboard::link_events::handle_message_delete_bulk(bot, event.ids).await?;
+        }
         Event::ThreadDelete(event) => {
             core::starboard::link_events::handle_message_delete(bot, event.id.get().into_id())
                 .await?;
@@ -7,9 +7,12 @@ use twilight_util::builder::InteractionResponseDataBuilder;
 use crate::{errors::StarboardResult, interactions::context::CommandCtx};
 
 use super::{
-    autoredeem::autoredeem_autocomplete, autostar_name::autostar_name_autocomplete,
+    autoredeem::autoredeem_autocomplete,
+    autostar_name::autostar_name_autocomplete,
     exclusive_group_name::exclusive_group_name_autocomplete,
-    filter_group::filter_group_name_autocomplete, override_name::override_name_autocomplete,
+    filter_group::filter_group_name_autocomplete,
+    override_name::override_name_autocomplete,
+    preset_name::{custom_preset_name_autocomplete, preset_name_autocomplete},
     starboard_name::starboard_name_autocomplete,
 };
 
@@ -60,6 +63,7 @@ pub async fn handle_autocomplete(ctx: CommandCtx) -> StarboardResult<()> {
     let (qual_name, focused) = parse(&ctx);
     let options = match qual_name.as_str() {
         // misc
+        "leaderboard starboard" => starboard_name_autocomplete(&ctx, focused).await?,
         "random starboard" => starboard_name_autocomplete(&ctx, focused).await?,
         "moststarred starboard" => starboard_name_autocomplete(&ctx, focused).await?,
         "utils force starboard" => starboard_name_autocomplete(&ctx, focused).await?,
@@ -88,6 +92,7 @@ pub async fn handle_autocomplete(ctx: CommandCtx) -> StarboardResult<()> {
             filter_group_name_autocomplete(&ctx, focused).await?
         }
         // starboards
+        "starboards create preset" => preset_name_autocomplete(&ctx, focused).await?,
         "starboards delete name" => starboard_name_autocomplete(&ctx, focused).await?,
         "starboards view name" => starboard_name_autocomplete(&ctx, focused).await?,
         "starboards edit embed name" => starboard_name_autocomplete(&ctx, focused).await?,
@@ -98,6 +103,10 @@ pub async fn handle_autocomplete(ctx: CommandCtx) -> StarboardResult<()> {
             exclusive_group_name_autocomplete(&ctx, focused).await?
         }
         "starboards rename current-name" => starboard_name_autocomplete(&ctx, focused).await?,
+        "starboards backfill name" => starboard_name_autocomplete(&ctx, focused).await?,
+        "starboards regenerate name" => starboard_name_autocomplete(&ctx, focused).await?,
+        "starboards move name" => starboard_name_autocomplete(&ctx, focused).await?,
+        "starboards clone source" => starboard_name_autocomplete(&ctx, focused).await?,
         "starboards filters add starboard" => starboard_name_autocomplete(&ctx, focused).await?,
         "starboards filters add filter-group" => {
             filter_group_name_autocomplete(&ctx, focused).await?
@@ -106,6 +115,8 @@ pub async fn handle_autocomplete(ctx: CommandCtx) -> StarboardResult<()> {
         "starboards filters remove filter-group" => {
             filter_group_name_autocomplete(&ctx, focused).await?
         }
+        "starboards presets save starboard" => starboard_name_autocomplete(&ctx, focused).await?,
+        "starboards presets delete name" => custom_preset_name_autocomplete(&ctx, focused).await?,
         // overrides
         "overrides create starboard" => starboard_name_autocomplete(&ctx, focused).await?,
         "overrides delete name" => override_name_autocomplete(&ctx, focused).await?,
@@ -122,6 +133,7 @@ pub async fn handle_autocomplete(ctx: CommandCtx) -> StarboardResult<()> {
         "overrides edit embed name" => override_name_autocomplete(&ctx, focused).await?,
         "overrides edit reset name" => override_name_autocomplete(&ctx, focused).await?,
         "overrides view name" => override_name_autocomplete(&ctx, focused).await?,
+        "overrides resolve starboard" => starboard_name_autocomplete(&ctx, focused).await?,
         // exclusive groups
         "exclusive-groups delete name" => exclusive_group_name_autocomplete(&ctx, focused).await?,
         "exclusive-groups rename original-name" => {
@@ -136,7 +148,16 @@ pub async fn handle_autocomplete(ctx: CommandCtx) -> StarboardResult<()> {
         }
         "filters delete-group name" => filter_group_name_autocomplete(&ctx, focused).await?,
         "filters view group" => filter_group_name_autocomplete(&ctx, focused).await?,
-        "filters edit group" => filter_group_name_autocomplete(&ctx, focused).await?,
+        "filters test group" => filter_group_name_autocomplete(&ctx, focused).await?,
+        "filters export group" => filter_group_name_autocomplete(&ctx, focused).await?,
+        "filters edit general group" => filter_group_name_autocomplete(&ctx, focused).await?,
+        "filters edit default-context group" => {
+            filter_group_name_autocomplete(&ctx, focused).await?
+        }
+        "filters edit message-context group" => {
+            filter_group_name_autocomplete(&ctx, focused).await?
+        }
+        "filters edit vote-context group" => filter_group_name_autocomplete(&ctx, focused).await?,
         // permroles
         "permroles edit-starboard starboard" => starboard_name_autocomplete(&ctx, focused).await?,
         qual => todo!("Unexpected autocomplete for {}.", qual),
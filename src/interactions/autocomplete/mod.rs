@@ -5,4 +5,5 @@ mod exclusive_group_name;
 mod filter_group;
 pub mod handle;
 mod override_name;
+mod preset_name;
 mod starboard_name;
@@ -0,0 +1,34 @@
+use twilight_model::application::command::CommandOptionChoice;
+
+use crate::{
+    core::starboard::presets::BUILTIN_PRESETS, database::StarboardPreset, errors::StarboardResult,
+    interactions::context::CommandCtx, utils::id_as_i64::GetI64,
+};
+
+use super::best_matches::best_matches_as_choices;
+
+pub async fn preset_name_autocomplete(
+    ctx: &CommandCtx,
+    focused: &str,
+) -> StarboardResult<Vec<CommandOptionChoice>> {
+    let guild_id = ctx.interaction.guild_id.unwrap();
+    let presets = StarboardPreset::list_by_guild(&ctx.bot.pool, guild_id.get_i64()).await?;
+    let names: Vec<&str> = BUILTIN_PRESETS
+        .iter()
+        .map(|p| p.name)
+        .chain(presets.iter().map(|p| p.name.as_str()))
+        .collect();
+
+    Ok(best_matches_as_choices(focused, &names, None))
+}
+
+pub async fn custom_preset_name_autocomplete(
+    ctx: &CommandCtx,
+    focused: &str,
+) -> StarboardResult<Vec<CommandOptionChoice>> {
+    let guild_id = ctx.interaction.guild_id.unwrap();
+    let presets = StarboardPreset::list_by_guild(&ctx.bot.pool, guild_id.get_i64()).await?;
+    let names: Vec<&str> = presets.iter().map(|p| p.name.as_str()).collect();
+
+    Ok(best_matches_as_choices(focused, &names, None))
+}
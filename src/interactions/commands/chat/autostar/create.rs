@@ -3,7 +3,10 @@ use twilight_model::application::interaction::application_command::InteractionCh
 
 use crate::{
     constants,
-    core::premium::is_premium::is_guild_premium,
+    core::{
+        audit::{self, AuditAction, AuditTarget},
+        premium::is_premium::is_guild_premium,
+    },
     database::{validation, AutoStarChannel, DbGuild},
     errors::StarboardResult,
     get_guild_id,
@@ -64,15 +67,21 @@ impl CreateAutoStarChannel {
             return Ok(());
         }
 
-        let ret = AutoStarChannel::create(&ctx.bot.pool, &name, channel_id, guild_id).await?;
+        let mut tx = ctx.bot.pool.begin().await?;
+        let ret = AutoStarChannel::create(&mut tx, &name, channel_id, guild_id).await?;
 
-        if ret.is_none() {
-            ctx.respond_str(
-                &format!("An autostar channel with the name '{name}' already exists."),
-                true,
+        if let Some(asc) = ret {
+            audit::log(
+                &mut tx,
+                &ctx,
+                AuditAction::Create,
+                AuditTarget::AutoStarChannel,
+                asc.id.into(),
+                &asc.name,
+                serde_json::json!({"channel_id": {"new": channel_id}}),
             )
             .await?;
-        } else {
+            tx.commit().await?;
             ctx.bot.cache.autostar_channel_ids.insert(self.channel.id);
 
             ctx.respond_str(
@@ -80,6 +89,12 @@ impl CreateAutoStarChannel {
                 false,
             )
             .await?;
+        } else {
+            ctx.respond_str(
+                &format!("An autostar channel with the name '{name}' already exists."),
+                true,
+            )
+            .await?;
         }
 
         Ok(())
@@ -1,7 +1,10 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
-    core::premium::{is_premium::is_guild_premium, locks::refresh_premium_locks},
+    core::{
+        audit::{self, AuditAction, AuditTarget},
+        premium::{is_premium::is_guild_premium, locks::refresh_premium_locks},
+    },
     database::AutoStarChannel,
     errors::StarboardResult,
     get_guild_id,
@@ -35,20 +38,35 @@ impl DeleteAutoStarChannel {
             Some(btn_ctx) => btn_ctx,
         };
 
-        let ret = AutoStarChannel::delete(&ctx.bot.pool, &self.name, guild_id.get_i64()).await?;
+        let mut tx = ctx.bot.pool.begin().await?;
+        let ret = AutoStarChannel::delete(&mut tx, &self.name, guild_id.get_i64()).await?;
+        if let Some(asc) = &ret {
+            audit::log(
+                &mut tx,
+                &ctx,
+                AuditAction::Delete,
+                AuditTarget::AutoStarChannel,
+                asc.id.into(),
+                &asc.name,
+                serde_json::json!({"channel_id": {"old": asc.channel_id}}),
+            )
+            .await?;
+        }
+        tx.commit().await?;
+
         refresh_premium_locks(
             &ctx.bot,
             guild_id.get_i64(),
             is_guild_premium(&ctx.bot, guild_id.get_i64(), true).await?,
         )
         .await?;
-        if ret.is_none() {
+        if ret.is_some() {
             btn_ctx
-                .edit_str("No autostar channel with that name was found.", true)
+                .edit_str(&format!("Deleted autostar channel '{}'.", self.name), true)
                 .await?;
         } else {
             btn_ctx
-                .edit_str(&format!("Deleted autostar channel '{}'.", self.name), true)
+                .edit_str("No autostar channel with that name was found.", true)
                 .await?;
         }
         Ok(())
@@ -2,6 +2,7 @@ use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
     core::{
+        audit::{self, AuditAction, AuditTarget},
         emoji::{EmojiCommon, SimpleEmoji},
         premium::is_premium::is_guild_premium,
     },
@@ -48,6 +49,7 @@ impl EditAutoStar {
             }
             Some(asc) => asc,
         };
+        let old = asc.clone();
 
         let is_prem = is_guild_premium(&ctx.bot, guild_id_i64, true).await?;
 
@@ -79,13 +81,26 @@ impl EditAutoStar {
             asc.delete_invalid = val;
         }
 
-        let asc = asc.update_settings(&ctx.bot.pool).await?;
+        let changes = audit::diff(&old, &asc);
+        let mut tx = ctx.bot.pool.begin().await?;
+        let asc = asc.update_settings(&mut tx).await?;
 
-        if asc.is_none() {
+        let Some(asc) = asc else {
             ctx.respond_str("No autostar channels with that name were found.", true)
                 .await?;
             return Ok(());
-        }
+        };
+        audit::log(
+            &mut tx,
+            &ctx,
+            AuditAction::Edit,
+            AuditTarget::AutoStarChannel,
+            asc.id.into(),
+            &asc.name,
+            changes,
+        )
+        .await?;
+        tx.commit().await?;
 
         // set the emojis
         ctx.respond_str(
@@ -1,6 +1,7 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
+    core::audit::{self, AuditAction, AuditTarget},
     database::{
         models::{
             autostar_channel_filter_group::AutostarChannelFilterGroup, filter_group::FilterGroup,
@@ -48,8 +49,20 @@ impl Add {
             return Ok(());
         };
 
-        let ret = AutostarChannelFilterGroup::create(&ctx.bot.pool, group.id, asc.id).await?;
+        let mut tx = ctx.bot.pool.begin().await?;
+        let ret = AutostarChannelFilterGroup::create(&mut tx, group.id, asc.id).await?;
         if ret.is_some() {
+            audit::log(
+                &mut tx,
+                &ctx,
+                AuditAction::Edit,
+                AuditTarget::FilterGroup,
+                group.id.into(),
+                &group.name,
+                serde_json::json!({"autostar_channel": {"new": asc.name}}),
+            )
+            .await?;
+            tx.commit().await?;
             ctx.respond_str(
                 &format!(
                     "Added filter group '{}' to autostar channel '{}'.",
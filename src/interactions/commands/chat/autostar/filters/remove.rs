@@ -1,6 +1,7 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
+    core::audit::{self, AuditAction, AuditTarget},
     database::{
         models::{
             autostar_channel_filter_group::AutostarChannelFilterGroup, filter_group::FilterGroup,
@@ -51,9 +52,21 @@ impl Remove {
             return Ok(());
         };
 
-        let ret = AutostarChannelFilterGroup::delete(&ctx.bot.pool, group.id, asc.id).await?;
+        let mut tx = ctx.bot.pool.begin().await?;
+        let ret = AutostarChannelFilterGroup::delete(&mut tx, group.id, asc.id).await?;
 
         if ret.is_some() {
+            audit::log(
+                &mut tx,
+                &ctx,
+                AuditAction::Edit,
+                AuditTarget::FilterGroup,
+                group.id.into(),
+                &group.name,
+                serde_json::json!({"autostar_channel": {"old": asc.name}}),
+            )
+            .await?;
+            tx.commit().await?;
             ctx.respond_str(
                 &format!(
                     "Removed the filter group '{}' from autostar channel '{}'.",
@@ -1,6 +1,7 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
+    core::audit::{self, AuditAction, AuditTarget},
     database::{validation, AutoStarChannel},
     errors::StarboardResult,
     get_guild_id,
@@ -31,13 +32,10 @@ impl RenameAutoStarChannel {
             Ok(name) => name,
         };
 
-        let ret = AutoStarChannel::rename(
-            &ctx.bot.pool,
-            &self.current_name,
-            guild_id.get_i64(),
-            &new_name,
-        )
-        .await;
+        let mut tx = ctx.bot.pool.begin().await?;
+        let ret =
+            AutoStarChannel::rename(&mut tx, &self.current_name, guild_id.get_i64(), &new_name)
+                .await;
 
         match ret {
             Err(why) => {
@@ -55,7 +53,18 @@ impl RenameAutoStarChannel {
                 ctx.respond_str("No autostar channel with that name was found.", true)
                     .await?
             }
-            Ok(Some(_)) => {
+            Ok(Some(asc)) => {
+                audit::log(
+                    &mut tx,
+                    &ctx,
+                    AuditAction::Rename,
+                    AuditTarget::AutoStarChannel,
+                    asc.id.into(),
+                    &new_name,
+                    audit::rename(&self.current_name, &new_name),
+                )
+                .await?;
+                tx.commit().await?;
                 ctx.respond_str(
                     &format!(
                         "Renamed the autostar channel from '{}' to '{}'.",
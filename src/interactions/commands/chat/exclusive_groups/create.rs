@@ -2,6 +2,7 @@ use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
     constants,
+    core::audit::{self, AuditAction, AuditTarget},
     database::{validation::name::validate_name, DbGuild, ExclusiveGroup},
     errors::StarboardResult,
     get_guild_id,
@@ -43,9 +44,21 @@ impl Create {
             return Ok(());
         }
 
-        let group = ExclusiveGroup::create(&ctx.bot.pool, &name, guild_id).await?;
+        let mut tx = ctx.bot.pool.begin().await?;
+        let group = ExclusiveGroup::create(&mut tx, &name, guild_id).await?;
 
-        if group.is_some() {
+        if let Some(group) = group {
+            audit::log(
+                &mut tx,
+                &ctx,
+                AuditAction::Create,
+                AuditTarget::ExclusiveGroup,
+                group.id.into(),
+                &group.name,
+                serde_json::json!({}),
+            )
+            .await?;
+            tx.commit().await?;
             ctx.respond_str(&format!("Created exclusive group '{name}'."), false)
                 .await?;
         } else {
@@ -1,8 +1,12 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
-    database::ExclusiveGroup, errors::StarboardResult, get_guild_id,
-    interactions::context::CommandCtx, utils::id_as_i64::GetI64,
+    core::audit::{self, AuditAction, AuditTarget},
+    database::ExclusiveGroup,
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::context::CommandCtx,
+    utils::id_as_i64::GetI64,
 };
 
 #[derive(CommandModel, CreateCommand)]
@@ -17,7 +21,8 @@ impl Delete {
     pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
         let guild_id = get_guild_id!(ctx).get_i64();
 
-        let ret = ExclusiveGroup::delete(&ctx.bot.pool, &self.name, guild_id).await?;
+        let mut tx = ctx.bot.pool.begin().await?;
+        let ret = ExclusiveGroup::delete(&mut tx, &self.name, guild_id).await?;
         let Some(group) = ret else {
             ctx.respond_str(
                 &format!("Exclusive group '{}' does not exist.", self.name),
@@ -33,9 +38,21 @@ impl Delete {
             guild_id,
             group.id,
         )
-        .fetch_all(&ctx.bot.pool)
+        .fetch_all(&mut tx)
         .await?;
 
+        audit::log(
+            &mut tx,
+            &ctx,
+            AuditAction::Delete,
+            AuditTarget::ExclusiveGroup,
+            group.id.into(),
+            &group.name,
+            serde_json::json!({}),
+        )
+        .await?;
+        tx.commit().await?;
+
         ctx.respond_str(&format!("Deleted exclusive group '{}'.", self.name), false)
             .await?;
 
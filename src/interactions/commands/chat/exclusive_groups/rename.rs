@@ -1,6 +1,7 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
+    core::audit::{self, AuditAction, AuditTarget},
     database::{validation::name::validate_name, ExclusiveGroup},
     errors::StarboardResult,
     get_guild_id,
@@ -31,8 +32,8 @@ impl Rename {
             Ok(name) => name,
         };
 
-        let ret =
-            ExclusiveGroup::rename(&ctx.bot.pool, guild_id, &self.original_name, &new_name).await;
+        let mut tx = ctx.bot.pool.begin().await?;
+        let ret = ExclusiveGroup::rename(&mut tx, guild_id, &self.original_name, &new_name).await;
 
         let err = match ret {
             Err(why) => {
@@ -43,7 +44,18 @@ impl Rename {
                 }
             }
             Ok(None) => format!("Exclusive group '{}' does not exist.", self.original_name),
-            Ok(Some(_)) => {
+            Ok(Some(group)) => {
+                audit::log(
+                    &mut tx,
+                    &ctx,
+                    AuditAction::Rename,
+                    AuditTarget::ExclusiveGroup,
+                    group.id.into(),
+                    &group.name,
+                    audit::rename(&self.original_name, &group.name),
+                )
+                .await?;
+                tx.commit().await?;
                 ctx.respond_str("Done.", true).await?;
                 return Ok(());
             }
@@ -2,6 +2,7 @@ use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
     constants,
+    core::audit::{self, AuditAction, AuditTarget},
     database::models::{filter::Filter, filter_group::FilterGroup},
     errors::StarboardResult,
     get_guild_id,
@@ -42,18 +43,29 @@ impl CreateFilter {
             return Ok(());
         }
 
+        let mut tx = ctx.bot.pool.begin().await?;
         if let Some(insert_pos) = self.position {
-            Filter::shift(&ctx.bot.pool, group.id, insert_pos as i16, None, 1).await?;
+            Filter::shift(&mut tx, group.id, insert_pos as i16, None, 1).await?;
         }
 
         let position = match self.position {
             Some(val) => val as i16,
-            None => Filter::get_last_position(&ctx.bot.pool, group.id).await? + 1,
+            None => Filter::get_last_position(&mut tx, group.id).await? + 1,
         };
 
-        Filter::create(&ctx.bot.pool, group.id, position)
-            .await?
-            .unwrap();
+        let filter = Filter::create(&mut tx, group.id, position).await?.unwrap();
+        audit::log(
+            &mut tx,
+            &ctx,
+            AuditAction::Create,
+            AuditTarget::Filter,
+            filter.id.into(),
+            &audit::filter_name(&group.name, position),
+            serde_json::json!({"position": {"new": position}}),
+        )
+        .await?;
+        tx.commit().await?;
+        ctx.bot.cache.filter_groups.invalidate(&group.id).await;
 
         ctx.respond_str("Filter created.", false).await?;
         Ok(())
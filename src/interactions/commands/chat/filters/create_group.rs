@@ -2,6 +2,7 @@ use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
     constants,
+    core::audit::{self, AuditAction, AuditTarget},
     database::{models::filter_group::FilterGroup, validation::name::validate_name, DbGuild},
     errors::StarboardResult,
     get_guild_id,
@@ -43,16 +44,28 @@ impl CreateGroup {
                 return Ok(());
             }
         };
-        let group = FilterGroup::create(&ctx.bot.pool, guild_id, &name).await?;
-        if group.is_none() {
+        let mut tx = ctx.bot.pool.begin().await?;
+        let group = FilterGroup::create(&mut tx, guild_id, &name).await?;
+        if let Some(group) = group {
+            audit::log(
+                &mut tx,
+                &ctx,
+                AuditAction::Create,
+                AuditTarget::FilterGroup,
+                group.id.into(),
+                &group.name,
+                serde_json::json!({}),
+            )
+            .await?;
+            tx.commit().await?;
+            ctx.respond_str(&format!("Created filter group '{name}'."), false)
+                .await?;
+        } else {
             ctx.respond_str(
                 &format!("A filter group named '{name}' already exists."),
                 true,
             )
             .await?;
-        } else {
-            ctx.respond_str(&format!("Created filter group '{name}'."), false)
-                .await?;
         }
 
         Ok(())
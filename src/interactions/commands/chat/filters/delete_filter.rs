@@ -1,6 +1,7 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
+    core::audit::{self, AuditAction, AuditTarget},
     database::models::{filter::Filter, filter_group::FilterGroup},
     errors::StarboardResult,
     get_guild_id,
@@ -41,7 +42,22 @@ impl DeleteFilter {
             return Ok(());
         };
 
-        let ret = Filter::delete(&ctx.bot.pool, group.id, self.position as i16).await?;
+        let mut tx = ctx.bot.pool.begin().await?;
+        let ret = Filter::delete(&mut tx, group.id, self.position as i16).await?;
+        if let Some(filter) = &ret {
+            audit::log(
+                &mut tx,
+                &ctx,
+                AuditAction::Delete,
+                AuditTarget::Filter,
+                filter.id.into(),
+                &audit::filter_name(&group.name, filter.position),
+                serde_json::json!({"position": {"old": filter.position}}),
+            )
+            .await?;
+        }
+        tx.commit().await?;
+        ctx.bot.cache.filter_groups.invalidate(&group.id).await;
 
         if ret.is_some() {
             btn_ctx
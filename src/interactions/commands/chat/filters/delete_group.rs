@@ -1,7 +1,8 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
-    database::models::filter_group::FilterGroup,
+    core::audit::{self, AuditAction, AuditTarget},
+    database::models::{filter::Filter, filter_group::FilterGroup},
     errors::StarboardResult,
     get_guild_id,
     interactions::context::CommandCtx,
@@ -28,9 +29,27 @@ impl DeleteGroup {
             return Ok(());
         };
 
-        let filter = FilterGroup::delete(&ctx.bot.pool, guild_id, &self.name).await?;
+        let mut tx = ctx.bot.pool.begin().await?;
+        let filter = FilterGroup::delete(&mut tx, guild_id, &self.name).await?;
+        if let Some(filter) = &filter {
+            Filter::remove_group_references(&mut tx, filter.id).await?;
+            audit::log(
+                &mut tx,
+                &ctx,
+                AuditAction::Delete,
+                AuditTarget::FilterGroup,
+                filter.id.into(),
+                &filter.name,
+                serde_json::json!({}),
+            )
+            .await?;
+        }
+        tx.commit().await?;
 
         if filter.is_some() {
+            // other groups may have referenced this one
+            ctx.bot.cache.filter_groups.invalidate_all();
+
             btn_ctx
                 .edit_str(&format!("Deleted filter group '{}'.", self.name), true)
                 .await?;
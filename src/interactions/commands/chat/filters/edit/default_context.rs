@@ -0,0 +1,149 @@
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+
+use crate::{
+    database::validation::mentions::parse_role_ids, errors::StarboardResult, get_guild_id,
+    interactions::context::CommandCtx, utils::id_as_i64::GetI64,
+};
+
+use super::{get_filter, parse_min_age, save_filter, validate_roles};
+
+#[derive(CreateOption, CommandOption)]
+pub enum UserBotRequirement {
+    #[option(name = "User must be a bot", value = 0)]
+    MustBeBot,
+    #[option(name = "User must not be a bot", value = 1)]
+    MustBeHuman,
+    #[option(name = "Disabled", value = 2)]
+    Disabled,
+}
+
+impl From<UserBotRequirement> for Option<bool> {
+    fn from(val: UserBotRequirement) -> Self {
+        match val {
+            UserBotRequirement::MustBeBot => Some(true),
+            UserBotRequirement::MustBeHuman => Some(false),
+            UserBotRequirement::Disabled => None,
+        }
+    }
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "default-context",
+    desc = "Edit the conditions for the author of the message."
+)]
+pub struct EditDefaultContext {
+    /// The name of the filter group containing the filter to be edited.
+    #[command(autocomplete = true)]
+    group: String,
+    /// The position of the filter to edit.
+    #[command(min_value = 1, max_value = 1_000)]
+    position: i64,
+
+    /// Require that the user/author has all of these roles.
+    #[command(rename = "user-has-all-of")]
+    user_has_all_of: Option<String>,
+    /// Require that the user/author has at least one of these roles.
+    #[command(rename = "user-has-some-of")]
+    user_has_some_of: Option<String>,
+    /// Require that the user/author is missing all of these roles.
+    #[command(rename = "user-missing-all-of")]
+    user_missing_all_of: Option<String>,
+    /// Require that the user/author is missing at least one of these roles.
+    #[command(rename = "user-missing-some-of")]
+    user_missing_some_of: Option<String>,
+    /// Require that the user is or is not a bot.
+    #[command(rename = "user-is-bot")]
+    user_is_bot: Option<UserBotRequirement>,
+    /// Require that the user's account is at least this old (e.g. "1 week"). Use "disable" to disable.
+    #[command(rename = "user-account-older-than")]
+    user_account_older_than: Option<String>,
+    /// Require that the user joined the server at least this long ago. Use "disable" to disable.
+    #[command(rename = "user-joined-older-than")]
+    user_joined_older_than: Option<String>,
+}
+
+impl EditDefaultContext {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx);
+
+        let filter = get_filter(&mut ctx, guild_id.get_i64(), &self.group, self.position).await?;
+        let Some((group, mut filter)) = filter else {
+            return Ok(());
+        };
+
+        if let Some(val) = self.user_has_all_of {
+            let roles = parse_role_ids(&ctx.bot, guild_id, &val);
+            if let Err(why) = validate_roles(roles.len()) {
+                ctx.respond_str(&why, true).await?;
+                return Ok(());
+            }
+            if roles.is_empty() {
+                filter.user_has_all_of = None;
+            } else {
+                filter.user_has_all_of = Some(roles.into_iter().collect());
+            }
+        }
+        if let Some(val) = self.user_has_some_of {
+            let roles = parse_role_ids(&ctx.bot, guild_id, &val);
+            if let Err(why) = validate_roles(roles.len()) {
+                ctx.respond_str(&why, true).await?;
+                return Ok(());
+            }
+            if roles.is_empty() {
+                filter.user_has_some_of = None;
+            } else {
+                filter.user_has_some_of = Some(roles.into_iter().collect());
+            }
+        }
+        if let Some(val) = self.user_missing_all_of {
+            let roles = parse_role_ids(&ctx.bot, guild_id, &val);
+            if let Err(why) = validate_roles(roles.len()) {
+                ctx.respond_str(&why, true).await?;
+                return Ok(());
+            }
+            if roles.is_empty() {
+                filter.user_missing_all_of = None;
+            } else {
+                filter.user_missing_all_of = Some(roles.into_iter().collect());
+            }
+        }
+        if let Some(val) = self.user_missing_some_of {
+            let roles = parse_role_ids(&ctx.bot, guild_id, &val);
+            if let Err(why) = validate_roles(roles.len()) {
+                ctx.respond_str(&why, true).await?;
+                return Ok(());
+            }
+            if roles.is_empty() {
+                filter.user_missing_some_of = None;
+            } else {
+                filter.user_missing_some_of = Some(roles.into_iter().collect());
+            }
+        }
+        if let Some(val) = self.user_is_bot {
+            filter.user_is_bot = val.into();
+        }
+        if let Some(val) = self.user_account_older_than {
+            let val = match parse_min_age(&val) {
+                Ok(val) => val,
+                Err(why) => {
+                    ctx.respond_str(&why, true).await?;
+                    return Ok(());
+                }
+            };
+            filter.user_account_older_than = val;
+        }
+        if let Some(val) = self.user_joined_older_than {
+            let val = match parse_min_age(&val) {
+                Ok(val) => val,
+                Err(why) => {
+                    ctx.respond_str(&why, true).await?;
+                    return Ok(());
+                }
+            };
+            filter.user_joined_older_than = val;
+        }
+
+        save_filter(&mut ctx, &group, filter).await
+    }
+}
@@ -0,0 +1,105 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    database::{
+        models::{filter::Filter, filter_group::FilterGroup},
+        validation::filter_groups::{parse_filter_groups, validate_group_references},
+    },
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::context::CommandCtx,
+    utils::id_as_i64::GetI64,
+};
+
+use super::{get_filter, save_filter};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "general",
+    desc = "Edit how a filter affects its filter group, and which groups it depends on."
+)]
+pub struct EditGeneral {
+    /// The name of the filter group containing the filter to be edited.
+    #[command(autocomplete = true)]
+    group: String,
+    /// The position of the filter to edit.
+    #[command(min_value = 1, max_value = 1_000)]
+    position: i64,
+
+    /// If true and this filter passes, the entire filter groups passes.
+    #[command(rename = "instant-pass")]
+    instant_pass: Option<bool>,
+    /// If true and this filter fails, then the entire filter group fails.
+    #[command(rename = "instant-fail")]
+    instant_fail: Option<bool>,
+
+    /// Require that all of these filter groups pass (AND). Use "none" to disable.
+    #[command(rename = "passes-all-of-groups")]
+    passes_all_of_groups: Option<String>,
+    /// Require that at least one of these filter groups passes (OR). Use "none" to disable.
+    #[command(rename = "passes-some-of-groups")]
+    passes_some_of_groups: Option<String>,
+    /// Require that none of these filter groups pass (NOT). Use "none" to disable.
+    #[command(rename = "passes-none-of-groups")]
+    passes_none_of_groups: Option<String>,
+}
+
+impl EditGeneral {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx).get_i64();
+
+        let filter = get_filter(&mut ctx, guild_id, &self.group, self.position).await?;
+        let Some((group, mut filter)) = filter else {
+            return Ok(());
+        };
+
+        if let Some(val) = self.instant_pass {
+            filter.instant_pass = val;
+        }
+        if let Some(val) = self.instant_fail {
+            filter.instant_fail = val;
+        }
+
+        let edits_groups = self.passes_all_of_groups.is_some()
+            || self.passes_some_of_groups.is_some()
+            || self.passes_none_of_groups.is_some();
+        if edits_groups {
+            let groups = FilterGroup::list_by_guild(&ctx.bot.pool, guild_id).await?;
+            if let Some(val) = self.passes_all_of_groups {
+                filter.passes_all_of_groups = match parse_filter_groups(&groups, &val) {
+                    Ok(val) => val,
+                    Err(why) => {
+                        ctx.respond_str(&why, true).await?;
+                        return Ok(());
+                    }
+                };
+            }
+            if let Some(val) = self.passes_some_of_groups {
+                filter.passes_some_of_groups = match parse_filter_groups(&groups, &val) {
+                    Ok(val) => val,
+                    Err(why) => {
+                        ctx.respond_str(&why, true).await?;
+                        return Ok(());
+                    }
+                };
+            }
+            if let Some(val) = self.passes_none_of_groups {
+                filter.passes_none_of_groups = match parse_filter_groups(&groups, &val) {
+                    Ok(val) => val,
+                    Err(why) => {
+                        ctx.respond_str(&why, true).await?;
+                        return Ok(());
+                    }
+                };
+            }
+
+            let guild_filters = Filter::list_by_guild(&ctx.bot.pool, guild_id).await?;
+            if let Err(why) = validate_group_references(&filter, &guild_filters) {
+                ctx.respond_str(&why, true).await?;
+                return Ok(());
+            }
+        }
+
+        save_filter(&mut ctx, &group, filter).await
+    }
+}
@@ -0,0 +1,384 @@
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+
+use crate::{
+    constants,
+    core::premium::is_premium::is_guild_premium,
+    database::validation::{
+        filter_content::{parse_attachment_types, parse_domains, parse_extensions},
+        mentions::textable_channel_ids,
+    },
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::context::CommandCtx,
+    utils::{id_as_i64::GetI64, safe_regex::build_regex},
+};
+
+use super::{get_filter, save_filter, validate_channels};
+
+#[derive(CreateOption, CommandOption)]
+pub enum ContentRequirement {
+    #[option(name = "Required", value = 0)]
+    Required,
+    #[option(name = "Forbidden", value = 1)]
+    Forbidden,
+    #[option(name = "Disabled", value = 2)]
+    Disabled,
+}
+
+impl From<ContentRequirement> for Option<bool> {
+    fn from(val: ContentRequirement) -> Self {
+        match val {
+            ContentRequirement::Required => Some(true),
+            ContentRequirement::Forbidden => Some(false),
+            ContentRequirement::Disabled => None,
+        }
+    }
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "message-context",
+    desc = "Edit the conditions for the message."
+)]
+pub struct EditMessageContext {
+    /// The name of the filter group containing the filter to be edited.
+    #[command(autocomplete = true)]
+    group: String,
+    /// The position of the filter to edit.
+    #[command(min_value = 1, max_value = 1_000)]
+    position: i64,
+
+    /// Require that the message was sent in one of these channels.
+    #[command(rename = "in-channel")]
+    in_channel: Option<String>,
+    /// Require that the message was not sent in one of these channels.
+    #[command(rename = "not-in-channel")]
+    not_in_channel: Option<String>,
+    /// Require that the message was sent in one of these channels or their sub-channels.
+    #[command(rename = "in-channel-or-sub-channels")]
+    in_channel_or_sub_channels: Option<String>,
+    /// Require that the message was not sent in one of these channels or their sub-channels.
+    #[command(rename = "not-in-channel-or-sub-channels")]
+    not_in_channel_or_sub_channels: Option<String>,
+    /// Require that the message has at least this many attachments. Use 0 to disable.
+    #[command(rename = "min-attachments")]
+    min_attachments: Option<i64>,
+    /// Require that the message have at most this many attachments. Use -1 to disable.
+    #[command(rename = "max-attachments")]
+    max_attachments: Option<i64>,
+    /// Require that the message be at least this many characters long. Use 0 to disable.
+    #[command(rename = "min-length")]
+    min_length: Option<i64>,
+    /// Require that the message be at most this many characters long. Use -1 to disable.
+    #[command(rename = "max-length")]
+    max_length: Option<i64>,
+    /// (Premium) Require that the message match this regex. Use `.*` to disable.
+    matches: Option<String>,
+    /// (Premium) Require that the message not match this regex. Use `.*` to disable.
+    #[command(rename = "not-matches")]
+    not_matches: Option<String>,
+    /// Require that the message does or doesn't contain a link.
+    #[command(rename = "contains-link")]
+    contains_link: Option<ContentRequirement>,
+    /// Require that the message does or doesn't contain a server invite.
+    #[command(rename = "contains-invite")]
+    contains_invite: Option<ContentRequirement>,
+    /// Require that every link in the message is to one of these domains. Use "none" to disable.
+    #[command(rename = "allowed-link-domains")]
+    allowed_link_domains: Option<String>,
+    /// Require that no link in the message is to one of these domains. Use "none" to disable.
+    #[command(rename = "denied-link-domains")]
+    denied_link_domains: Option<String>,
+    /// Require that the message does or doesn't mention any users.
+    #[command(rename = "mentions-users")]
+    mentions_users: Option<ContentRequirement>,
+    /// Require that the message does or doesn't mention any roles.
+    #[command(rename = "mentions-roles")]
+    mentions_roles: Option<ContentRequirement>,
+    /// Require that the message does or doesn't have a sticker.
+    #[command(rename = "has-sticker")]
+    has_sticker: Option<ContentRequirement>,
+    /// Require an attachment of one of these types (image, video, audio, file). Use "none" to disable.
+    #[command(rename = "attachment-types")]
+    attachment_types: Option<String>,
+    /// Require an attachment with one of these file extensions (e.g. "png gif"). Use "none" to disable.
+    #[command(rename = "attachment-extensions")]
+    attachment_extensions: Option<String>,
+    /// Require that the message is or isn't a reply.
+    #[command(rename = "is-reply")]
+    is_reply: Option<ContentRequirement>,
+}
+
+impl EditMessageContext {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx);
+
+        let filter = get_filter(&mut ctx, guild_id.get_i64(), &self.group, self.position).await?;
+        let Some((group, mut filter)) = filter else {
+            return Ok(());
+        };
+
+        let premium = is_guild_premium(&ctx.bot, guild_id.get_i64(), true).await?;
+
+        if let Some(val) = self.in_channel {
+            let channels = textable_channel_ids(&ctx.bot, guild_id, &val).await?;
+            if let Err(why) = validate_channels(channels.len()) {
+                ctx.respond_str(&why, true).await?;
+                return Ok(());
+            }
+            if channels.is_empty() {
+                filter.in_channel = None;
+            } else {
+                filter.in_channel = Some(channels.into_iter().collect());
+            }
+        }
+        if let Some(val) = self.not_in_channel {
+            let channels = textable_channel_ids(&ctx.bot, guild_id, &val).await?;
+            if let Err(why) = validate_channels(channels.len()) {
+                ctx.respond_str(&why, true).await?;
+                return Ok(());
+            }
+            if channels.is_empty() {
+                filter.not_in_channel = None;
+            } else {
+                filter.not_in_channel = Some(channels.into_iter().collect());
+            }
+        }
+        if let Some(val) = self.in_channel_or_sub_channels {
+            let channels = textable_channel_ids(&ctx.bot, guild_id, &val).await?;
+            if let Err(why) = validate_channels(channels.len()) {
+                ctx.respond_str(&why, true).await?;
+                return Ok(());
+            }
+            if channels.is_empty() {
+                filter.in_channel_or_sub_channels = None;
+            } else {
+                filter.in_channel_or_sub_channels = Some(channels.into_iter().collect());
+            }
+        }
+        if let Some(val) = self.not_in_channel_or_sub_channels {
+            let channels = textable_channel_ids(&ctx.bot, guild_id, &val).await?;
+            if let Err(why) = validate_channels(channels.len()) {
+                ctx.respond_str(&why, true).await?;
+                return Ok(());
+            }
+            if channels.is_empty() {
+                filter.not_in_channel_or_sub_channels = None;
+            } else {
+                filter.not_in_channel_or_sub_channels = Some(channels.into_iter().collect());
+            }
+        }
+        if let Some(val) = self.min_attachments {
+            if val > constants::MAX_ATTACHMENTS {
+                ctx.respond_str(
+                    &format!(
+                        "You can only have up to {} attachments.",
+                        constants::MAX_ATTACHMENTS
+                    ),
+                    true,
+                )
+                .await?;
+                return Ok(());
+            } else if val < 0 {
+                ctx.respond_str("`min-attachments` must be at least 0.", true)
+                    .await?;
+                return Ok(());
+            }
+
+            if val == 0 {
+                filter.min_attachments = None;
+            } else {
+                filter.min_attachments = Some(val.try_into().unwrap());
+            }
+        }
+        if let Some(val) = self.max_attachments {
+            if val > constants::MAX_ATTACHMENTS {
+                ctx.respond_str(
+                    &format!(
+                        "You can only have up to {} attachments.",
+                        constants::MAX_ATTACHMENTS
+                    ),
+                    true,
+                )
+                .await?;
+                return Ok(());
+            } else if val < -1 {
+                ctx.respond_str("`max-attachments` must be at least -1.", true)
+                    .await?;
+                return Ok(());
+            }
+
+            if val == -1 {
+                filter.max_attachments = None;
+            } else {
+                filter.max_attachments = Some(val.try_into().unwrap());
+            }
+        }
+        if let Some(val) = self.min_length {
+            if val > constants::MAX_LENGTH {
+                ctx.respond_str(
+                    &format!(
+                        "`min-length` cannot be longer than {}.",
+                        constants::MAX_LENGTH
+                    ),
+                    true,
+                )
+                .await?;
+                return Ok(());
+            } else if val < 0 {
+                ctx.respond_str("`min-length` must be at least 0.", true)
+                    .await?;
+                return Ok(());
+            }
+
+            if val == 0 {
+                filter.min_length = None;
+            } else {
+                filter.min_length = Some(val.try_into().unwrap());
+            }
+        }
+        if let Some(val) = self.max_length {
+            if val > constants::MAX_LENGTH {
+                ctx.respond_str(
+                    &format!(
+                        "`max-length` cannot be longer than {}.",
+                        constants::MAX_LENGTH
+                    ),
+                    true,
+                )
+                .await?;
+                return Ok(());
+            } else if val < -1 {
+                ctx.respond_str("`max-length` must be at least -1.", true)
+                    .await?;
+                return Ok(());
+            }
+
+            if val == -1 {
+                filter.max_length = None;
+            } else {
+                filter.max_length = Some(val.try_into().unwrap());
+            }
+        }
+        if let Some(val) = self.matches {
+            if val.len() > constants::MAX_REGEX_LENGTH as usize {
+                ctx.respond_str(
+                    &format!(
+                        "`matches` cannot be longer than {}.",
+                        constants::MAX_REGEX_LENGTH
+                    ),
+                    true,
+                )
+                .await?;
+                return Ok(());
+            }
+
+            if val == ".*" {
+                filter.matches = None;
+            } else {
+                if !premium {
+                    ctx.respond_str(
+                        "Only premium servers can use the `matches` condition.",
+                        true,
+                    )
+                    .await?;
+                    return Ok(());
+                }
+                if let Err(why) = build_regex(&val) {
+                    ctx.respond_str(&format!("```\n{why}\n```"), true).await?;
+                    return Ok(());
+                }
+
+                filter.matches = Some(val);
+            }
+        }
+        if let Some(val) = self.not_matches {
+            if val.len() > constants::MAX_REGEX_LENGTH as usize {
+                ctx.respond_str(
+                    &format!(
+                        "`not-matches` cannot be longer than {}.",
+                        constants::MAX_REGEX_LENGTH
+                    ),
+                    true,
+                )
+                .await?;
+                return Ok(());
+            }
+
+            if val == ".*" {
+                filter.not_matches = None;
+            } else {
+                if !premium {
+                    ctx.respond_str(
+                        "Only premium servers can use the `not-matches` condition.",
+                        true,
+                    )
+                    .await?;
+                    return Ok(());
+                }
+                if let Err(why) = build_regex(&val) {
+                    ctx.respond_str(&format!("```\n{why}\n```"), true).await?;
+                    return Ok(());
+                }
+
+                filter.not_matches = Some(val);
+            }
+        }
+        if let Some(val) = self.contains_link {
+            filter.contains_link = val.into();
+        }
+        if let Some(val) = self.contains_invite {
+            filter.contains_invite = val.into();
+        }
+        if let Some(val) = self.allowed_link_domains {
+            filter.allowed_link_domains = match parse_domains(&val) {
+                Ok(val) => val,
+                Err(why) => {
+                    ctx.respond_str(&why, true).await?;
+                    return Ok(());
+                }
+            };
+        }
+        if let Some(val) = self.denied_link_domains {
+            filter.denied_link_domains = match parse_domains(&val) {
+                Ok(val) => val,
+                Err(why) => {
+                    ctx.respond_str(&why, true).await?;
+                    return Ok(());
+                }
+            };
+        }
+        if let Some(val) = self.mentions_users {
+            filter.mentions_users = val.into();
+        }
+        if let Some(val) = self.mentions_roles {
+            filter.mentions_roles = val.into();
+        }
+        if let Some(val) = self.has_sticker {
+            filter.has_sticker = val.into();
+        }
+        if let Some(val) = self.attachment_types {
+            filter.attachment_types = match parse_attachment_types(&val) {
+                Ok(val) => val,
+                Err(why) => {
+                    ctx.respond_str(&why, true).await?;
+                    return Ok(());
+                }
+            };
+        }
+        if let Some(val) = self.attachment_extensions {
+            filter.attachment_extensions = match parse_extensions(&val) {
+                Ok(val) => val,
+                Err(why) => {
+                    ctx.respond_str(&why, true).await?;
+                    return Ok(());
+                }
+            };
+        }
+        if let Some(val) = self.is_reply {
+            filter.is_reply = val.into();
+        }
+
+        save_filter(&mut ctx, &group, filter).await
+    }
+}
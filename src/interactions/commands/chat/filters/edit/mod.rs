@@ -0,0 +1,156 @@
+pub mod default_context;
+pub mod general;
+pub mod message_context;
+pub mod vote_context;
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    constants,
+    core::audit::{self, AuditAction, AuditTarget},
+    database::{
+        models::{filter::Filter, filter_group::FilterGroup},
+        validation::time_delta::parse_time_delta,
+    },
+    errors::StarboardResult,
+    interactions::context::CommandCtx,
+};
+
+fn validate_roles(length: usize) -> Result<(), String> {
+    if length > constants::MAX_FILTER_ROLES {
+        Err(format!(
+            "You can only have up to {} roles in a list.",
+            constants::MAX_FILTER_ROLES
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_channels(length: usize) -> Result<(), String> {
+    if length > constants::MAX_FILTER_CHANNELS {
+        Err(format!(
+            "You can only have up to {} channels in a list.",
+            constants::MAX_FILTER_CHANNELS
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Parses a minimum account or join age. Returns `None` if the condition
+/// should be disabled.
+fn parse_min_age(val: &str) -> Result<Option<i64>, String> {
+    if val == "disable" {
+        return Ok(None);
+    }
+
+    let delta = parse_time_delta(val)?;
+    if delta > constants::MAX_OLDER_THAN {
+        return Err(format!(
+            "The age cannot be longer than {}.",
+            humantime::format_duration(std::time::Duration::from_secs(
+                constants::MAX_OLDER_THAN as u64
+            ))
+        ));
+    }
+
+    if delta == 0 {
+        Ok(None)
+    } else {
+        Ok(Some(delta))
+    }
+}
+
+/// Fetches the filter group and the filter to edit, responding with an error
+/// if either doesn't exist.
+async fn get_filter(
+    ctx: &mut CommandCtx,
+    guild_id: i64,
+    group: &str,
+    position: i64,
+) -> StarboardResult<Option<(FilterGroup, Filter)>> {
+    let Some(group) = FilterGroup::get_by_name(&ctx.bot.pool, guild_id, group).await? else {
+        ctx.respond_str(&format!("No filter group named '{group}' exists."), true)
+            .await?;
+        return Ok(None);
+    };
+
+    let filter = Filter::get_by_position(&ctx.bot.pool, group.id, position as i16).await?;
+    let Some(filter) = filter else {
+        ctx.respond_str(
+            &format!(
+                "No filter for group '{}' at {} exists.",
+                group.name, position
+            ),
+            true,
+        )
+        .await?;
+        return Ok(None);
+    };
+
+    Ok(Some((group, filter)))
+}
+
+async fn save_filter(
+    ctx: &mut CommandCtx,
+    group: &FilterGroup,
+    filter: Filter,
+) -> StarboardResult<()> {
+    let position = filter.position;
+    let group_id = filter.filter_group_id;
+    let filter_id = filter.id;
+    let old = Filter::get_by_position(&ctx.bot.pool, group_id, position).await?;
+    let changes = old.map(|old| audit::diff(&old, &filter));
+    let mut tx = ctx.bot.pool.begin().await?;
+    filter.update_settings(&mut tx).await?;
+    if let Some(changes) = changes {
+        audit::log(
+            &mut tx,
+            ctx,
+            AuditAction::Edit,
+            AuditTarget::Filter,
+            filter_id.into(),
+            &audit::filter_name(&group.name, position),
+            changes,
+        )
+        .await?;
+    }
+    tx.commit().await?;
+    ctx.bot.cache.filter_groups.invalidate(&group_id).await;
+
+    ctx.respond_str(
+        &format!(
+            "Updated settings for filter at {} for group '{}'.",
+            position, group.name
+        ),
+        false,
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "edit", desc = "Edit a filters conditions.")]
+pub enum Edit {
+    #[command(name = "general")]
+    General(general::EditGeneral),
+    #[command(name = "default-context")]
+    DefaultContext(default_context::EditDefaultContext),
+    #[command(name = "message-context")]
+    MessageContext(message_context::EditMessageContext),
+    #[command(name = "vote-context")]
+    VoteContext(vote_context::EditVoteContext),
+}
+
+impl Edit {
+    pub async fn callback(self, ctx: CommandCtx) -> StarboardResult<()> {
+        match self {
+            Self::General(cmd) => cmd.callback(ctx).await,
+            Self::DefaultContext(cmd) => cmd.callback(ctx).await,
+            Self::MessageContext(cmd) => cmd.callback(ctx).await,
+            Self::VoteContext(cmd) => cmd.callback(ctx).await,
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    database::validation::{
+        mentions::parse_role_ids,
+        time_delta::{parse_time_delta, validate_relative_duration},
+    },
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::context::CommandCtx,
+    utils::id_as_i64::GetI64,
+};
+
+use super::{get_filter, parse_min_age, save_filter, validate_roles};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "vote-context", desc = "Edit the conditions for the voter.")]
+pub struct EditVoteContext {
+    /// The name of the filter group containing the filter to be edited.
+    #[command(autocomplete = true)]
+    group: String,
+    /// The position of the filter to edit.
+    #[command(min_value = 1, max_value = 1_000)]
+    position: i64,
+
+    /// Require that the voter has all of these roles.
+    #[command(rename = "voter-has-all-of")]
+    voter_has_all_of: Option<String>,
+    /// Require that the voter has at least one of these roles.
+    #[command(rename = "voter-has-some-of")]
+    voter_has_some_of: Option<String>,
+    /// Require that the voter is missing all of these roles.
+    #[command(rename = "voter-missing-all-of")]
+    voter_missing_all_of: Option<String>,
+    /// Require that the voter is missing at least one of these roles.
+    #[command(rename = "voter-missing-some-of")]
+    voter_missing_some_of: Option<String>,
+    /// Require that the message being voted on is over a certain age. Use "disable" to disable.
+    #[command(rename = "older-than")]
+    older_than: Option<String>,
+    /// Require that the message being voted on is under a certain age. Use "disable" to disable.
+    #[command(rename = "newer-than")]
+    newer_than: Option<String>,
+    /// Require that the voter's account is at least this old (e.g. "1 week"). Use "disable" to disable.
+    #[command(rename = "voter-account-older-than")]
+    voter_account_older_than: Option<String>,
+    /// Require that the voter joined the server at least this long ago. Use "disable" to disable.
+    #[command(rename = "voter-joined-older-than")]
+    voter_joined_older_than: Option<String>,
+}
+
+impl EditVoteContext {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx);
+
+        let filter = get_filter(&mut ctx, guild_id.get_i64(), &self.group, self.position).await?;
+        let Some((group, mut filter)) = filter else {
+            return Ok(());
+        };
+
+        if let Some(val) = self.voter_has_all_of {
+            let roles = parse_role_ids(&ctx.bot, guild_id, &val);
+            if let Err(why) = validate_roles(roles.len()) {
+                ctx.respond_str(&why, true).await?;
+                return Ok(());
+            }
+            if roles.is_empty() {
+                filter.voter_has_all_of = None;
+            } else {
+                filter.voter_has_all_of = Some(roles.into_iter().collect());
+            }
+        }
+        if let Some(val) = self.voter_has_some_of {
+            let roles = parse_role_ids(&ctx.bot, guild_id, &val);
+            if let Err(why) = validate_roles(roles.len()) {
+                ctx.respond_str(&why, true).await?;
+                return Ok(());
+            }
+            if roles.is_empty() {
+                filter.voter_has_some_of = None;
+            } else {
+                filter.voter_has_some_of = Some(roles.into_iter().collect());
+            }
+        }
+        if let Some(val) = self.voter_missing_all_of {
+            let roles = parse_role_ids(&ctx.bot, guild_id, &val);
+            if let Err(why) = validate_roles(roles.len()) {
+                ctx.respond_str(&why, true).await?;
+                return Ok(());
+            }
+            if roles.is_empty() {
+                filter.voter_missing_all_of = None;
+            } else {
+                filter.voter_missing_all_of = Some(roles.into_iter().collect());
+            }
+        }
+        if let Some(val) = self.voter_missing_some_of {
+            let roles = parse_role_ids(&ctx.bot, guild_id, &val);
+            if let Err(why) = validate_roles(roles.len()) {
+                ctx.respond_str(&why, true).await?;
+                return Ok(());
+            }
+            if roles.is_empty() {
+                filter.voter_missing_some_of = None;
+            } else {
+                filter.voter_missing_some_of = Some(roles.into_iter().collect());
+            }
+        }
+        if let Some(val) = self.older_than {
+            if val == "disable" {
+                filter.older_than = None;
+            } else {
+                let delta = match parse_time_delta(&val) {
+                    Ok(val) => val,
+                    Err(why) => {
+                        ctx.respond_str(&why, true).await?;
+                        return Ok(());
+                    }
+                };
+                filter.older_than = Some(delta);
+            }
+        }
+        if let Some(val) = self.newer_than {
+            if val == "disable" {
+                filter.newer_than = None;
+            } else {
+                let delta = match parse_time_delta(&val) {
+                    Ok(val) => val,
+                    Err(why) => {
+                        ctx.respond_str(&why, true).await?;
+                        return Ok(());
+                    }
+                };
+                filter.newer_than = Some(delta);
+            }
+        }
+        if let Some(val) = self.voter_account_older_than {
+            let val = match parse_min_age(&val) {
+                Ok(val) => val,
+                Err(why) => {
+                    ctx.respond_str(&why, true).await?;
+                    return Ok(());
+                }
+            };
+            filter.voter_account_older_than = val;
+        }
+        if let Some(val) = self.voter_joined_older_than {
+            let val = match parse_min_age(&val) {
+                Ok(val) => val,
+                Err(why) => {
+                    ctx.respond_str(&why, true).await?;
+                    return Ok(());
+                }
+            };
+            filter.voter_joined_older_than = val;
+        }
+
+        if let Err(why) = validate_relative_duration(filter.newer_than, filter.older_than) {
+            ctx.respond_str(&why, true).await?;
+            return Ok(());
+        }
+
+        save_filter(&mut ctx, &group, filter).await
+    }
+}
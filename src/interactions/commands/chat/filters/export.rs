@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::http::attachment::Attachment;
+
+use crate::{
+    core::export::filters::ExportedFilterGroup,
+    database::models::{filter::Filter, filter_group::FilterGroup},
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::context::CommandCtx,
+    utils::id_as_i64::GetI64,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "export", desc = "Export a filter group as a JSON file.")]
+pub struct Export {
+    /// The name of the filter group to export.
+    #[command(autocomplete = true)]
+    group: String,
+}
+
+impl Export {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx).get_i64();
+
+        let group = FilterGroup::get_by_name(&ctx.bot.pool, guild_id, &self.group).await?;
+        let Some(group) = group else {
+            ctx.respond_str(
+                &format!("No filter group named '{}' exists.", self.group),
+                true,
+            )
+            .await?;
+            return Ok(());
+        };
+
+        let filters = Filter::list_by_filter(&ctx.bot.pool, group.id).await?;
+        let group_names: HashMap<_, _> = FilterGroup::list_by_guild(&ctx.bot.pool, guild_id)
+            .await?
+            .into_iter()
+            .map(|g| (g.id, g.name))
+            .collect();
+
+        let exported = ExportedFilterGroup::new(&group, &filters, &group_names);
+        let file = serde_json::to_vec_pretty(&exported)?;
+        let attachment =
+            Attachment::from_bytes(format!("filter-group-{}.json", group.name), file, 0);
+
+        let resp = ctx
+            .build_resp()
+            .content(format!("Exported filter group '{}'.", group.name))
+            .attachments([attachment])
+            .build();
+        ctx.respond(resp).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::channel::Attachment;
+
+use crate::{
+    constants,
+    core::{
+        audit::{self, AuditAction, AuditTarget},
+        export::filters::ExportedFilterGroup,
+        premium::is_premium::is_guild_premium,
+    },
+    database::{models::filter_group::FilterGroup, validation::name::validate_name, DbGuild},
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::context::CommandCtx,
+    utils::id_as_i64::GetI64,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "import",
+    desc = "Create a filter group from a file made by /filters export."
+)]
+pub struct Import {
+    /// The exported filter group.
+    file: Attachment,
+    /// The name for the new filter group. Defaults to the name in the file.
+    name: Option<String>,
+}
+
+impl Import {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx);
+        let guild_id_i64 = guild_id.get_i64();
+
+        if self.file.size > constants::MAX_IMPORT_SIZE {
+            ctx.respond_str("That file is too large.", true).await?;
+            return Ok(());
+        }
+
+        let count = FilterGroup::list_by_guild(&ctx.bot.pool, guild_id_i64)
+            .await?
+            .len();
+        if count >= constants::MAX_FILTER_GROUPS {
+            ctx.respond_str(
+                &format!(
+                    "You can only have up to {} filter groups.",
+                    constants::MAX_FILTER_GROUPS
+                ),
+                true,
+            )
+            .await?;
+            return Ok(());
+        }
+
+        ctx.defer(true).await?;
+
+        let file = ctx
+            .bot
+            .reqwest
+            .get(&self.file.url)
+            .send()
+            .await?
+            .bytes()
+            .await?;
+        let mut exported: ExportedFilterGroup = match serde_json::from_slice(&file) {
+            Ok(val) => val,
+            Err(why) => {
+                ctx.respond_str(&format!("Invalid file: {why}"), true)
+                    .await?;
+                return Ok(());
+            }
+        };
+
+        let premium = is_guild_premium(&ctx.bot, guild_id_i64, true).await?;
+        if let Err(why) = exported.validate(premium) {
+            ctx.respond_str(&why, true).await?;
+            return Ok(());
+        }
+
+        let name = self.name.unwrap_or_else(|| exported.name.clone());
+        let name = match validate_name(&name) {
+            Ok(val) => val,
+            Err(why) => {
+                ctx.respond_str(&why, true).await?;
+                return Ok(());
+            }
+        };
+
+        DbGuild::create(&ctx.bot.pool, guild_id_i64).await?;
+        let mut tx = ctx.bot.pool.begin().await?;
+        let imported = exported.import(&ctx.bot, &mut tx, guild_id, &name).await?;
+        let Some((group, report)) = imported else {
+            ctx.respond_str(
+                &format!("A filter group named '{name}' already exists."),
+                true,
+            )
+            .await?;
+            return Ok(());
+        };
+
+        audit::log(
+            &mut tx,
+            &ctx,
+            AuditAction::Import,
+            AuditTarget::FilterGroup,
+            group.id.into(),
+            &group.name,
+            serde_json::json!({}),
+        )
+        .await?;
+        tx.commit().await?;
+        ctx.bot.cache.filter_groups.invalidate(&group.id).await;
+
+        let mut message = format!("Imported filter group '{}'.", group.name);
+        if !report.is_empty() {
+            message.push_str(
+                "\n\nSome conditions referenced things that don't exist in this server, \
+                so they were left out:\n",
+            );
+            message.push_str(&report.describe());
+        }
+        ctx.respond_str(&message, true).await?;
+
+        Ok(())
+    }
+}
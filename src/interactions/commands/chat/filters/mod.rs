@@ -3,8 +3,11 @@ mod create_group;
 mod delete_filter;
 mod delete_group;
 mod edit;
+mod export;
+mod import;
 mod move_filter;
 mod rename_group;
+mod test;
 mod view;
 
 use twilight_interactions::command::{CommandModel, CreateCommand};
@@ -14,7 +17,6 @@ use crate::{
     interactions::{commands::permissions::manage_roles_channels, context::CommandCtx},
 };
 
-#[allow(clippy::large_enum_variant)] // Edit(edit::Edit) being the culprit
 #[derive(CommandModel, CreateCommand)]
 #[command(
     name = "filters",
@@ -41,6 +43,13 @@ pub enum Filters {
 
     #[command(name = "view")]
     View(view::View),
+    #[command(name = "test")]
+    Test(test::Test),
+
+    #[command(name = "export")]
+    Export(export::Export),
+    #[command(name = "import")]
+    Import(import::Import),
 }
 
 impl Filters {
@@ -56,6 +65,10 @@ impl Filters {
             Self::Edit(cmd) => cmd.callback(ctx).await,
 
             Self::View(cmd) => cmd.callback(ctx).await,
+            Self::Test(cmd) => cmd.callback(ctx).await,
+
+            Self::Export(cmd) => cmd.callback(ctx).await,
+            Self::Import(cmd) => cmd.callback(ctx).await,
         }
     }
 }
@@ -1,6 +1,7 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
+    core::audit::{self, AuditAction, AuditTarget},
     database::models::{filter::Filter, filter_group::FilterGroup},
     errors::StarboardResult,
     get_guild_id,
@@ -32,14 +33,30 @@ impl MoveFilter {
             return Ok(());
         };
 
+        let mut tx = ctx.bot.pool.begin().await?;
         let ret = Filter::set_position(
-            &ctx.bot.pool,
+            &mut tx,
             group.id,
             self.current_position as i16,
             self.new_position as i16,
         )
         .await?;
         if ret.is_some() {
+            audit::log(
+                &mut tx,
+                &ctx,
+                AuditAction::Edit,
+                AuditTarget::FilterGroup,
+                group.id.into(),
+                &group.name,
+                serde_json::json!({"filter_position": {
+                    "old": self.current_position,
+                    "new": self.new_position,
+                }}),
+            )
+            .await?;
+            tx.commit().await?;
+            ctx.bot.cache.filter_groups.invalidate(&group.id).await;
             ctx.respond_str(
                 &format!(
                     "Filter moved from {} to {}.",
@@ -1,6 +1,7 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
+    core::audit::{self, AuditAction, AuditTarget},
     database::models::filter_group::FilterGroup,
     errors::StarboardResult,
     get_guild_id,
@@ -29,10 +30,22 @@ impl RenameGroup {
             return Ok(());
         };
 
-        let ret = FilterGroup::rename(&ctx.bot.pool, group.id, &self.new_name).await;
+        let mut tx = ctx.bot.pool.begin().await?;
+        let ret = FilterGroup::rename(&mut tx, group.id, &self.new_name).await;
 
         match ret {
             Ok(_) => {
+                audit::log(
+                    &mut tx,
+                    &ctx,
+                    AuditAction::Rename,
+                    AuditTarget::FilterGroup,
+                    group.id.into(),
+                    &self.new_name,
+                    audit::rename(&group.name, &self.new_name),
+                )
+                .await?;
+                tx.commit().await?;
                 ctx.respond_str("Renamed filter group.", false).await?;
             }
             Err(why) => {
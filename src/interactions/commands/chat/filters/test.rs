@@ -0,0 +1,158 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::user::User;
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    cache::MessageResult,
+    constants,
+    core::filters::{FilterEvaluater, FilterTrace},
+    database::models::{filter::Filter, filter_group::FilterGroup},
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::context::CommandCtx,
+    utils::{
+        id_as_i64::GetI64,
+        into_id::IntoId,
+        message_link::{fmt_message_link, parse_message_link},
+    },
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "test",
+    desc = "Test a filter group against a message, and see why it passes or fails."
+)]
+pub struct Test {
+    /// The filter group to test.
+    #[command(autocomplete = true)]
+    group: String,
+    /// Link to the message to test the filter group against.
+    message: String,
+    /// The user voting on the message. Leave blank to skip the vote context.
+    voter: Option<User>,
+}
+
+impl Test {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx);
+        let bot = ctx.bot.clone();
+
+        let group = FilterGroup::get_by_name(&bot.pool, guild_id.get_i64(), &self.group).await?;
+        let Some(group) = group else {
+            ctx.respond_str(
+                &format!("Filter group '{}' does not exist.", self.group),
+                true,
+            )
+            .await?;
+            return Ok(());
+        };
+
+        let Some((channel_id, message_id)) = parse_message_link(&self.message) else {
+            ctx.respond_str("Invalid message link.", true).await?;
+            return Ok(());
+        };
+        if !bot
+            .cache
+            .guild_has_channel(&bot, guild_id, channel_id.into_id())
+            .await?
+        {
+            ctx.respond_str("That message belongs to a different server.", true)
+                .await?;
+            return Ok(());
+        }
+
+        let message = bot
+            .cache
+            .fog_message(&bot, channel_id.into_id(), message_id.into_id())
+            .await?;
+        let MessageResult::Ok(message) = message else {
+            ctx.respond_str("I couldn't find that message.", true)
+                .await?;
+            return Ok(());
+        };
+
+        let filters = bot.cache.fog_filter_group(&bot, group.id).await?;
+        let mut evaluater = FilterEvaluater::new(
+            &bot,
+            guild_id,
+            message.author_id,
+            self.voter.as_ref().map(|v| v.id),
+            Some(channel_id.into_id()),
+            Some(message_id.into_id()),
+            vec![group.id],
+        );
+        evaluater.set_filters(vec![filters.clone()]);
+        evaluater.set_message(Some(message));
+        let trace = evaluater.trace().await?;
+
+        let link = fmt_message_link(guild_id, channel_id, message_id);
+        let mut desc = format!(
+            "Testing against [this message]({}){}.\n\n",
+            link,
+            match &self.voter {
+                None => " without a voter, so vote context conditions are skipped".to_string(),
+                Some(voter) => format!(" with <@{}> as the voter", voter.id),
+            }
+        );
+        desc.push_str(&format_trace(&trace, &filters));
+
+        let emb = EmbedBuilder::new()
+            .color(constants::EMBED_DARK_BG)
+            .title(format!(
+                "Filter Group '{}' {}",
+                group.name,
+                if trace.passed { "Passed" } else { "Failed" }
+            ))
+            .description(desc)
+            .build();
+
+        ctx.respond(ctx.build_resp().embeds([emb]).build()).await?;
+
+        Ok(())
+    }
+}
+
+fn format_trace(trace: &FilterTrace, filters: &[Filter]) -> String {
+    if filters.is_empty() {
+        return "This filter group has no filters, so it always passes.".to_string();
+    }
+
+    let mut lines = Vec::new();
+    for check in &trace.checks {
+        let mut line = match check.failed_condition {
+            None => format!("Filter {}: passed", check.position),
+            Some(condition) => format!("Filter {}: failed on `{}`", check.position, condition),
+        };
+        if check.instant_pass {
+            line.push_str(", and instant-pass ended the evaluation");
+        }
+        if check.instant_fail {
+            line.push_str(", and instant-fail ended the evaluation");
+        }
+        line.push('.');
+        lines.push(line);
+    }
+
+    let skipped = filters.len() - trace.checks.len();
+    if skipped != 0 {
+        lines.push(format!("{skipped} filter(s) after that were not checked."));
+    }
+
+    let last = trace.checks.last();
+    let summary = if last.is_some_and(|c| c.instant_fail) {
+        "The group failed because a filter with instant-fail failed."
+    } else if last.is_some_and(|c| c.instant_pass) {
+        "The group passed because a filter with instant-pass passed."
+    } else if trace.checks.iter().any(|c| c.failed_condition.is_some()) {
+        concat!(
+            "The group passed because none of the filters that failed have instant-fail ",
+            "enabled."
+        )
+    } else {
+        "The group passed because every filter passed."
+    };
+    lines.push(String::new());
+    lines.push(summary.to_string());
+
+    lines.join("\n")
+}
@@ -1,4 +1,5 @@
-use std::time::Duration;
+use std::{collections::HashMap, time::Duration};
+
 use thousands::Separable;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::channel::message::Embed;
@@ -51,14 +52,18 @@ impl View {
 
         let premium = is_guild_premium(&ctx.bot, guild_id, true).await?;
 
-        let bot = ctx.bot.clone();
+        let tree = GroupTree::new(
+            &groups,
+            Filter::list_by_guild(&ctx.bot.pool, guild_id).await?,
+        );
+
         let mut paginator = SelectPaginatorBuilder::new(ctx);
         let mut start = 0;
         for (x, group) in groups.into_iter().enumerate() {
             if Some(&group.name) == self.group.as_ref() {
                 start = x;
             }
-            let embeds = group_embed(&bot.pool, &group, premium).await?;
+            let embeds = group_embed(&group, premium, &tree);
             let page = SelectPaginatorPageBuilder::new(format!("Filter Group '{}'", group.name))
                 .embeds(embeds);
             paginator = paginator.add_page(page);
@@ -68,11 +73,77 @@ impl View {
     }
 }
 
-async fn group_embed(
-    pool: &sqlx::PgPool,
-    group: &FilterGroup,
-    premium: bool,
-) -> StarboardResult<Vec<Embed>> {
+/// The filter groups of a server and their filters, used to show how the
+/// groups reference each other.
+struct GroupTree {
+    names: HashMap<i32, String>,
+    filters: HashMap<i32, Vec<Filter>>,
+}
+
+impl GroupTree {
+    fn new(groups: &[FilterGroup], guild_filters: Vec<Filter>) -> Self {
+        let names = groups.iter().map(|g| (g.id, g.name.clone())).collect();
+        let mut filters: HashMap<i32, Vec<Filter>> = HashMap::new();
+        for filter in guild_filters {
+            filters
+                .entry(filter.filter_group_id)
+                .or_default()
+                .push(filter);
+        }
+
+        Self { names, filters }
+    }
+
+    fn format_group(
+        &self,
+        group_id: i32,
+        depth: usize,
+        path: &mut Vec<i32>,
+        lines: &mut Vec<String>,
+    ) {
+        let indent = "\u{2003}".repeat(depth);
+        let Some(name) = self.names.get(&group_id) else {
+            lines.push(format!("{indent}└ Unknown group"));
+            return;
+        };
+        if path.contains(&group_id) {
+            lines.push(format!("{indent}└ '{name}' (references itself)"));
+            return;
+        }
+        lines.push(format!("{indent}└ '{name}'"));
+        if path.len() >= constants::MAX_FILTER_GROUP_DEPTH {
+            return;
+        }
+
+        path.push(group_id);
+        for filter in self.filters.get(&group_id).into_iter().flatten() {
+            for (label, ids) in sub_group_lists(filter) {
+                lines.push(format!(
+                    "{indent}\u{2003}└ Filter {} {}:",
+                    filter.position,
+                    label.to_lowercase()
+                ));
+                for id in ids {
+                    self.format_group(*id, depth + 2, path, lines);
+                }
+            }
+        }
+        path.pop();
+    }
+}
+
+fn sub_group_lists(filter: &Filter) -> Vec<(&'static str, &[i32])> {
+    [
+        ("Must pass all of", &filter.passes_all_of_groups),
+        ("Must pass some of", &filter.passes_some_of_groups),
+        ("Must pass none of", &filter.passes_none_of_groups),
+    ]
+    .into_iter()
+    .filter_map(|(label, ids)| ids.as_deref().map(|ids| (label, ids)))
+    .collect()
+}
+
+fn group_embed(group: &FilterGroup, premium: bool, tree: &GroupTree) -> Vec<Embed> {
     let mut ret = Vec::new();
     let emb = EmbedBuilder::new()
         .color(constants::EMBED_DARK_BG)
@@ -84,7 +155,7 @@ async fn group_embed(
         .build();
     ret.push(emb);
 
-    let filters = Filter::list_by_filter(pool, group.id).await?;
+    let filters = tree.filters.get(&group.id).cloned().unwrap_or_default();
     if filters.is_empty() {
         let emb = EmbedBuilder::new()
             .color(constants::EMBED_DARK_BG)
@@ -94,10 +165,10 @@ async fn group_embed(
     }
 
     for filter in filters {
-        ret.push(filter_embed(filter, premium));
+        ret.push(filter_embed(filter, premium, tree));
     }
 
-    Ok(ret)
+    ret
 }
 
 fn format_roles(role_ids: &[i64]) -> String {
@@ -124,7 +195,24 @@ fn format_channels(channel_ids: &[i64]) -> String {
     }
 }
 
-fn filter_embed(filter: Filter, premium: bool) -> Embed {
+fn format_list(items: &[String]) -> String {
+    items
+        .iter()
+        .map(|item| format!("`{item}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn filter_embed(filter: Filter, premium: bool, tree: &GroupTree) -> Embed {
+    let mut sub_groups = Vec::new();
+    for (label, ids) in sub_group_lists(&filter) {
+        let mut lines = vec![format!("{label} these filter groups:")];
+        for id in ids {
+            tree.format_group(*id, 0, &mut vec![filter.filter_group_id], &mut lines);
+        }
+        sub_groups.push(lines.join("\n"));
+    }
+
     let mut default_context = Vec::new();
     let mut message_context = Vec::new();
     let mut vote_context = Vec::new();
@@ -163,6 +251,20 @@ fn filter_embed(filter: Filter, premium: bool) -> Embed {
         };
         default_context.push(desc.to_string());
     }
+    if let Some(val) = filter.user_account_older_than {
+        let desc = format!(
+            "User's account must be older than {}.",
+            humantime::format_duration(Duration::from_secs(val as u64)),
+        );
+        default_context.push(desc);
+    }
+    if let Some(val) = filter.user_joined_older_than {
+        let desc = format!(
+            "User must have joined the server more than {} ago.",
+            humantime::format_duration(Duration::from_secs(val as u64)),
+        );
+        default_context.push(desc);
+    }
 
     // message context
     if let Some(val) = filter.in_channel {
@@ -215,6 +317,82 @@ fn filter_embed(filter: Filter, premium: bool) -> Embed {
         );
         message_context.push(desc);
     }
+    if let Some(val) = filter.contains_link {
+        let desc = if val {
+            "Message must contain a link."
+        } else {
+            "Message must not contain a link."
+        };
+        message_context.push(desc.to_string());
+    }
+    if let Some(val) = filter.contains_invite {
+        let desc = if val {
+            "Message must contain a server invite."
+        } else {
+            "Message must not contain a server invite."
+        };
+        message_context.push(desc.to_string());
+    }
+    if let Some(val) = filter.allowed_link_domains {
+        let desc = format!(
+            "Every link in the message must be to one of these domains:\n{}",
+            format_list(&val)
+        );
+        message_context.push(desc);
+    }
+    if let Some(val) = filter.denied_link_domains {
+        let desc = format!(
+            "No link in the message can be to one of these domains:\n{}",
+            format_list(&val)
+        );
+        message_context.push(desc);
+    }
+    if let Some(val) = filter.mentions_users {
+        let desc = if val {
+            "Message must mention a user."
+        } else {
+            "Message must not mention any users."
+        };
+        message_context.push(desc.to_string());
+    }
+    if let Some(val) = filter.mentions_roles {
+        let desc = if val {
+            "Message must mention a role."
+        } else {
+            "Message must not mention any roles."
+        };
+        message_context.push(desc.to_string());
+    }
+    if let Some(val) = filter.has_sticker {
+        let desc = if val {
+            "Message must have a sticker."
+        } else {
+            "Message must not have a sticker."
+        };
+        message_context.push(desc.to_string());
+    }
+    if let Some(val) = filter.attachment_types {
+        let desc = format!(
+            "Message must have an attachment of one of these types:\n{}",
+            format_list(&val)
+        );
+        message_context.push(desc);
+    }
+    if let Some(val) = filter.attachment_extensions {
+        let desc = format!(
+            "Message must have an attachment with one of these file extensions:\n{}",
+            format_list(&val)
+        );
+        message_context.push(desc);
+    }
+    if let Some(val) = filter.is_reply {
+        let desc = if val {
+            "Message must be a reply."
+        } else {
+            "Message must not be a reply."
+        };
+        message_context.push(desc.to_string());
+    }
     if let Some(val) = filter.matches {
         let mut desc = format!("Message must match the following regex:\n```re\n{val}\n```");
         if !premium {
@@ -277,6 +455,20 @@ fn filter_embed(filter: Filter, premium: bool) -> Embed {
         );
         vote_context.push(desc);
     }
+    if let Some(val) = filter.voter_account_older_than {
+        let desc = format!(
+            "Voter's account must be older than {}.",
+            humantime::format_duration(Duration::from_secs(val as u64)),
+        );
+        vote_context.push(desc);
+    }
+    if let Some(val) = filter.voter_joined_older_than {
+        let desc = format!(
+            "Voter must have joined the server more than {} ago.",
+            humantime::format_duration(Duration::from_secs(val as u64)),
+        );
+        vote_context.push(desc);
+    }
 
     let mut desc = String::new();
     desc.push_str(&format!(
@@ -288,6 +480,18 @@ fn filter_embed(filter: Filter, premium: bool) -> Embed {
 
     let mut has_conditions = false;
 
+    if !sub_groups.is_empty() {
+        has_conditions = true;
+        let mut value = sub_groups.join("\n\n");
+        if value.chars().count() > constants::MAX_FILTER_TREE_LENGTH {
+            value = value
+                .chars()
+                .take(constants::MAX_FILTER_TREE_LENGTH)
+                .collect::<String>();
+            value.push('…');
+        }
+        emb = emb.field(EmbedFieldBuilder::new("Filter Groups", value));
+    }
     if !default_context.is_empty() {
         has_conditions = true;
         emb = emb.field(EmbedFieldBuilder::new(
@@ -1,12 +1,15 @@
-use futures::TryStreamExt;
+use chrono::{Duration, Utc};
+use futures::{StreamExt, TryStreamExt};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
-    database::DbMember,
+    database::{DbGuild, DbMember, SeasonStanding, Starboard, Vote},
     errors::StarboardResult,
     get_guild_id,
-    interactions::context::CommandCtx,
-    utils::{embed, id_as_i64::GetI64, into_id::IntoId, views::paginator},
+    interactions::{commands::choices::period::Period, context::CommandCtx},
+    utils::{
+        embed, format_points::format_points, id_as_i64::GetI64, into_id::IntoId, views::paginator,
+    },
 };
 
 #[derive(CommandModel, CreateCommand)]
@@ -19,50 +22,142 @@ pub struct Leaderboard {
     /// Whether to include users who've left. False by default.
     #[command(rename = "include-gone")]
     include_gone: Option<bool>,
+    /// Only count points received during this period.
+    period: Option<Period>,
+    /// Only count points received on this starboard.
+    #[command(autocomplete = true)]
+    starboard: Option<String>,
+    /// Show the XP standings for this season.
+    #[command(min_value = 1)]
+    season: Option<i64>,
 }
 
 impl Leaderboard {
     pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
         let guild_id = get_guild_id!(ctx);
         let guild_id_i64 = guild_id.get_i64();
+        let bot = ctx.bot.clone();
 
         let include_gone = self.include_gone == Some(true);
+        let use_xp = self.period.is_none() && self.starboard.is_none();
 
-        let lb = if include_gone {
-            DbMember::list_by_xp(&ctx.bot.pool, guild_id_i64, 99).await?
+        let season = match self.season {
+            None => None,
+            Some(_) if !use_xp => {
+                ctx.respond_str(
+                    "The `season` option can't be combined with `period` or `starboard`.",
+                    true,
+                )
+                .await?;
+                return Ok(());
+            }
+            Some(season) => {
+                let current = DbGuild::get(&bot.pool, guild_id_i64)
+                    .await?
+                    .map_or(1, |g| g.season);
+                if season > current as i64 {
+                    ctx.respond_str(&format!("Season {season} hasn't started yet."), true)
+                        .await?;
+                    return Ok(());
+                }
+
+                Some((season as i32, season as i32 == current))
+            }
+        };
+
+        let (starboard_ids, starboard_name) = if let Some(name) = &self.starboard {
+            let Some(sb) = Starboard::get_by_name(&bot.pool, name, guild_id_i64).await? else {
+                ctx.respond_str(&format!("Starboard '{name}' does not exist."), true)
+                    .await?;
+                return Ok(());
+            };
+
+            if sb.settings.private {
+                ctx.respond_str("That starboard is private.", true).await?;
+                return Ok(());
+            }
+
+            (vec![sb.id], sb.name)
+        } else if use_xp {
+            (Vec::new(), String::new())
         } else {
-            ctx.defer(false).await?;
+            let starboard_ids = Starboard::list_by_guild(&bot.pool, guild_id_i64)
+                .await?
+                .into_iter()
+                .filter(|sb| !sb.settings.private)
+                .map(|sb| sb.id)
+                .collect();
+
+            (starboard_ids, "All Starboards".to_string())
+        };
 
-            let mut lb = Vec::new();
-            let mut stream = DbMember::stream_by_xp(&ctx.bot.pool, guild_id_i64);
+        let period = self.period.unwrap_or(Period::All);
+        let mut stream = if let Some((season, is_current)) = season {
+            if is_current {
+                DbMember::stream_by_season_xp(&bot.pool, guild_id_i64)
+                    .map_ok(|member| (member.user_id, member.season_xp))
+                    .boxed()
+            } else {
+                SeasonStanding::stream_by_xp(&bot.pool, guild_id_i64, season)
+                    .map_ok(|standing| (standing.user_id, standing.xp))
+                    .boxed()
+            }
+        } else if use_xp {
+            DbMember::stream_by_xp(&bot.pool, guild_id_i64)
+                .map_ok(|member| (member.user_id, member.xp))
+                .boxed()
+        } else {
+            let since = period
+                .as_secs()
+                .map(|secs| Utc::now() - Duration::seconds(secs));
 
-            while let Some(member) = stream.try_next().await? {
-                let obj = ctx
-                    .bot
+            Vote::stream_received_points(&bot.pool, &starboard_ids, since)
+                .map_ok(|row| (row.user_id, row.points))
+                .boxed()
+        };
+
+        if !include_gone {
+            ctx.defer(false).await?;
+        }
+
+        let mut lb = Vec::new();
+        while let Some((user_id, value)) = stream.try_next().await? {
+            if !include_gone {
+                let obj = bot
                     .cache
-                    .fog_member(&ctx.bot, guild_id, member.user_id.into_id())
+                    .fog_member(&bot, guild_id, user_id.into_id())
                     .await?;
                 if obj.is_none() {
                     continue;
                 }
+            }
 
-                lb.push(member);
+            lb.push((user_id, value));
 
-                if lb.len() >= 99 {
-                    break;
-                }
+            if lb.len() >= 99 {
+                break;
             }
+        }
 
-            lb
+        let unit = if use_xp { "XP" } else { "points" };
+        let mut title = if let Some((season, _)) = season {
+            format!("Leaderboard - Season {season}")
+        } else if use_xp {
+            "Leaderboard".to_string()
+        } else {
+            format!("Leaderboard - {starboard_name} ({})", period.title())
         };
+        if include_gone {
+            title.push_str(" (Including Gone)");
+        }
 
         let mut idx = 0;
         let pages = lb.chunks(9).map(|chunk| {
             chunk
                 .iter()
-                .map(|DbMember { user_id, xp, .. }| {
+                .map(|(user_id, value)| {
                     idx += 1;
-                    format!("`#{idx}` <@{user_id}> - {xp} XP\n")
+                    format!("`#{idx}` <@{user_id}> - {} {unit}\n", format_points(*value))
                 })
                 .collect::<String>()
         });
@@ -70,14 +165,7 @@ impl Leaderboard {
             .map(|p| {
                 (
                     None,
-                    Some(vec![embed::build()
-                        .title(if include_gone {
-                            "Leaderboard (Including Gone)"
-                        } else {
-                            "Leaderboard"
-                        })
-                        .description(p)
-                        .build()]),
+                    Some(vec![embed::build().title(&title).description(p).build()]),
                 )
             })
             .collect();
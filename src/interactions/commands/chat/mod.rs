@@ -12,6 +12,7 @@ pub mod posroles;
 pub mod premium;
 pub mod premium_locks;
 pub mod random;
+pub mod seasons;
 pub mod starboard;
 pub mod stats;
 pub mod utils;
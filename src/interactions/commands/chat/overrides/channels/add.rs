@@ -1,6 +1,7 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
+    core::audit::{self, AuditAction, AuditTarget},
     database::{validation::mentions::textable_channel_ids, StarboardOverride},
     errors::StarboardResult,
     get_guild_id,
@@ -26,22 +27,33 @@ impl AddOverrideChannels {
         let ov = StarboardOverride::get(&ctx.bot.pool, guild_id_i64, &self.name).await?;
         if let Some(ov) = ov {
             let mut channel_ids = textable_channel_ids(&ctx.bot, guild_id, &self.channels).await?;
-            channel_ids.extend(ov.channel_ids);
+            channel_ids.extend(ov.channel_ids.iter().copied());
             let new_channels: Vec<_> = channel_ids.into_iter().collect();
 
             if let Err(why) = StarboardOverride::validate_channels(&new_channels) {
                 ctx.respond_str(&why, true).await?;
                 return Ok(());
             }
-            let ret = StarboardOverride::set_channels(
-                &ctx.bot.pool,
-                guild_id_i64,
-                &self.name,
-                &new_channels,
-            )
-            .await?;
+            let mut tx = ctx.bot.pool.begin().await?;
+            let ret =
+                StarboardOverride::set_channels(&mut tx, guild_id_i64, &self.name, &new_channels)
+                    .await?;
 
-            if ret.is_some() {
+            if let Some(new) = ret {
+                audit::log(
+                    &mut tx,
+                    &ctx,
+                    AuditAction::Edit,
+                    AuditTarget::Override,
+                    new.id.into(),
+                    &new.name,
+                    audit::diff(
+                        &serde_json::json!({"channel_ids": ov.channel_ids}),
+                        &serde_json::json!({"channel_ids": new.channel_ids}),
+                    ),
+                )
+                .await?;
+                tx.commit().await?;
                 ctx.respond_str(
                     &format!("Updated the channels for override '{}'.", self.name),
                     false,
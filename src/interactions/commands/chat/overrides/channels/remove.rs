@@ -1,6 +1,7 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
+    core::audit::{self, AuditAction, AuditTarget},
     database::{validation::mentions::textable_channel_ids, StarboardOverride},
     errors::StarboardResult,
     get_guild_id,
@@ -33,15 +34,26 @@ impl RemoveOverrideChannels {
                 .filter(|id| !to_remove.contains(id))
                 .collect();
 
-            let ret = StarboardOverride::set_channels(
-                &ctx.bot.pool,
-                guild_id_i64,
-                &self.name,
-                &channel_ids,
-            )
-            .await?;
+            let mut tx = ctx.bot.pool.begin().await?;
+            let ret =
+                StarboardOverride::set_channels(&mut tx, guild_id_i64, &self.name, &channel_ids)
+                    .await?;
 
-            if ret.is_some() {
+            if let Some(new) = ret {
+                audit::log(
+                    &mut tx,
+                    &ctx,
+                    AuditAction::Edit,
+                    AuditTarget::Override,
+                    new.id.into(),
+                    &new.name,
+                    audit::diff(
+                        &serde_json::json!({"channel_ids": ov.channel_ids}),
+                        &serde_json::json!({"channel_ids": new.channel_ids}),
+                    ),
+                )
+                .await?;
+                tx.commit().await?;
                 ctx.respond_str(
                     &format!("Updated the channels for override '{}'.", self.name),
                     false,
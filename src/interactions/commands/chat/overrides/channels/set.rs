@@ -1,6 +1,7 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
+    core::audit::{self, AuditAction, AuditTarget},
     database::{validation::mentions::textable_channel_ids, StarboardOverride},
     errors::StarboardResult,
     get_guild_id,
@@ -32,22 +33,33 @@ impl SetOverrideChannels {
             ctx.respond_str(&why, true).await?;
             return Ok(());
         }
-        let ov =
-            StarboardOverride::set_channels(&ctx.bot.pool, guild_id_i64, &self.name, &channel_ids)
-                .await?;
+        let mut tx = ctx.bot.pool.begin().await?;
+        let ov = StarboardOverride::set_channels(&mut tx, guild_id_i64, &self.name, &channel_ids)
+            .await?;
 
-        if ov.is_none() {
-            ctx.respond_str(
-                &format!("No override with the name '{}' exists.", self.name),
-                true,
+        if let Some(ov) = ov {
+            audit::log(
+                &mut tx,
+                &ctx,
+                AuditAction::Edit,
+                AuditTarget::Override,
+                ov.id.into(),
+                &ov.name,
+                serde_json::json!({"channel_ids": {"new": ov.channel_ids}}),
             )
             .await?;
-        } else {
+            tx.commit().await?;
             ctx.respond_str(
                 &format!("Set the channels for override '{}'.", self.name),
                 false,
             )
             .await?;
+        } else {
+            ctx.respond_str(
+                &format!("No override with the name '{}' exists.", self.name),
+                true,
+            )
+            .await?;
         }
         Ok(())
     }
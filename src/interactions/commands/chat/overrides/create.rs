@@ -2,6 +2,7 @@ use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
     constants,
+    core::audit::{self, AuditAction, AuditTarget},
     database::{validation, Starboard, StarboardOverride},
     errors::StarboardResult,
     get_guild_id,
@@ -54,15 +55,21 @@ impl CreateOverride {
             return Ok(());
         }
 
-        let ov = StarboardOverride::create(&ctx.bot.pool, guild_id, &name, starboard.id).await?;
+        let mut tx = ctx.bot.pool.begin().await?;
+        let ov = StarboardOverride::create(&mut tx, guild_id, &name, starboard.id).await?;
 
-        if ov.is_none() {
-            ctx.respond_str(
-                &format!("An override with the name '{name}' already exists."),
-                true,
+        if let Some(ov) = ov {
+            audit::log(
+                &mut tx,
+                &ctx,
+                AuditAction::Create,
+                AuditTarget::Override,
+                ov.id.into(),
+                &ov.name,
+                serde_json::json!({"starboard": {"new": starboard.name}}),
             )
             .await?;
-        } else {
+            tx.commit().await?;
             ctx.respond_str(
                 &format!(
                     "Created override '{}' in starboard '{}'.",
@@ -71,6 +78,12 @@ impl CreateOverride {
                 false,
             )
             .await?;
+        } else {
+            ctx.respond_str(
+                &format!("An override with the name '{name}' already exists."),
+                true,
+            )
+            .await?;
         }
 
         Ok(())
@@ -1,6 +1,7 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
+    core::audit::{self, AuditAction, AuditTarget},
     database::StarboardOverride,
     errors::StarboardResult,
     get_guild_id,
@@ -34,18 +35,30 @@ impl DeleteOverride {
             Some(btn_ctx) => btn_ctx,
         };
 
-        let ov = StarboardOverride::delete(&ctx.bot.pool, guild_id, &self.name).await?;
-        if ov.is_none() {
+        let mut tx = ctx.bot.pool.begin().await?;
+        let ov = StarboardOverride::delete(&mut tx, guild_id, &self.name).await?;
+        if let Some(ov) = ov {
+            audit::log(
+                &mut tx,
+                &ctx,
+                AuditAction::Delete,
+                AuditTarget::Override,
+                ov.id.into(),
+                &ov.name,
+                serde_json::json!({}),
+            )
+            .await?;
+            tx.commit().await?;
+            btn_ctx
+                .edit_str(&format!("Deleted override '{}'.", self.name), true)
+                .await?;
+        } else {
             btn_ctx
                 .edit_str(
                     &format!("No override with the name '{}' exists.", self.name),
                     true,
                 )
                 .await?;
-        } else {
-            btn_ctx
-                .edit_str(&format!("Deleted override '{}'.", self.name), true)
-                .await?;
         }
 
         Ok(())
@@ -1,6 +1,7 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
+    core::audit::{self, AuditAction, AuditTarget},
     database::{validation::cooldown::parse_cooldown, ExclusiveGroup, StarboardOverride},
     errors::StarboardResult,
     get_guild_id,
@@ -23,6 +24,9 @@ pub struct EditBehavior {
     /// Whether to automatically react to starboard messages with the downvote emojis.
     #[command(rename = "autoreact-downvote")]
     autoreact_downvote: Option<bool>,
+    /// Whether to use Upvote/Downvote buttons on starboard messages instead of reactions.
+    #[command(rename = "vote-buttons")]
+    vote_buttons: Option<bool>,
     /// Whether to remove reactions that don't meet requirements.
     #[command(rename = "remove-invalid-reactions")]
     remove_invalid_reactions: Option<bool>,
@@ -63,6 +67,7 @@ impl EditBehavior {
             Some(ov) => ov,
         };
         let mut settings = ov.get_overrides()?;
+        let old_settings = settings.clone();
 
         if let Some(val) = self.enabled {
             settings.enabled = Some(val);
@@ -73,6 +78,9 @@ impl EditBehavior {
         if let Some(val) = self.autoreact_downvote {
             settings.autoreact_downvote = Some(val);
         }
+        if let Some(val) = self.vote_buttons {
+            settings.vote_buttons = Some(val);
+        }
         if let Some(val) = self.remove_invalid_reactions {
             settings.remove_invalid_reactions = Some(val);
         }
@@ -119,7 +127,20 @@ impl EditBehavior {
             settings.exclusive_group_priority = Some(val as i16);
         }
 
-        StarboardOverride::update_settings(&ctx.bot.pool, ov.id, settings).await?;
+        let changes = audit::diff(&old_settings, &settings);
+        let mut tx = ctx.bot.pool.begin().await?;
+        StarboardOverride::update_settings(&mut tx, ov.id, settings).await?;
+        audit::log(
+            &mut tx,
+            &ctx,
+            AuditAction::Edit,
+            AuditTarget::Override,
+            ov.id.into(),
+            &ov.name,
+            changes,
+        )
+        .await?;
+        tx.commit().await?;
         ctx.respond_str(
             &format!("Updated settings for override '{}'.", self.name),
             false,
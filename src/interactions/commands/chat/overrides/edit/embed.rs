@@ -1,6 +1,7 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
+    core::audit::{self, AuditAction, AuditTarget},
     database::{validation::color, StarboardOverride},
     errors::StarboardResult,
     get_guild_id,
@@ -42,6 +43,7 @@ impl EditEmbedStyle {
             Some(ov) => ov,
         };
         let mut settings = ov.get_overrides()?;
+        let old_settings = settings.clone();
 
         if let Some(val) = self.color {
             if val == "none" {
@@ -63,7 +65,20 @@ impl EditEmbedStyle {
             settings.replied_to = Some(val);
         }
 
-        StarboardOverride::update_settings(&ctx.bot.pool, ov.id, settings).await?;
+        let changes = audit::diff(&old_settings, &settings);
+        let mut tx = ctx.bot.pool.begin().await?;
+        StarboardOverride::update_settings(&mut tx, ov.id, settings).await?;
+        audit::log(
+            &mut tx,
+            &ctx,
+            AuditAction::Edit,
+            AuditTarget::Override,
+            ov.id.into(),
+            &ov.name,
+            changes,
+        )
+        .await?;
+        tx.commit().await?;
         ctx.respond_str(
             &format!("Updated settings for override '{}'.", self.name),
             false,
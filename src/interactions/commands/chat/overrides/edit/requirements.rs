@@ -2,6 +2,7 @@ use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
     core::{
+        audit::{self, AuditAction, AuditTarget},
         emoji::{EmojiCommon, SimpleEmoji},
         premium::is_premium::is_guild_premium,
         starboard::config::StarboardConfig,
@@ -9,6 +10,7 @@ use crate::{
     database::{
         validation::{
             self,
+            emoji_points::{parse_emoji_points, prune_emoji_points},
             starboard_settings::{validate_required, validate_required_remove},
             time_delta::{parse_time_delta, validate_relative_duration},
         },
@@ -41,6 +43,9 @@ pub struct EditRequirements {
     /// The emojis that can be used to downvote a post. Use 'none' to remove all.
     #[command(rename = "downvote-emojis")]
     downvote_emojis: Option<String>,
+    /// How many points each vote emoji is worth (e.g. "⭐=1 🌟=5"). Use 'none' to reset all to 1.
+    #[command(rename = "emoji-points")]
+    emoji_points: Option<String>,
     /// Whether to allow users to vote on their own posts.
     #[command(rename = "self-vote")]
     self_vote: Option<bool>,
@@ -84,6 +89,7 @@ impl EditRequirements {
             (resolved.overrides.remove(0), resolved.resolved)
         };
         let mut settings = ov.get_overrides()?;
+        let old_settings = settings.clone();
 
         let is_prem = is_guild_premium(&ctx.bot, guild_id_i64, true).await?;
 
@@ -108,6 +114,7 @@ impl EditRequirements {
             settings.required_remove = Some(val);
         }
 
+        let emojis_changed = self.upvote_emojis.is_some() || self.downvote_emojis.is_some();
         if let Some(val) = self.upvote_emojis {
             let emojis = SimpleEmoji::from_user_input(&val, &ctx.bot, guild_id).into_stored();
             settings.upvote_emojis = Some(emojis);
@@ -122,6 +129,33 @@ impl EditRequirements {
             // delete cached value
             ctx.bot.cache.guild_vote_emojis.remove(&guild_id_i64);
         }
+        let vote_emojis: Vec<_> = settings
+            .upvote_emojis
+            .as_ref()
+            .unwrap_or(&resolved.upvote_emojis)
+            .iter()
+            .chain(
+                settings
+                    .downvote_emojis
+                    .as_ref()
+                    .unwrap_or(&resolved.downvote_emojis),
+            )
+            .cloned()
+            .collect();
+        if emojis_changed {
+            if let Some(emoji_points) = &mut settings.emoji_points {
+                prune_emoji_points(emoji_points, &vote_emojis);
+            }
+        }
+        if let Some(val) = self.emoji_points {
+            match parse_emoji_points(&val, &ctx.bot, guild_id, &vote_emojis) {
+                Err(why) => {
+                    ctx.respond_str(&why, true).await?;
+                    return Ok(());
+                }
+                Ok(val) => settings.emoji_points = Some(val),
+            }
+        }
         if let Err(why) = validation::starboard_settings::validate_vote_emojis(
             settings
                 .upvote_emojis
@@ -194,7 +228,20 @@ impl EditRequirements {
             }
         }
 
-        StarboardOverride::update_settings(&ctx.bot.pool, ov.id, settings).await?;
+        let changes = audit::diff(&old_settings, &settings);
+        let mut tx = ctx.bot.pool.begin().await?;
+        StarboardOverride::update_settings(&mut tx, ov.id, settings).await?;
+        audit::log(
+            &mut tx,
+            &ctx,
+            AuditAction::Edit,
+            AuditTarget::Override,
+            ov.id.into(),
+            &ov.name,
+            changes,
+        )
+        .await?;
+        tx.commit().await?;
         ctx.respond_str(
             &format!("Updated settings for override '{}'.", self.name),
             false,
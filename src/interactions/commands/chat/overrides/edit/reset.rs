@@ -3,6 +3,7 @@ use std::collections::HashSet;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
+    core::audit::{self, AuditAction, AuditTarget},
     database::{helpers::settings::overrides::call_with_override_settings, StarboardOverride},
     errors::StarboardResult,
     get_guild_id,
@@ -48,6 +49,7 @@ impl ResetOverrideSettings {
             Some(ov) => ov,
         };
         let mut settings = ov.get_overrides()?;
+        let old_settings = settings.clone();
 
         let reset = self.reset.replace(',', " ").replace('-', "_");
         let mut reset: HashSet<_> = reset.split(' ').collect();
@@ -64,7 +66,20 @@ impl ResetOverrideSettings {
 
         call_with_override_settings!(reset_settings, settings, reset);
 
-        StarboardOverride::update_settings(&ctx.bot.pool, ov.id, settings).await?;
+        let changes = audit::diff(&old_settings, &settings);
+        let mut tx = ctx.bot.pool.begin().await?;
+        StarboardOverride::update_settings(&mut tx, ov.id, settings).await?;
+        audit::log(
+            &mut tx,
+            &ctx,
+            AuditAction::Edit,
+            AuditTarget::Override,
+            ov.id.into(),
+            &ov.name,
+            changes,
+        )
+        .await?;
+        tx.commit().await?;
         ctx.respond_str(
             &format!(
                 "Reset {} setting(s) for override '{}'.",
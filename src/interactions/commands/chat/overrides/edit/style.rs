@@ -2,6 +2,7 @@ use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
     core::{
+        audit::{self, AuditAction, AuditTarget},
         emoji::{EmojiCommon, SimpleEmoji},
         starboard::webhooks::create_webhook,
     },
@@ -53,6 +54,7 @@ impl EditGeneralStyle {
             Some(ov) => ov,
         };
         let mut settings = ov.get_overrides()?;
+        let old_settings = settings.clone();
 
         if let Some(val) = self.display_emoji {
             let emoji = if val == "none" {
@@ -99,7 +101,20 @@ impl EditGeneralStyle {
             message = None;
         }
 
-        StarboardOverride::update_settings(&ctx.bot.pool, ov.id, settings).await?;
+        let changes = audit::diff(&old_settings, &settings);
+        let mut tx = ctx.bot.pool.begin().await?;
+        StarboardOverride::update_settings(&mut tx, ov.id, settings).await?;
+        audit::log(
+            &mut tx,
+            &ctx,
+            AuditAction::Edit,
+            AuditTarget::Override,
+            ov.id.into(),
+            &ov.name,
+            changes,
+        )
+        .await?;
+        tx.commit().await?;
 
         let mut response = format!("Updated settings for override '{}'.", self.name);
         if let Some(message) = message {
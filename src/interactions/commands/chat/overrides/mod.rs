@@ -3,6 +3,7 @@ mod create;
 mod delete;
 mod edit;
 mod rename;
+mod resolve;
 mod view;
 
 use twilight_interactions::command::{CommandModel, CreateCommand};
@@ -32,6 +33,8 @@ pub enum Overrides {
     Edit(edit::EditOverride),
     #[command(name = "view")]
     View(view::ViewOverride),
+    #[command(name = "resolve")]
+    Resolve(resolve::ResolveOverrides),
 }
 
 impl Overrides {
@@ -43,6 +46,7 @@ impl Overrides {
             Self::Channels(cmd) => cmd.callback(ctx).await,
             Self::Edit(cmd) => cmd.callback(ctx).await,
             Self::View(cmd) => cmd.callback(ctx).await,
+            Self::Resolve(cmd) => cmd.callback(ctx).await,
         }
     }
 }
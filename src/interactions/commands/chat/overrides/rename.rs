@@ -1,6 +1,7 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
+    core::audit::{self, AuditAction, AuditTarget},
     database::{validation, StarboardOverride},
     errors::StarboardResult,
     get_guild_id,
@@ -31,8 +32,8 @@ impl RenameOverride {
             }
         };
 
-        let ov =
-            StarboardOverride::rename(&ctx.bot.pool, guild_id, &self.old_name, &self.name).await;
+        let mut tx = ctx.bot.pool.begin().await?;
+        let ov = StarboardOverride::rename(&mut tx, guild_id, &self.old_name, &self.name).await;
 
         match ov {
             Err(why) => {
@@ -53,7 +54,18 @@ impl RenameOverride {
                 )
                 .await?;
             }
-            Ok(Some(_)) => {
+            Ok(Some(ov)) => {
+                audit::log(
+                    &mut tx,
+                    &ctx,
+                    AuditAction::Rename,
+                    AuditTarget::Override,
+                    ov.id.into(),
+                    &ov.name,
+                    audit::rename(&self.old_name, &ov.name),
+                )
+                .await?;
+                tx.commit().await?;
                 ctx.respond_str(
                     &format!("Renamed override '{}' to '{}'.", self.old_name, name),
                     false,
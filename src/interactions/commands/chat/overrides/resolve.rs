@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::application::interaction::application_command::InteractionChannel;
+use twilight_util::builder::embed::EmbedFieldBuilder;
+
+use crate::{
+    core::starboard::config::StarboardConfig,
+    database::{Starboard, StarboardOverride},
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::{commands::format_settings::format_settings_with_sources, context::CommandCtx},
+    utils::{embed, id_as_i64::GetI64},
+};
+
+#[derive(CreateCommand, CommandModel)]
+#[command(
+    name = "resolve",
+    desc = "See which settings a starboard uses in a channel, and which overrides they come from."
+)]
+pub struct ResolveOverrides {
+    /// The starboard to show the settings of.
+    #[command(autocomplete = true)]
+    starboard: String,
+    /// The channel to show the settings for.
+    channel: InteractionChannel,
+}
+
+impl ResolveOverrides {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx);
+        let guild_id_i64 = guild_id.get_i64();
+        let bot = ctx.bot.clone();
+
+        let starboard = Starboard::get_by_name(&bot.pool, &self.starboard, guild_id_i64).await?;
+        let Some(starboard) = starboard else {
+            ctx.respond_str(&format!("'{}' is not a starboard.", self.starboard), true)
+                .await?;
+            return Ok(());
+        };
+
+        // the channel, followed by its parent channels and category
+        let channel_ids: Vec<i64> = bot
+            .cache
+            .qualified_channel_ids(&bot, guild_id, self.channel.id)
+            .await?
+            .into_iter()
+            .map(|id| id.get_i64())
+            .collect();
+        let overrides = StarboardOverride::list_by_starboard_and_channels(
+            &bot.pool,
+            starboard.id,
+            &channel_ids,
+        )
+        .await?;
+        let config = StarboardConfig::new(starboard, &channel_ids, overrides)?;
+
+        // overrides are applied from least to most specific, but are
+        // numbered from most to least specific
+        let count = config.overrides.len();
+        let label = |idx: usize| (count - idx).to_string();
+        let sources = config.setting_sources();
+        let labels: HashMap<_, _> = sources
+            .iter()
+            .map(|(setting, idx)| (setting.clone(), label(*idx)))
+            .collect();
+
+        let mut description = format!(
+            concat!(
+                "These are the settings that the starboard '{}' uses for messages in <#{}>.\n\n",
+                "Overrides are checked for these channels, from most to least specific: {}\n\n",
+            ),
+            config.starboard.name,
+            self.channel.id,
+            channel_ids
+                .iter()
+                .map(|id| format!("<#{id}>"))
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+        if count == 0 {
+            description
+                .push_str("No overrides apply here, so every setting comes from the starboard.");
+        } else {
+            description.push_str(concat!(
+                "Settings marked with a number come from that override, and all others come from ",
+                "the starboard. Overrides for more specific channels take priority:\n",
+            ));
+        }
+        for (idx, ov) in config.overrides.iter().enumerate().rev() {
+            let matched = channel_ids
+                .iter()
+                .find(|id| ov.channel_ids.contains(id))
+                .copied()
+                .unwrap_or_default();
+            description.push_str(&format!(
+                "\n`[{}]` '{}', which applies to <#{matched}>",
+                label(idx),
+                ov.name
+            ));
+
+            let shadowed: Vec<_> = ov
+                .overrides
+                .as_object()
+                .into_iter()
+                .flat_map(|o| o.keys())
+                .filter(|key| sources.get(*key) != Some(&idx))
+                .map(|key| format!("`{}`", key.replace('_', "-")))
+                .collect();
+            if !shadowed.is_empty() {
+                description.push_str(&format!(
+                    " ({} replaced by a higher priority override)",
+                    shadowed.join(", ")
+                ));
+            }
+        }
+
+        let pretty = format_settings_with_sources(&bot, guild_id, &config, Some(&labels)).await?;
+        let emb = embed::build()
+            .title(format!("Resolved Settings for '{}'", config.starboard.name))
+            .description(description)
+            .field(
+                EmbedFieldBuilder::new("Requirements", pretty.requirements)
+                    .inline()
+                    .build(),
+            )
+            .field(
+                EmbedFieldBuilder::new("Behaviour", pretty.behavior)
+                    .inline()
+                    .build(),
+            )
+            .field(
+                EmbedFieldBuilder::new("Style", pretty.style)
+                    .inline()
+                    .build(),
+            )
+            .field(
+                EmbedFieldBuilder::new("Embed Style", pretty.embed)
+                    .inline()
+                    .build(),
+            )
+            .field(EmbedFieldBuilder::new("Regex Matching", pretty.regex).build())
+            .build();
+
+        ctx.respond(ctx.build_resp().embeds([emb]).build()).await?;
+
+        Ok(())
+    }
+}
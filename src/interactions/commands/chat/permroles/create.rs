@@ -3,8 +3,13 @@ use twilight_mention::Mention;
 use twilight_model::guild::Role;
 
 use crate::{
-    constants, database::PermRole, errors::StarboardResult, get_guild_id,
-    interactions::context::CommandCtx, utils::id_as_i64::GetI64,
+    constants,
+    core::audit::{self, AuditAction, AuditTarget},
+    database::PermRole,
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::context::CommandCtx,
+    utils::id_as_i64::GetI64,
 };
 
 #[derive(CommandModel, CreateCommand)]
@@ -32,16 +37,28 @@ impl CreatePermRole {
             return Ok(());
         }
 
-        let pr = PermRole::create(&ctx.bot.pool, self.role.id.get_i64(), guild_id_i64).await?;
+        let mut tx = ctx.bot.pool.begin().await?;
+        let pr = PermRole::create(&mut tx, self.role.id.get_i64(), guild_id_i64).await?;
 
-        if pr.is_none() {
-            ctx.respond_str("That is already a PermRole.", true).await?;
-        } else {
+        if let Some(pr) = pr {
+            audit::log(
+                &mut tx,
+                &ctx,
+                AuditAction::Create,
+                AuditTarget::PermRole,
+                pr.role_id,
+                &self.role.name,
+                serde_json::json!({}),
+            )
+            .await?;
+            tx.commit().await?;
             ctx.respond_str(
                 &format!("{} is now a PermRole.", self.role.mention()),
                 false,
             )
             .await?;
+        } else {
+            ctx.respond_str("That is already a PermRole.", true).await?;
         }
 
         Ok(())
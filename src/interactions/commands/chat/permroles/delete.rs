@@ -4,6 +4,7 @@ use twilight_model::guild::Role;
 
 use crate::{
     concat_format,
+    core::audit::{self, AuditAction, AuditTarget},
     database::PermRole,
     errors::StarboardResult,
     get_guild_id,
@@ -20,12 +21,24 @@ pub struct DeletePermRole {
 
 impl DeletePermRole {
     pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
-        let pr = PermRole::delete(&ctx.bot.pool, self.role.id.get_i64()).await?;
-        if pr.is_none() {
-            ctx.respond_str(&format!("{} is not a PermRole.", self.role.mention()), true)
+        let mut tx = ctx.bot.pool.begin().await?;
+        let pr = PermRole::delete(&mut tx, self.role.id.get_i64()).await?;
+        if let Some(pr) = pr {
+            audit::log(
+                &mut tx,
+                &ctx,
+                AuditAction::Delete,
+                AuditTarget::PermRole,
+                pr.role_id,
+                &self.role.name,
+                serde_json::json!({}),
+            )
+            .await?;
+            tx.commit().await?;
+            ctx.respond_str(&format!("Deleted PermRole {}.", self.role.mention()), false)
                 .await?;
         } else {
-            ctx.respond_str(&format!("Deleted PermRole {}.", self.role.mention()), false)
+            ctx.respond_str(&format!("{} is not a PermRole.", self.role.mention()), true)
                 .await?;
         }
 
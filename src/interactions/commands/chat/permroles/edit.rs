@@ -3,7 +3,8 @@ use twilight_mention::Mention;
 use twilight_model::guild::Role;
 
 use crate::{
-    database::PermRole,
+    core::audit::{self, AuditAction, AuditTarget},
+    database::{validation::vote_weight::validate_vote_weight, PermRole},
     errors::StarboardResult,
     interactions::{commands::choices::tribool::Tribool, context::CommandCtx},
     utils::id_as_i64::GetI64,
@@ -22,6 +23,9 @@ pub struct EditPermRole {
     receive_votes: Option<Tribool>,
     /// Whether a user with this role can gain XPRoles.
     xproles: Option<Tribool>,
+    /// How many points a vote from a user with this role is worth (e.g. 0.5). Use "none" to unset.
+    #[command(rename = "vote-weight")]
+    vote_weight: Option<String>,
 }
 
 impl EditPermRole {
@@ -35,6 +39,7 @@ impl EditPermRole {
             }
             Some(pr) => pr,
         };
+        let old = pr.clone();
 
         if let Some(val) = self.vote {
             pr.give_votes = val.as_bool();
@@ -45,8 +50,29 @@ impl EditPermRole {
         if let Some(val) = self.xproles {
             pr.obtain_xproles = val.as_bool();
         }
+        if let Some(val) = self.vote_weight {
+            match validate_vote_weight(val) {
+                Ok(val) => pr.vote_weight = val,
+                Err(why) => {
+                    ctx.respond_str(&why, true).await?;
+                    return Ok(());
+                }
+            }
+        }
 
-        pr.update(&ctx.bot.pool).await?;
+        let mut tx = ctx.bot.pool.begin().await?;
+        pr.update(&mut tx).await?;
+        audit::log(
+            &mut tx,
+            &ctx,
+            AuditAction::Edit,
+            AuditTarget::PermRole,
+            pr.role_id,
+            &self.role.name,
+            audit::diff(&old, &pr),
+        )
+        .await?;
+        tx.commit().await?;
 
         ctx.respond_str(
             &format!("Updated settings for {}", self.role.mention()),
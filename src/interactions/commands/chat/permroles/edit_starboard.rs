@@ -3,7 +3,8 @@ use twilight_mention::Mention;
 use twilight_model::guild::Role;
 
 use crate::{
-    database::{PermRoleStarboard, Starboard},
+    core::audit::{self, AuditAction, AuditTarget},
+    database::{validation::vote_weight::validate_vote_weight, PermRoleStarboard, Starboard},
     errors::StarboardResult,
     get_guild_id,
     interactions::{commands::choices::tribool::Tribool, context::CommandCtx},
@@ -27,12 +28,24 @@ pub struct EditPermRoleStarboard {
     /// Whether a user's messages can be voted on.
     #[command(rename = "receive-votes")]
     receive_vote: Option<Tribool>,
+    /// How many points a vote from a user is worth (e.g. 0.5). Use "none" to unset.
+    #[command(rename = "vote-weight")]
+    vote_weight: Option<String>,
 }
 
 impl EditPermRoleStarboard {
     pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
         let guild_id = get_guild_id!(ctx).get_i64();
 
+        let vote_weight = match self.vote_weight.map(validate_vote_weight) {
+            None => None,
+            Some(Ok(val)) => Some(val),
+            Some(Err(why)) => {
+                ctx.respond_str(&why, true).await?;
+                return Ok(());
+            }
+        };
+
         let sb = Starboard::get_by_name(&ctx.bot.pool, &self.starboard, guild_id).await?;
         let sb = match sb {
             None => {
@@ -62,14 +75,30 @@ impl EditPermRoleStarboard {
             }
         };
 
+        let old = pr_sb.clone();
         if let Some(val) = self.vote {
             pr_sb.give_votes = val.as_bool();
         }
         if let Some(val) = self.receive_vote {
             pr_sb.receive_votes = val.as_bool();
         }
+        if let Some(val) = vote_weight {
+            pr_sb.vote_weight = val;
+        }
 
-        pr_sb.update(&ctx.bot.pool).await?;
+        let mut tx = ctx.bot.pool.begin().await?;
+        pr_sb.update(&mut tx).await?;
+        audit::log(
+            &mut tx,
+            &ctx,
+            AuditAction::Edit,
+            AuditTarget::PermRoleStarboard,
+            pr_sb.permrole_id,
+            &sb.name,
+            audit::diff(&old, &pr_sb),
+        )
+        .await?;
+        tx.commit().await?;
         ctx.respond_str(
             &format!(
                 "Updated the settings for {} in '{}'.",
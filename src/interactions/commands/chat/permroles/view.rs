@@ -82,6 +82,7 @@ async fn permrole_embed(bot: &StarboardBot, pr: PermRole) -> StarboardResult<Emb
         "vote: {}\n" <- fmt_trib!(pr.give_votes);
         "receive-votes: {}\n" <- fmt_trib!(pr.receive_votes);
         "xproles: {}\n" <- fmt_trib!(pr.obtain_xproles);
+        "vote-weight: {}\n" <- fmt_trib!(pr.vote_weight);
     ));
 
     let permrole_sbs = PermRoleStarboard::list_by_permrole(&bot.pool, pr.role_id).await?;
@@ -103,6 +104,7 @@ async fn permrole_embed(bot: &StarboardBot, pr: PermRole) -> StarboardResult<Emb
         pr_config.push_str(&concat_format!(
             "vote: {}\n" <- fmt_trib!(pr_sb.give_votes);
             "receive-votes: {}\n" <- fmt_trib!(pr_sb.receive_votes);
+            "vote-weight: {}\n" <- fmt_trib!(pr_sb.vote_weight);
         ));
     }
 
@@ -2,6 +2,7 @@ mod delete;
 mod refresh;
 mod set_max_members;
 mod view;
+mod xp_source;
 
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
@@ -26,6 +27,8 @@ pub enum PosRoles {
     ClearDeleted(delete::ClearDeleted),
     #[command(name = "view")]
     View(view::View),
+    #[command(name = "xp-source")]
+    XPSource(xp_source::SetXPSource),
     #[command(name = "refresh")]
     Refresh(refresh::Refresh),
 }
@@ -37,6 +40,7 @@ impl PosRoles {
             Self::Delete(cmd) => cmd.callback(ctx).await,
             Self::ClearDeleted(cmd) => cmd.callback(ctx).await,
             Self::View(cmd) => cmd.callback(ctx).await,
+            Self::XPSource(cmd) => cmd.callback(ctx).await,
             Self::Refresh(cmd) => cmd.callback(ctx).await,
         }
     }
@@ -0,0 +1,53 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    database::DbGuild,
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::context::CommandCtx,
+    utils::{id_as_i64::GetI64, views::confirm},
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "end",
+    desc = "Archive the current season's standings and reset season XP."
+)]
+pub struct End;
+
+impl End {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx).get_i64();
+
+        DbGuild::create(&ctx.bot.pool, guild_id).await?;
+
+        let Some(mut btn_ctx) = confirm::simple(
+            &mut ctx,
+            concat!(
+                "This will archive the current season's leaderboard and reset everyone's ",
+                "season XP. Lifetime XP will not be affected. Continue?"
+            ),
+            true,
+        ).await? else {
+            return Ok(());
+        };
+
+        let Some(guild) = DbGuild::end_season(&ctx.bot.pool, guild_id).await? else {
+            return Ok(());
+        };
+
+        btn_ctx
+            .edit_str(
+                &format!(
+                    "Season {} has ended and season {} has started. Use `/leaderboard season:{}` to view the final standings.",
+                    guild.season - 1,
+                    guild.season,
+                    guild.season - 1,
+                ),
+                true,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
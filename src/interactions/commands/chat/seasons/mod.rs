@@ -0,0 +1,32 @@
+mod end;
+mod view;
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    errors::StarboardResult,
+    interactions::{commands::permissions::manage_guild, context::CommandCtx},
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "seasons",
+    desc = "View and manage XP seasons.",
+    dm_permission = false,
+    default_permissions = "manage_guild"
+)]
+pub enum Seasons {
+    #[command(name = "end")]
+    End(end::End),
+    #[command(name = "view")]
+    View(view::View),
+}
+
+impl Seasons {
+    pub async fn callback(self, ctx: CommandCtx) -> StarboardResult<()> {
+        match self {
+            Self::End(cmd) => cmd.callback(ctx).await,
+            Self::View(cmd) => cmd.callback(ctx).await,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    database::DbGuild, errors::StarboardResult, get_guild_id, interactions::context::CommandCtx,
+    utils::id_as_i64::GetI64,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "view", desc = "View the current XP season.")]
+pub struct View;
+
+impl View {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx).get_i64();
+
+        let guild = DbGuild::get(&ctx.bot.pool, guild_id).await?;
+        let (season, started_at) = match guild {
+            Some(guild) => (guild.season, guild.season_started_at),
+            None => (1, None),
+        };
+
+        let started = match started_at {
+            Some(started_at) => format!("started <t:{}:R>", started_at.timestamp()),
+            None => "has been running since the beginning".to_string(),
+        };
+
+        ctx.respond_str(&format!("Season {season} {started}."), true)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,237 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    application::interaction::application_command::InteractionChannel,
+    channel::message::{
+        component::{ActionRow, Button, ButtonStyle},
+        Component,
+    },
+    http::interaction::InteractionResponseType,
+};
+
+use crate::{
+    constants,
+    core::starboard::{
+        backfill::{Backfill, BackfillProgress},
+        config::StarboardConfig,
+    },
+    database::Starboard,
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::context::CommandCtx,
+    utils::{
+        id_as_i64::GetI64, into_id::IntoId, message_link::parse_message_bound,
+        progress::report_progress, views::wait_for::wait_for_component,
+    },
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "backfill",
+    desc = "Scan the history of a channel and add messages that already have votes to a starboard."
+)]
+pub struct BackfillStarboard {
+    /// The starboard to backfill.
+    #[command(autocomplete = true)]
+    name: String,
+    /// The channel to scan.
+    #[command(channel_types = r#"
+            guild_text
+            guild_voice
+            guild_stage_voice
+            guild_announcement
+            announcement_thread
+            public_thread
+            private_thread
+        "#)]
+    channel: InteractionChannel,
+    /// Only scan messages after this message link, message ID, or date (YYYY-MM-DD).
+    after: Option<String>,
+    /// Only scan messages before this message link, message ID, or date (YYYY-MM-DD).
+    before: Option<String>,
+}
+
+impl BackfillStarboard {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx);
+        let bot = ctx.bot.clone();
+
+        let mut bounds = Vec::new();
+        for bound in [&self.after, &self.before] {
+            let parsed = match bound {
+                None => None,
+                Some(bound) => match parse_message_bound(bound) {
+                    Some(id) => Some(id.into_id()),
+                    None => {
+                        ctx.respond_str(
+                            &format!("'{bound}' is not a valid message link, message ID, or date."),
+                            true,
+                        )
+                        .await?;
+                        return Ok(());
+                    }
+                },
+            };
+            bounds.push(parsed);
+        }
+        let (after, before) = (bounds[0], bounds[1]);
+        if let (Some(after), Some(before)) = (after, before) {
+            if after >= before {
+                ctx.respond_str("`after` must be earlier than `before`.", true)
+                    .await?;
+                return Ok(());
+            }
+        }
+
+        let starboard = Starboard::get_by_name(&bot.pool, &self.name, guild_id.get_i64()).await?;
+        let Some(starboard) = starboard else {
+            ctx.respond_str(&format!("'{}' is not a starboard.", self.name), true)
+                .await?;
+            return Ok(());
+        };
+        if starboard.premium_locked {
+            ctx.respond_str("That starboard is premium-locked.", true)
+                .await?;
+            return Ok(());
+        }
+        if starboard.channel_id == self.channel.id.get_i64() {
+            ctx.respond_str("You can't backfill a starboard from its own channel.", true)
+                .await?;
+            return Ok(());
+        }
+
+        let configs = StarboardConfig::list_for_channel(&bot, guild_id, self.channel.id).await?;
+        let enabled = configs
+            .iter()
+            .find(|c| c.starboard.id == starboard.id)
+            .is_some_and(|c| c.resolved.enabled);
+        if !enabled {
+            ctx.respond_str(
+                &format!(
+                    "'{}' is disabled in <#{}>.",
+                    starboard.name, self.channel.id
+                ),
+                true,
+            )
+            .await?;
+            return Ok(());
+        }
+
+        let Some(_guard) = bot.locks.backfill.lock(guild_id.get_i64()) else {
+            ctx.respond_str("A backfill is already running in this server.", true)
+                .await?;
+            return Ok(());
+        };
+
+        let resp = ctx
+            .build_resp()
+            .content(format_progress(
+                &starboard.name,
+                BackfillProgress::default(),
+            ))
+            .components(components())
+            .build();
+        let msg = ctx.respond(resp).await?.model().await?;
+
+        // listen for the cancel button while the backfill runs
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let listener = {
+            let bot = bot.clone();
+            let cancelled = cancelled.clone();
+            let user_id = ctx.interaction.author_id().unwrap();
+            tokio::spawn(async move {
+                let btn_ctx = wait_for_component(
+                    bot,
+                    &["backfill::cancel"],
+                    msg.id,
+                    user_id,
+                    constants::BACKFILL_CANCEL_TIMEOUT,
+                )
+                .await;
+                if let Some(mut btn_ctx) = btn_ctx {
+                    cancelled.store(true, Ordering::Relaxed);
+                    let _ = btn_ctx
+                        .raw_respond(None, InteractionResponseType::DeferredUpdateMessage)
+                        .await;
+                }
+            })
+        };
+
+        let mut backfill = Backfill {
+            bot: bot.clone(),
+            guild_id,
+            channel_id: self.channel.id,
+            configs: configs.into_iter().map(Arc::new).collect(),
+            starboard_id: starboard.id,
+            after,
+            before,
+            cancelled,
+            progress: BackfillProgress::default(),
+        };
+        let ret = backfill
+            .run(report_progress(bot.clone(), &msg, |progress| {
+                format_progress(&starboard.name, progress)
+            }))
+            .await;
+        listener.abort();
+
+        let status = match ret {
+            Ok(true) => "Backfill finished.",
+            Ok(false) => "Backfill cancelled.",
+            Err(why) => {
+                bot.handle_error(&why).await;
+                "Backfill stopped because of an error."
+            }
+        };
+        let content = format!(
+            "{}\n{}",
+            status,
+            format_progress(&starboard.name, backfill.progress)
+        );
+        bot.http
+            .update_message(msg.channel_id, msg.id)
+            .content(Some(&content))?
+            .components(Some(&[]))?
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn format_progress(name: &str, progress: BackfillProgress) -> String {
+    let mut content = format!(
+        "Backfilling '{}': scanned {} messages, found {} with votes.",
+        name, progress.scanned, progress.found
+    );
+    if progress.done_scanning {
+        content.push_str(&format!(
+            "\nProcessed {}/{} messages, {} on the starboard.",
+            progress.processed, progress.found, progress.posted
+        ));
+        if progress.skipped != 0 {
+            content.push_str(&format!(
+                " {} couldn't be refreshed because they were already being updated.",
+                progress.skipped
+            ));
+        }
+    }
+
+    content
+}
+
+fn components() -> Vec<Component> {
+    vec![Component::ActionRow(ActionRow {
+        components: vec![Component::Button(Button {
+            custom_id: Some("backfill::cancel".to_string()),
+            disabled: false,
+            emoji: None,
+            label: Some("Cancel".to_string()),
+            style: ButtonStyle::Danger,
+            url: None,
+        })],
+    })]
+}
@@ -0,0 +1,205 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::application::interaction::application_command::InteractionChannel;
+
+use crate::{
+    constants,
+    core::{
+        audit::{self, AuditAction, AuditTarget},
+        premium::is_premium::is_guild_premium,
+    },
+    database::{
+        models::starboard_filter_group::StarboardFilterGroup,
+        validation::{name::validate_name, starboard_settings::validate_override_values},
+        DbGuild, OverrideValues, PermRoleStarboard, Starboard, StarboardOverride,
+    },
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::context::CommandCtx,
+    utils::id_as_i64::GetI64,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "clone",
+    desc = "Create a copy of a starboard, including its overrides and filters."
+)]
+pub struct CloneStarboard {
+    /// The starboard to copy.
+    #[command(autocomplete = true)]
+    source: String,
+    /// The name of the new starboard.
+    name: String,
+    /// The channel to create the new starboard in.
+    #[command(channel_types = r#"
+            guild_text
+            guild_voice
+            guild_stage_voice
+            guild_announcement
+            announcement_thread
+            public_thread
+            private_thread
+            guild_forum
+        "#)]
+    channel: InteractionChannel,
+}
+
+impl CloneStarboard {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx).get_i64();
+        DbGuild::create(&ctx.bot.pool, guild_id).await?;
+        let channel_id = self.channel.id.get_i64();
+
+        let count = Starboard::count_by_guild(&ctx.bot.pool, guild_id).await?;
+        let premium = is_guild_premium(&ctx.bot, guild_id, true).await?;
+        let limit = if premium {
+            constants::MAX_PREM_STARBOARDS
+        } else {
+            constants::MAX_STARBOARDS
+        };
+        if count >= limit {
+            ctx.respond_str(
+                &format!(
+                    "You can only have up to {} starboards. The premium limit is {}.",
+                    limit,
+                    constants::MAX_PREM_STARBOARDS,
+                ),
+                true,
+            )
+            .await?;
+            return Ok(());
+        }
+
+        let name = match validate_name(&self.name) {
+            Err(why) => {
+                ctx.respond_str(&why, true).await?;
+                return Ok(());
+            }
+            Ok(name) => name,
+        };
+
+        let source = Starboard::get_by_name(&ctx.bot.pool, &self.source, guild_id).await?;
+        let Some(source) = source else {
+            ctx.respond_str(&format!("'{}' is not a starboard.", self.source), true)
+                .await?;
+            return Ok(());
+        };
+        if source.premium_locked {
+            ctx.respond_str("That starboard is premium-locked.", true)
+                .await?;
+            return Ok(());
+        }
+
+        // the source may use settings that are only valid with premium
+        let settings = OverrideValues::from_settings(source.settings.clone());
+        if let Err(why) = validate_override_values(&settings, premium) {
+            ctx.respond_str(&why, true).await?;
+            return Ok(());
+        }
+
+        let mut tx = ctx.bot.pool.begin().await?;
+
+        let new = Starboard::create(&mut tx, &name, channel_id, guild_id).await?;
+        let Some(mut new) = new else {
+            ctx.respond_str(
+                &format!("A starboard with the name '{name}' already exists."),
+                true,
+            )
+            .await?;
+            return Ok(());
+        };
+        new.settings = source.settings.clone();
+        // exclusive groups are too specific to the starboard to be copied
+        new.settings.exclusive_group = None;
+        new.settings.exclusive_group_priority = 0;
+        let new_id = new.id;
+        new.update_settings(&mut tx).await?;
+
+        let mut skipped = Vec::new();
+        let mut invalid = Vec::new();
+        for ov in StarboardOverride::list_by_starboard(&mut tx, source.id).await? {
+            // override names are unique per guild, so prefix them with the
+            // name of the new starboard instead
+            let suffix = ov
+                .name
+                .strip_prefix(&format!("{}-", source.name))
+                .unwrap_or(&ov.name);
+            let Ok(ov_name) = validate_name(&format!("{name}-{suffix}")) else {
+                skipped.push(ov.name);
+                continue;
+            };
+
+            let mut settings = ov.get_overrides()?;
+            settings.exclusive_group = None;
+            settings.exclusive_group_priority = None;
+            if let Err(why) = validate_override_values(&settings, premium) {
+                invalid.push(format!("'{}': {}", ov.name, why));
+                continue;
+            }
+
+            let new_ov = StarboardOverride::create(&mut tx, guild_id, &ov_name, new_id).await?;
+            let Some(new_ov) = new_ov else {
+                skipped.push(ov.name);
+                continue;
+            };
+            StarboardOverride::set_channels(&mut tx, guild_id, &new_ov.name, &ov.channel_ids)
+                .await?;
+            StarboardOverride::update_settings(&mut tx, new_ov.id, settings).await?;
+        }
+
+        for group in StarboardFilterGroup::list_by_starboard(&mut tx, source.id).await? {
+            StarboardFilterGroup::create(&mut tx, group.filter_group_id, new_id).await?;
+        }
+
+        for pr_sb in PermRoleStarboard::list_by_starboard(&mut tx, source.id).await? {
+            PermRoleStarboard::create(&mut tx, pr_sb.permrole_id, new_id).await?;
+            let pr_sb = PermRoleStarboard {
+                starboard_id: new_id,
+                ..pr_sb
+            };
+            pr_sb.update(&mut tx).await?;
+        }
+
+        audit::log(
+            &mut tx,
+            &ctx,
+            AuditAction::Create,
+            AuditTarget::Starboard,
+            new_id.into(),
+            &name,
+            serde_json::json!({
+                "channel_id": {"new": channel_id},
+                "cloned_from": {"new": source.name},
+            }),
+        )
+        .await?;
+        tx.commit().await?;
+        ctx.bot.cache.guild_vote_emojis.remove(&guild_id);
+
+        let mut message = format!(
+            "Created starboard '{name}' in <#{channel_id}> as a copy of '{}'.",
+            source.name
+        );
+        if !skipped.is_empty() {
+            message.push_str(&format!(
+                concat!(
+                    "\n\nThese overrides could not be copied, because the new name was too long ",
+                    "or already in use: {}"
+                ),
+                skipped
+                    .iter()
+                    .map(|name| format!("'{name}'"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        if !invalid.is_empty() {
+            message.push_str(&format!(
+                "\n\nThese overrides could not be copied, because their settings aren't valid:\n{}",
+                invalid.join("\n")
+            ));
+        }
+        ctx.respond_str(&message, false).await?;
+
+        Ok(())
+    }
+}
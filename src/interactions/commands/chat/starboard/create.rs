@@ -3,7 +3,11 @@ use twilight_model::application::interaction::application_command::InteractionCh
 
 use crate::{
     constants,
-    core::premium::is_premium::is_guild_premium,
+    core::{
+        audit::{self, AuditAction, AuditTarget},
+        premium::is_premium::is_guild_premium,
+        starboard::presets::PresetValues,
+    },
     database::{validation, DbGuild, Starboard},
     errors::StarboardResult,
     get_guild_id,
@@ -28,16 +32,21 @@ pub struct CreateStarboard {
             guild_forum
         "#)]
     channel: InteractionChannel,
+    /// A preset to use for the settings of the starboard.
+    #[command(autocomplete = true)]
+    preset: Option<String>,
 }
 
 impl CreateStarboard {
     pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
-        let guild_id = get_guild_id!(ctx).get_i64();
-        DbGuild::create(&ctx.bot.pool, guild_id).await?;
+        let guild_id = get_guild_id!(ctx);
+        let guild_id_i64 = guild_id.get_i64();
+        DbGuild::create(&ctx.bot.pool, guild_id_i64).await?;
         let channel_id = self.channel.id.get_i64();
 
-        let count = Starboard::count_by_guild(&ctx.bot.pool, guild_id).await?;
-        let limit = if is_guild_premium(&ctx.bot, guild_id, true).await? {
+        let count = Starboard::count_by_guild(&ctx.bot.pool, guild_id_i64).await?;
+        let premium = is_guild_premium(&ctx.bot, guild_id_i64, true).await?;
+        let limit = if premium {
             constants::MAX_PREM_STARBOARDS
         } else {
             constants::MAX_STARBOARDS
@@ -63,20 +72,73 @@ impl CreateStarboard {
             Ok(name) => name,
         };
 
-        let ret = Starboard::create(&ctx.bot.pool, &name, channel_id, guild_id).await?;
+        let preset = match &self.preset {
+            None => None,
+            Some(preset_name) => {
+                let Some(mut preset) =
+                    PresetValues::get(&ctx.bot, guild_id_i64, preset_name).await?
+                else {
+                    ctx.respond_str(&format!("Preset '{preset_name}' does not exist."), true)
+                        .await?;
+                    return Ok(());
+                };
+                if let Err(why) = preset.validate(premium) {
+                    ctx.respond_str(&why, true).await?;
+                    return Ok(());
+                }
+                Some(preset)
+            }
+        };
 
-        if ret.is_none() {
-            ctx.respond_str(
-                &format!("A starboard with the name '{name}' already exists."),
-                true,
+        let mut tx = ctx.bot.pool.begin().await?;
+        let (ret, report) = match preset {
+            None => (
+                Starboard::create(&mut tx, &name, channel_id, guild_id_i64).await?,
+                None,
+            ),
+            Some(preset) => {
+                match preset
+                    .create_starboard(&ctx.bot, &mut tx, guild_id, &name, channel_id)
+                    .await?
+                {
+                    None => (None, None),
+                    Some((starboard, report)) => (Some(starboard), Some(report)),
+                }
+            }
+        };
+
+        if let Some(starboard) = ret {
+            audit::log(
+                &mut tx,
+                &ctx,
+                AuditAction::Create,
+                AuditTarget::Starboard,
+                starboard.id.into(),
+                &name,
+                serde_json::json!({
+                    "channel_id": {"new": channel_id},
+                    "preset": {"new": self.preset},
+                }),
             )
             .await?;
-        } else {
-            ctx.bot.cache.guild_vote_emojis.remove(&guild_id);
+            tx.commit().await?;
+            ctx.bot.cache.guild_vote_emojis.remove(&guild_id_i64);
 
+            let mut message = format!("Created starboard '{name}' in <#{channel_id}>.");
+            if let Some(preset_name) = &self.preset {
+                message.push_str(&format!(
+                    " It uses the settings of the preset '{preset_name}'."
+                ));
+            }
+            if let Some(report) = report.filter(|report| !report.is_empty()) {
+                message.push_str("\n\nSome parts of the preset were left out:\n");
+                message.push_str(&report.describe());
+            }
+            ctx.respond_str(&message, false).await?;
+        } else {
             ctx.respond_str(
-                &format!("Created starboard '{name}' in <#{channel_id}>."),
-                false,
+                &format!("A starboard with the name '{name}' already exists."),
+                true,
             )
             .await?;
         }
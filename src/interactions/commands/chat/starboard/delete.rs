@@ -1,7 +1,10 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
-    core::premium::{is_premium::is_guild_premium, locks::refresh_premium_locks},
+    core::{
+        audit::{self, AuditAction, AuditTarget},
+        premium::{is_premium::is_guild_premium, locks::refresh_premium_locks},
+    },
     database::Starboard,
     errors::StarboardResult,
     get_guild_id,
@@ -36,21 +39,36 @@ impl DeleteStarboard {
             Some(btn_ctx) => btn_ctx,
         };
 
-        let ret = Starboard::delete(&ctx.bot.pool, &self.name, guild_id_i64).await?;
+        let mut tx = ctx.bot.pool.begin().await?;
+        let ret = Starboard::delete(&mut tx, &self.name, guild_id_i64).await?;
+        if let Some(starboard) = &ret {
+            audit::log(
+                &mut tx,
+                &ctx,
+                AuditAction::Delete,
+                AuditTarget::Starboard,
+                starboard.id.into(),
+                &starboard.name,
+                serde_json::json!({"channel_id": {"old": starboard.channel_id}}),
+            )
+            .await?;
+        }
+        tx.commit().await?;
+
         refresh_premium_locks(
             &ctx.bot,
             guild_id.get_i64(),
             is_guild_premium(&ctx.bot, guild_id.get_i64(), true).await?,
         )
         .await?;
-        if ret.is_none() {
+        if ret.is_some() {
+            ctx.bot.cache.guild_vote_emojis.remove(&guild_id_i64);
             btn_ctx
-                .edit_str("No starboard with that name was found.", true)
+                .edit_str(&format!("Deleted starboard '{}'.", self.name), true)
                 .await?;
         } else {
-            ctx.bot.cache.guild_vote_emojis.remove(&guild_id_i64);
             btn_ctx
-                .edit_str(&format!("Deleted starboard '{}'.", self.name), true)
+                .edit_str("No starboard with that name was found.", true)
                 .await?;
         }
         Ok(())
@@ -1,6 +1,7 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
+    core::audit::{self, AuditAction, AuditTarget},
     database::{
         validation::{self, cooldown::parse_cooldown},
         ExclusiveGroup, Starboard,
@@ -26,6 +27,9 @@ pub struct EditBehavior {
     /// Whether to automatically react to starboard messages with the downvote emojis.
     #[command(rename = "autoreact-downvote")]
     autoreact_downvote: Option<bool>,
+    /// Whether to use Upvote/Downvote buttons on starboard messages instead of reactions.
+    #[command(rename = "vote-buttons")]
+    vote_buttons: Option<bool>,
     /// Whether to remove reactions that don't meet requirements.
     #[command(rename = "remove-invalid-reactions")]
     remove_invalid_reactions: Option<bool>,
@@ -71,6 +75,7 @@ impl EditBehavior {
                 }
                 Some(starboard) => starboard,
             };
+        let old_settings = starboard.settings.clone();
 
         if let Some(val) = self.enabled {
             starboard.settings.enabled = val;
@@ -81,6 +86,9 @@ impl EditBehavior {
         if let Some(val) = self.autoreact_downvote {
             starboard.settings.autoreact_downvote = val;
         }
+        if let Some(val) = self.vote_buttons {
+            starboard.settings.vote_buttons = val;
+        }
         if let Some(val) = self.remove_invalid_reactions {
             starboard.settings.remove_invalid_reactions = val;
         }
@@ -138,7 +146,21 @@ impl EditBehavior {
             starboard.settings.exclusive_group_priority = val as i16;
         }
 
-        starboard.update_settings(&ctx.bot.pool).await?;
+        let changes = audit::diff(&old_settings, &starboard.settings);
+        let starboard_id = starboard.id;
+        let mut tx = ctx.bot.pool.begin().await?;
+        starboard.update_settings(&mut tx).await?;
+        audit::log(
+            &mut tx,
+            &ctx,
+            AuditAction::Edit,
+            AuditTarget::Starboard,
+            starboard_id.into(),
+            &self.name,
+            changes,
+        )
+        .await?;
+        tx.commit().await?;
         ctx.respond_str(
             &format!("Updated settings for starboard '{}'.", self.name),
             false,
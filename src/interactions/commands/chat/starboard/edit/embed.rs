@@ -1,6 +1,7 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
+    core::audit::{self, AuditAction, AuditTarget},
     database::{validation::color, Starboard},
     errors::StarboardResult,
     get_guild_id,
@@ -42,6 +43,7 @@ impl EditEmbedStyle {
             }
             Some(starboard) => starboard,
         };
+        let old_settings = starboard.settings.clone();
 
         if let Some(val) = self.color {
             if val == "none" {
@@ -63,7 +65,21 @@ impl EditEmbedStyle {
             starboard.settings.replied_to = val;
         }
 
-        starboard.update_settings(&ctx.bot.pool).await?;
+        let changes = audit::diff(&old_settings, &starboard.settings);
+        let starboard_id = starboard.id;
+        let mut tx = ctx.bot.pool.begin().await?;
+        starboard.update_settings(&mut tx).await?;
+        audit::log(
+            &mut tx,
+            &ctx,
+            AuditAction::Edit,
+            AuditTarget::Starboard,
+            starboard_id.into(),
+            &self.name,
+            changes,
+        )
+        .await?;
+        tx.commit().await?;
         ctx.respond_str(
             &format!("Updated settings for starboard '{}'.", self.name),
             false,
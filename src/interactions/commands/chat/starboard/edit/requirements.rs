@@ -2,12 +2,14 @@ use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
     core::{
+        audit::{self, AuditAction, AuditTarget},
         emoji::{EmojiCommon, SimpleEmoji},
         premium::is_premium::is_guild_premium,
     },
     database::{
         validation::{
             self,
+            emoji_points::{parse_emoji_points, prune_emoji_points},
             starboard_settings::{validate_required, validate_required_remove},
             time_delta::{parse_time_delta, validate_relative_duration},
         },
@@ -40,6 +42,9 @@ pub struct EditRequirements {
     /// The emojis that can be used to downvote a post. Use 'none' to remove all.
     #[command(rename = "downvote-emojis")]
     downvote_emojis: Option<String>,
+    /// How many points each vote emoji is worth (e.g. "⭐=1 🌟=5"). Use 'none' to reset all to 1.
+    #[command(rename = "emoji-points")]
+    emoji_points: Option<String>,
     /// Whether to allow users to vote on their own posts.
     #[command(rename = "self-vote")]
     self_vote: Option<bool>,
@@ -75,6 +80,7 @@ impl EditRequirements {
                 }
                 Some(starboard) => starboard,
             };
+        let old_settings = starboard.settings.clone();
 
         let is_prem = is_guild_premium(&ctx.bot, guild_id_i64, true).await?;
 
@@ -98,6 +104,7 @@ impl EditRequirements {
             };
             starboard.settings.required_remove = val;
         }
+        let emojis_changed = self.upvote_emojis.is_some() || self.downvote_emojis.is_some();
         if let Some(val) = self.upvote_emojis {
             let emojis = SimpleEmoji::from_user_input(&val, &ctx.bot, guild_id).into_stored();
             starboard.settings.upvote_emojis = emojis;
@@ -113,6 +120,26 @@ impl EditRequirements {
             ctx.bot.cache.guild_vote_emojis.remove(&guild_id_i64);
         }
 
+        let vote_emojis: Vec<_> = starboard
+            .settings
+            .upvote_emojis
+            .iter()
+            .chain(&starboard.settings.downvote_emojis)
+            .cloned()
+            .collect();
+        if emojis_changed {
+            prune_emoji_points(&mut starboard.settings.emoji_points, &vote_emojis);
+        }
+        if let Some(val) = self.emoji_points {
+            match parse_emoji_points(&val, &ctx.bot, guild_id, &vote_emojis) {
+                Err(why) => {
+                    ctx.respond_str(&why, true).await?;
+                    return Ok(());
+                }
+                Ok(val) => starboard.settings.emoji_points = val,
+            }
+        }
+
         if let Err(why) = validation::starboard_settings::validate_vote_emojis(
             &starboard.settings.upvote_emojis,
             &starboard.settings.downvote_emojis,
@@ -179,7 +206,21 @@ impl EditRequirements {
             }
         }
 
-        starboard.update_settings(&ctx.bot.pool).await?;
+        let changes = audit::diff(&old_settings, &starboard.settings);
+        let starboard_id = starboard.id;
+        let mut tx = ctx.bot.pool.begin().await?;
+        starboard.update_settings(&mut tx).await?;
+        audit::log(
+            &mut tx,
+            &ctx,
+            AuditAction::Edit,
+            AuditTarget::Starboard,
+            starboard_id.into(),
+            &self.name,
+            changes,
+        )
+        .await?;
+        tx.commit().await?;
         ctx.respond_str(
             &format!("Updated settings for starboard '{}'.", self.name),
             false,
@@ -2,6 +2,7 @@ use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
     core::{
+        audit::{self, AuditAction, AuditTarget},
         emoji::{EmojiCommon, SimpleEmoji},
         starboard::webhooks::create_webhook,
     },
@@ -53,6 +54,7 @@ impl EditGeneralStyle {
             }
             Some(starboard) => starboard,
         };
+        let old_settings = starboard.settings.clone();
 
         if let Some(val) = self.display_emoji {
             let emoji = if val == "none" {
@@ -102,7 +104,21 @@ impl EditGeneralStyle {
             response.push_str(message);
         }
 
-        starboard.update_settings(&ctx.bot.pool).await?;
+        let changes = audit::diff(&old_settings, &starboard.settings);
+        let starboard_id = starboard.id;
+        let mut tx = ctx.bot.pool.begin().await?;
+        starboard.update_settings(&mut tx).await?;
+        audit::log(
+            &mut tx,
+            &ctx,
+            AuditAction::Edit,
+            AuditTarget::Starboard,
+            starboard_id.into(),
+            &self.name,
+            changes,
+        )
+        .await?;
+        tx.commit().await?;
         ctx.respond_str(&response, false).await?;
         Ok(())
     }
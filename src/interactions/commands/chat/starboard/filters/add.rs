@@ -1,6 +1,7 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
+    core::audit::{self, AuditAction, AuditTarget},
     database::{
         models::{filter_group::FilterGroup, starboard_filter_group::StarboardFilterGroup},
         Starboard,
@@ -46,9 +47,21 @@ impl Add {
             return Ok(());
         };
 
-        let ret = StarboardFilterGroup::create(&ctx.bot.pool, group.id, starboard.id).await?;
+        let mut tx = ctx.bot.pool.begin().await?;
+        let ret = StarboardFilterGroup::create(&mut tx, group.id, starboard.id).await?;
 
         if ret.is_some() {
+            audit::log(
+                &mut tx,
+                &ctx,
+                AuditAction::Edit,
+                AuditTarget::FilterGroup,
+                group.id.into(),
+                &group.name,
+                serde_json::json!({"starboard": {"new": starboard.name}}),
+            )
+            .await?;
+            tx.commit().await?;
             ctx.respond_str(
                 &format!(
                     "Added filter group '{}' to starboard '{}'.",
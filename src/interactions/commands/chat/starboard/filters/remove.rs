@@ -1,6 +1,7 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
+    core::audit::{self, AuditAction, AuditTarget},
     database::{
         models::{filter_group::FilterGroup, starboard_filter_group::StarboardFilterGroup},
         Starboard,
@@ -46,9 +47,21 @@ impl Remove {
             return Ok(());
         };
 
-        let ret = StarboardFilterGroup::delete(&ctx.bot.pool, group.id, starboard.id).await?;
+        let mut tx = ctx.bot.pool.begin().await?;
+        let ret = StarboardFilterGroup::delete(&mut tx, group.id, starboard.id).await?;
 
         if ret.is_some() {
+            audit::log(
+                &mut tx,
+                &ctx,
+                AuditAction::Edit,
+                AuditTarget::FilterGroup,
+                group.id.into(),
+                &group.name,
+                serde_json::json!({"starboard": {"old": starboard.name}}),
+            )
+            .await?;
+            tx.commit().await?;
             ctx.respond_str(
                 &format!(
                     "Removed filter group '{}' from starboard '{}'.",
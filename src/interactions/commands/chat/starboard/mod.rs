@@ -1,7 +1,12 @@
+pub mod backfill;
+pub mod clone;
 pub mod create;
 pub mod delete;
 pub mod edit;
 pub mod filters;
+pub mod move_starboard;
+pub mod presets;
+pub mod regenerate;
 pub mod rename;
 pub mod view;
 
@@ -32,6 +37,16 @@ pub enum Starboard {
     Edit(edit::EditStarboard),
     #[command(name = "filters")]
     Filters(filters::Filters),
+    #[command(name = "presets")]
+    Presets(presets::Presets),
+    #[command(name = "backfill")]
+    Backfill(backfill::BackfillStarboard),
+    #[command(name = "regenerate")]
+    Regenerate(regenerate::RegenerateStarboard),
+    #[command(name = "move")]
+    Move(move_starboard::MoveStarboard),
+    #[command(name = "clone")]
+    Clone(clone::CloneStarboard),
 }
 
 impl Starboard {
@@ -43,6 +58,11 @@ impl Starboard {
             Self::Rename(cmd) => cmd.callback(ctx).await,
             Self::Edit(cmd) => cmd.callback(ctx).await,
             Self::Filters(cmd) => cmd.callback(ctx).await,
+            Self::Presets(cmd) => cmd.callback(ctx).await,
+            Self::Backfill(cmd) => cmd.callback(ctx).await,
+            Self::Regenerate(cmd) => cmd.callback(ctx).await,
+            Self::Move(cmd) => cmd.callback(ctx).await,
+            Self::Clone(cmd) => cmd.callback(ctx).await,
         }
     }
 }
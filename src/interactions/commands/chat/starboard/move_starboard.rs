@@ -0,0 +1,223 @@
+use std::sync::Arc;
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::application::interaction::application_command::InteractionChannel;
+
+use crate::{
+    core::{
+        audit::{self, AuditAction, AuditTarget},
+        starboard::{
+            config::StarboardConfig,
+            move_posts::{MovePosts, MoveProgress},
+            webhooks::get_shared_webhook,
+        },
+    },
+    database::Starboard,
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::context::CommandCtx,
+    utils::{id_as_i64::GetI64, progress::report_progress, views::confirm},
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "move", desc = "Move a starboard to a different channel.")]
+pub struct MoveStarboard {
+    /// The starboard to move.
+    #[command(autocomplete = true)]
+    name: String,
+    /// The channel to move the starboard to.
+    #[command(channel_types = r#"
+            guild_text
+            guild_voice
+            guild_stage_voice
+            guild_announcement
+            announcement_thread
+            public_thread
+            private_thread
+            guild_forum
+        "#)]
+    channel: InteractionChannel,
+    /// Whether to send the existing posts again in the new channel. Defaults to true.
+    repost: Option<bool>,
+    /// Whether to delete the old posts after they were sent again. Defaults to false.
+    #[command(rename = "delete-old")]
+    delete_old: Option<bool>,
+}
+
+impl MoveStarboard {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx).get_i64();
+        let bot = ctx.bot.clone();
+        let channel_id = self.channel.id.get_i64();
+        let repost = self.repost.unwrap_or(true);
+        let delete_old = self.delete_old.unwrap_or(false);
+
+        if delete_old && !repost {
+            ctx.respond_str(
+                "The old posts can only be deleted if they're reposted.",
+                true,
+            )
+            .await?;
+            return Ok(());
+        }
+
+        let starboard = Starboard::get_by_name(&bot.pool, &self.name, guild_id).await?;
+        let Some(starboard) = starboard else {
+            ctx.respond_str(&format!("'{}' is not a starboard.", self.name), true)
+                .await?;
+            return Ok(());
+        };
+        if starboard.premium_locked {
+            ctx.respond_str("That starboard is premium-locked.", true)
+                .await?;
+            return Ok(());
+        }
+        if starboard.channel_id == channel_id {
+            ctx.respond_str(
+                &format!("'{}' is already in <#{}>.", starboard.name, channel_id),
+                true,
+            )
+            .await?;
+            return Ok(());
+        }
+
+        let Some(_guard) = bot.locks.starboard_posts.lock(starboard.id) else {
+            ctx.respond_str("That starboard's posts are already being updated.", true)
+                .await?;
+            return Ok(());
+        };
+
+        let prompt = if repost {
+            format!(
+                concat!(
+                    "Are you sure you want to move '{}' to <#{}>? All of its posts will be sent ",
+                    "again in the new channel{}."
+                ),
+                starboard.name,
+                channel_id,
+                if delete_old {
+                    ", and the old posts will be deleted"
+                } else {
+                    ""
+                }
+            )
+        } else {
+            format!(
+                concat!(
+                    "Are you sure you want to move '{}' to <#{}>? Existing posts will stay in ",
+                    "the old channel, and will be sent again in the new channel the next time ",
+                    "they're updated."
+                ),
+                starboard.name, channel_id
+            )
+        };
+        let mut btn_ctx = match confirm::simple(&mut ctx, &prompt, false).await? {
+            None => return Ok(()),
+            Some(btn_ctx) => btn_ctx,
+        };
+
+        // updates to the posts are queued until they've all been reposted
+        let _moving = bot.locks.starboard_move.lock(starboard.id);
+        let mut tx = bot.pool.begin().await?;
+        let moved = Starboard::set_channel(&mut tx, starboard.id, channel_id).await?;
+        let Some(moved) = moved else {
+            btn_ctx
+                .edit_str("No starboard with that name was found.", true)
+                .await?;
+            return Ok(());
+        };
+        audit::log(
+            &mut tx,
+            &ctx,
+            AuditAction::Edit,
+            AuditTarget::Starboard,
+            moved.id.into(),
+            &moved.name,
+            serde_json::json!({"channel_id": {"old": starboard.channel_id, "new": moved.channel_id}}),
+        )
+        .await?;
+        tx.commit().await?;
+
+        let mut content = format!("Moved '{}' to <#{}>.", moved.name, channel_id);
+        if moved.settings.use_webhook && get_shared_webhook(&bot, &moved).await?.is_none() {
+            content.push_str(concat!(
+                "\n\nI couldn't find or create a webhook in the new channel. Please make sure I ",
+                "have the necessary permissions."
+            ));
+        }
+
+        if !repost {
+            btn_ctx.edit_str(&content, true).await?;
+            return Ok(());
+        }
+
+        let msg = btn_ctx
+            .edit_str(
+                &format!("{}\n{}", content, format_progress(MoveProgress::default())),
+                true,
+            )
+            .await?
+            .model()
+            .await?;
+
+        // the old posts are deleted with the starboard as it was before the
+        // move, so that its channel and webhook are used
+        let delete_from = if delete_old {
+            Some(Arc::new(StarboardConfig::new(starboard, &[], Vec::new())?))
+        } else {
+            None
+        };
+        let mut move_posts = MovePosts {
+            bot: bot.clone(),
+            guild_id,
+            starboard_id: moved.id,
+            delete_from,
+            progress: MoveProgress::default(),
+        };
+        let ret = move_posts
+            .run(report_progress(bot.clone(), &msg, |progress| {
+                format!("{}\n{}", content, format_progress(progress))
+            }))
+            .await;
+
+        let status = match ret {
+            Ok(()) => "Finished moving the posts.",
+            Err(why) => {
+                bot.handle_error(&why).await;
+                "Moving the posts stopped because of an error."
+            }
+        };
+        let mut content = format!(
+            "{}\n{}\n{}",
+            content,
+            status,
+            format_progress(move_posts.progress)
+        );
+        if move_posts.progress.removed != 0 {
+            content.push_str(concat!(
+                "\n\nRemoved posts no longer meet the starboard's settings or their original ",
+                "message was deleted. Any that were left in the old channel won't be updated ",
+                "anymore."
+            ));
+        }
+        if move_posts.progress.skipped != 0 {
+            content.push_str(concat!(
+                "\n\nSkipped posts were busy being updated or ran into an error, and were left ",
+                "in the old channel."
+            ));
+        }
+        bot.http
+            .update_message(msg.channel_id, msg.id)
+            .content(Some(&content))?
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn format_progress(progress: MoveProgress) -> String {
+    format!(
+        "Reposted {}/{} posts, {} removed, {} skipped.",
+        progress.moved, progress.total, progress.removed, progress.skipped
+    )
+}
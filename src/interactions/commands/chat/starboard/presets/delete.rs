@@ -0,0 +1,47 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    core::audit::{self, AuditAction, AuditTarget},
+    database::StarboardPreset,
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::context::CommandCtx,
+    utils::id_as_i64::GetI64,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "delete", desc = "Delete a preset.")]
+pub struct Delete {
+    /// The preset to delete.
+    #[command(autocomplete = true)]
+    name: String,
+}
+
+impl Delete {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx).get_i64();
+
+        let mut tx = ctx.bot.pool.begin().await?;
+        let ret = StarboardPreset::delete(&mut tx, guild_id, &self.name).await?;
+        if let Some(preset) = ret {
+            audit::log(
+                &mut tx,
+                &ctx,
+                AuditAction::Delete,
+                AuditTarget::Preset,
+                preset.id.into(),
+                &preset.name,
+                serde_json::json!({}),
+            )
+            .await?;
+            tx.commit().await?;
+            ctx.respond_str(&format!("Deleted preset '{}'.", self.name), false)
+                .await?;
+        } else {
+            ctx.respond_str(&format!("Preset '{}' does not exist.", self.name), true)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{errors::StarboardResult, interactions::context::CommandCtx};
+
+mod delete;
+mod save;
+mod view;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "presets", desc = "Manage presets for new starboards.")]
+pub enum Presets {
+    #[command(name = "save")]
+    Save(save::Save),
+    #[command(name = "delete")]
+    Delete(delete::Delete),
+    #[command(name = "view")]
+    View(view::View),
+}
+
+impl Presets {
+    pub async fn callback(self, ctx: CommandCtx) -> StarboardResult<()> {
+        match self {
+            Self::Save(cmd) => cmd.callback(ctx).await,
+            Self::Delete(cmd) => cmd.callback(ctx).await,
+            Self::View(cmd) => cmd.callback(ctx).await,
+        }
+    }
+}
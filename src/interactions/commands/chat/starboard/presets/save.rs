@@ -0,0 +1,109 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    constants,
+    core::{
+        audit::{self, AuditAction, AuditTarget},
+        starboard::presets::{builtin_preset, PresetValues},
+    },
+    database::{validation::name::validate_name, DbGuild, Starboard, StarboardPreset},
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::context::CommandCtx,
+    utils::id_as_i64::GetI64,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "save", desc = "Save the settings of a starboard as a preset.")]
+pub struct Save {
+    /// The starboard to save the settings of.
+    #[command(autocomplete = true)]
+    starboard: String,
+    /// The name for the preset.
+    name: String,
+    /// Whether to also save the overrides of the starboard. Defaults to false.
+    #[command(rename = "include-overrides")]
+    include_overrides: Option<bool>,
+}
+
+impl Save {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx).get_i64();
+
+        DbGuild::create(&ctx.bot.pool, guild_id).await?;
+
+        let name = match validate_name(&self.name) {
+            Err(why) => {
+                ctx.respond_str(&why, true).await?;
+                return Ok(());
+            }
+            Ok(name) => name,
+        };
+        if builtin_preset(&name).is_some() {
+            ctx.respond_str(&format!("'{name}' is the name of a built-in preset."), true)
+                .await?;
+            return Ok(());
+        }
+
+        let count = StarboardPreset::count_by_guild(&ctx.bot.pool, guild_id).await?;
+        if count >= constants::MAX_STARBOARD_PRESETS {
+            ctx.respond_str(
+                &format!(
+                    "You can only have up to {} presets.",
+                    constants::MAX_STARBOARD_PRESETS
+                ),
+                true,
+            )
+            .await?;
+            return Ok(());
+        }
+
+        let Some(starboard) =
+            Starboard::get_by_name(&ctx.bot.pool, &self.starboard, guild_id).await?
+        else {
+            ctx.respond_str(
+                &format!("Starboard '{}' does not exist.", self.starboard),
+                true,
+            )
+            .await?;
+            return Ok(());
+        };
+
+        let preset = PresetValues::from_starboard(
+            &ctx.bot,
+            &starboard,
+            self.include_overrides.unwrap_or(false),
+        )
+        .await?;
+        let preset = serde_json::to_value(&preset)?;
+
+        let mut tx = ctx.bot.pool.begin().await?;
+        let ret = StarboardPreset::create(&mut tx, guild_id, &name, preset).await?;
+        if let Some(preset) = ret {
+            audit::log(
+                &mut tx,
+                &ctx,
+                AuditAction::Create,
+                AuditTarget::Preset,
+                preset.id.into(),
+                &preset.name,
+                serde_json::json!({"starboard": {"new": starboard.name}}),
+            )
+            .await?;
+            tx.commit().await?;
+            ctx.respond_str(
+                &format!(
+                    "Saved the settings of '{}' as preset '{name}'.",
+                    starboard.name
+                ),
+                false,
+            )
+            .await?;
+        } else {
+            ctx.respond_str(&format!("A preset named '{name}' already exists."), true)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
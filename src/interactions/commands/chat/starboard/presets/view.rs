@@ -0,0 +1,50 @@
+use std::fmt::Write;
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedFieldBuilder;
+
+use crate::{
+    core::starboard::presets::BUILTIN_PRESETS,
+    database::StarboardPreset,
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::context::CommandCtx,
+    utils::{embed, id_as_i64::GetI64},
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "view", desc = "View the presets for new starboards.")]
+pub struct View;
+
+impl View {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx).get_i64();
+
+        let mut builtin = String::new();
+        for preset in BUILTIN_PRESETS {
+            writeln!(builtin, "`{}`: {}", preset.name, preset.description).unwrap();
+        }
+
+        let presets = StarboardPreset::list_by_guild(&ctx.bot.pool, guild_id).await?;
+        let custom = if presets.is_empty() {
+            "This server has no presets. Use `/starboards presets save` to create one.".to_string()
+        } else {
+            presets
+                .iter()
+                .map(|preset| format!("`{}`", preset.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        let embed = embed::build()
+            .title("Starboard Presets")
+            .description("Use a preset with `/starboards create preset:<name>`.")
+            .field(EmbedFieldBuilder::new("Built-in", builtin).build())
+            .field(EmbedFieldBuilder::new("Custom", custom).build())
+            .build();
+        ctx.respond(ctx.build_resp().embeds([embed]).build())
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    core::starboard::regenerate::{Regenerate, RegenerateProgress},
+    database::Starboard,
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::context::CommandCtx,
+    utils::{
+        id_as_i64::GetI64, message_link::parse_message_bound, progress::report_progress,
+        views::confirm,
+    },
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "regenerate",
+    desc = "Rebuild the existing posts on a starboard with its current style."
+)]
+pub struct RegenerateStarboard {
+    /// The starboard to regenerate.
+    #[command(autocomplete = true)]
+    name: String,
+    /// Only regenerate posts for messages after this message link, message ID, or date (YYYY-MM-DD).
+    since: Option<String>,
+}
+
+impl RegenerateStarboard {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx).get_i64();
+        let bot = ctx.bot.clone();
+
+        let since = match &self.since {
+            None => None,
+            Some(since) => match parse_message_bound(since) {
+                Some(id) => Some(id),
+                None => {
+                    ctx.respond_str(
+                        &format!("'{since}' is not a valid message link, message ID, or date."),
+                        true,
+                    )
+                    .await?;
+                    return Ok(());
+                }
+            },
+        };
+
+        let starboard = Starboard::get_by_name(&bot.pool, &self.name, guild_id).await?;
+        let Some(starboard) = starboard else {
+            ctx.respond_str(&format!("'{}' is not a starboard.", self.name), true)
+                .await?;
+            return Ok(());
+        };
+        if starboard.premium_locked {
+            ctx.respond_str("That starboard is premium-locked.", true)
+                .await?;
+            return Ok(());
+        }
+
+        let Some(_guard) = bot.locks.starboard_posts.lock(starboard.id) else {
+            ctx.respond_str("That starboard's posts are already being updated.", true)
+                .await?;
+            return Ok(());
+        };
+
+        let mut btn_ctx = match confirm::simple(
+            &mut ctx,
+            &format!(
+                concat!(
+                    "Are you sure you want to regenerate the posts on '{}'? Posts that were ",
+                    "sent with a different `use-webhook` setting will be deleted and sent again."
+                ),
+                starboard.name
+            ),
+            false,
+        )
+        .await?
+        {
+            None => return Ok(()),
+            Some(btn_ctx) => btn_ctx,
+        };
+
+        let msg = btn_ctx
+            .edit_str(
+                &format_progress(&starboard.name, RegenerateProgress::default()),
+                true,
+            )
+            .await?
+            .model()
+            .await?;
+
+        let mut regenerate = Regenerate {
+            bot: bot.clone(),
+            guild_id,
+            starboard_id: starboard.id,
+            since,
+            progress: RegenerateProgress::default(),
+        };
+        let ret = regenerate
+            .run(report_progress(bot.clone(), &msg, |progress| {
+                format_progress(&starboard.name, progress)
+            }))
+            .await;
+
+        let status = match ret {
+            Ok(()) => "Regeneration finished.",
+            Err(why) => {
+                bot.handle_error(&why).await;
+                "Regeneration stopped because of an error."
+            }
+        };
+        let content = format!(
+            "{}\n{}",
+            status,
+            format_progress(&starboard.name, regenerate.progress)
+        );
+        bot.http
+            .update_message(msg.channel_id, msg.id)
+            .content(Some(&content))?
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn format_progress(name: &str, progress: RegenerateProgress) -> String {
+    format!(
+        "Regenerating '{}': {}/{} posts, {} edited, {} resent, {} skipped.",
+        name,
+        progress.processed(),
+        progress.total,
+        progress.edited,
+        progress.resent,
+        progress.skipped
+    )
+}
@@ -1,6 +1,7 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
+    core::audit::{self, AuditAction, AuditTarget},
     database::{validation, Starboard},
     errors::StarboardResult,
     get_guild_id,
@@ -31,13 +32,9 @@ impl RenameStarboard {
             Ok(name) => name,
         };
 
-        let ret = Starboard::rename(
-            &ctx.bot.pool,
-            &self.current_name,
-            guild_id.get_i64(),
-            &new_name,
-        )
-        .await;
+        let mut tx = ctx.bot.pool.begin().await?;
+        let ret =
+            Starboard::rename(&mut tx, &self.current_name, guild_id.get_i64(), &new_name).await;
 
         match ret {
             Err(why) => {
@@ -55,7 +52,18 @@ impl RenameStarboard {
                 ctx.respond_str("No starboard with that name was found.", true)
                     .await?
             }
-            Ok(Some(_)) => {
+            Ok(Some(starboard)) => {
+                audit::log(
+                    &mut tx,
+                    &ctx,
+                    AuditAction::Rename,
+                    AuditTarget::Starboard,
+                    starboard.id.into(),
+                    &new_name,
+                    audit::rename(&self.current_name, &new_name),
+                )
+                .await?;
+                tx.commit().await?;
                 ctx.respond_str(
                     &format!(
                         "Renamed the starboard from '{}' to '{}'.",
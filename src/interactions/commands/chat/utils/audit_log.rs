@@ -0,0 +1,134 @@
+use std::fmt::Write;
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::user::User;
+
+use crate::{
+    database::models::audit_log_entry::AuditLogEntry,
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::context::CommandCtx,
+    utils::{id_as_i64::GetI64, views::paginator},
+};
+
+/// How many of the newest entries can be browsed.
+const MAX_ENTRIES: i64 = 250;
+/// How many changes to show per entry before summarizing the rest.
+const MAX_CHANGES_SHOWN: usize = 5;
+/// How many characters of each value to show, so that a page fits in a
+/// message even if it has long values such as regexes.
+const MAX_VALUE_LENGTH: usize = 50;
+
+fn format_value(value: Option<&serde_json::Value>) -> String {
+    let val = match value {
+        None => return "unset".to_string(),
+        Some(serde_json::Value::Null) => return "none".to_string(),
+        Some(serde_json::Value::String(val)) => val.clone(),
+        Some(val) => val.to_string(),
+    };
+
+    if val.chars().count() > MAX_VALUE_LENGTH {
+        let val: String = val.chars().take(MAX_VALUE_LENGTH).collect();
+        format!("{val}...")
+    } else {
+        val
+    }
+}
+
+pub fn format_entry(entry: &AuditLogEntry) -> String {
+    let target = if entry.target_kind.starts_with("permrole") {
+        format!("{} <@&{}>", entry.target_kind, entry.target_id)
+    } else {
+        format!("{} '{}'", entry.target_kind, entry.target_name)
+    };
+    let mut text = format!(
+        "<t:{}:f> <@{}> used `/{}` ({} {target}){}\n",
+        entry.created_at.timestamp(),
+        entry.user_id,
+        entry.command,
+        entry.action,
+        if entry.undone { " (undone)" } else { "" },
+    );
+
+    let Some(changes) = entry.changes.as_object() else {
+        return text;
+    };
+    for (key, change) in changes.iter().take(MAX_CHANGES_SHOWN) {
+        let old = change.get("old");
+        let new = change.get("new");
+        let key = key.replace('_', "-");
+        match (old, new) {
+            (None, Some(new)) if entry.action == "create" || entry.action == "import" => {
+                writeln!(text, "- `{key}`: {}", format_value(Some(new))).unwrap();
+            }
+            (Some(old), None) if entry.action == "delete" => {
+                writeln!(text, "- `{key}`: {}", format_value(Some(old))).unwrap();
+            }
+            _ => {
+                writeln!(
+                    text,
+                    "- `{key}`: {} → {}",
+                    format_value(old),
+                    format_value(new)
+                )
+                .unwrap();
+            }
+        }
+    }
+    if changes.len() > MAX_CHANGES_SHOWN {
+        writeln!(text, "- ...and {} more", changes.len() - MAX_CHANGES_SHOWN).unwrap();
+    }
+
+    text
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "audit-log",
+    desc = "View recent changes to the configuration of this server."
+)]
+pub struct AuditLog {
+    /// Only show changes to something with this name (such as a starboard or override).
+    target: Option<String>,
+    /// Only show changes made by this user.
+    user: Option<User>,
+}
+
+impl AuditLog {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx).get_i64();
+
+        let entries = AuditLogEntry::list_by_guild(
+            &ctx.bot.pool,
+            guild_id,
+            self.target.as_deref(),
+            self.user.map(|user| user.id.get_i64()),
+            MAX_ENTRIES,
+        )
+        .await?;
+        if entries.is_empty() {
+            ctx.respond_str("There are no matching changes.", true)
+                .await?;
+            return Ok(());
+        }
+
+        let pages = entries.chunks(5).map(|chunk| {
+            chunk
+                .iter()
+                .map(format_entry)
+                .collect::<Vec<_>>()
+                .join("\n")
+        });
+
+        let author_id = ctx.interaction.author_id().unwrap();
+        paginator::simple(
+            &mut ctx,
+            pages.map(|page| (Some(page), None)).collect(),
+            author_id,
+            true,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,179 @@
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::{channel::Attachment, http::attachment::Attachment as FileAttachment};
+
+use crate::{
+    constants,
+    core::{
+        audit::{self, AuditAction, AuditTarget},
+        export::guild::GuildConfig,
+        premium::is_premium::is_guild_premium,
+    },
+    database::DbGuild,
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::context::CommandCtx,
+    utils::{id_as_i64::GetI64, views::confirm},
+};
+
+use super::{check_manage_guild, truncate};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "config-export",
+    desc = "Export the configuration of this server as a JSON file."
+)]
+pub struct ConfigExport;
+
+impl ConfigExport {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx).get_i64();
+        if !check_manage_guild(&mut ctx).await? {
+            return Ok(());
+        }
+
+        let config = GuildConfig::export(&ctx.bot, guild_id).await?;
+        let file = serde_json::to_vec_pretty(&config)?;
+        let attachment = FileAttachment::from_bytes(format!("config-{guild_id}.json"), file, 0);
+
+        let resp = ctx
+            .build_resp()
+            .content("Exported the configuration of this server.")
+            .attachments([attachment])
+            .build();
+        ctx.respond(resp).await?;
+
+        Ok(())
+    }
+}
+
+#[derive(CommandOption, CreateOption)]
+pub enum ImportMode {
+    #[option(name = "Merge", value = 0)]
+    Merge,
+    #[option(name = "Replace", value = 1)]
+    Replace,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "config-import",
+    desc = "Import a configuration made by /utils config-export."
+)]
+pub struct ConfigImport {
+    /// The exported configuration.
+    file: Attachment,
+    /// Whether to keep (merge) or delete (replace) anything that isn't in the file.
+    mode: Option<ImportMode>,
+}
+
+impl ConfigImport {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx);
+        let guild_id_i64 = guild_id.get_i64();
+        if !check_manage_guild(&mut ctx).await? {
+            return Ok(());
+        }
+
+        if self.file.size > constants::MAX_IMPORT_SIZE {
+            ctx.respond_str("That file is too large.", true).await?;
+            return Ok(());
+        }
+
+        ctx.defer(true).await?;
+
+        let file = ctx
+            .bot
+            .reqwest
+            .get(&self.file.url)
+            .send()
+            .await?
+            .bytes()
+            .await?;
+        let mut config: GuildConfig = match serde_json::from_slice(&file) {
+            Ok(val) => val,
+            Err(why) => {
+                ctx.respond_str(&format!("Invalid file: {why}"), true)
+                    .await?;
+                return Ok(());
+            }
+        };
+
+        let premium = is_guild_premium(&ctx.bot, guild_id_i64, true).await?;
+        if let Err(why) = config.validate(premium) {
+            ctx.respond_str(&why, true).await?;
+            return Ok(());
+        }
+
+        DbGuild::create(&ctx.bot.pool, guild_id_i64).await?;
+
+        let replace = matches!(self.mode, Some(ImportMode::Replace));
+        let plan = config.plan(&ctx.bot, guild_id, replace, premium).await?;
+        if let Err(why) = plan.check_limits() {
+            ctx.respond_str(&why, true).await?;
+            return Ok(());
+        }
+        if plan.is_empty() {
+            ctx.respond_str("There is nothing to import.", true).await?;
+            return Ok(());
+        }
+
+        let preview = truncate(plan.describe());
+        let conf = confirm::simple(
+            &mut ctx,
+            &format!(
+                "Importing this configuration will make these changes:\n{preview}\n\n\
+                Do you wish to continue?"
+            ),
+            replace,
+        )
+        .await?;
+        let Some(mut btn_ctx) = conf else {
+            return Ok(());
+        };
+
+        let mut tx = ctx.bot.pool.begin().await?;
+        let applied = match config
+            .apply(&ctx.bot, &mut tx, guild_id, replace, premium)
+            .await?
+        {
+            Ok(applied) => applied,
+            Err(why) => {
+                btn_ctx
+                    .edit_str(&format!("Nothing was imported. {why}"), true)
+                    .await?;
+                return Ok(());
+            }
+        };
+        let guild_name = ctx
+            .bot
+            .cache
+            .guilds
+            .with(&guild_id, |_, guild| guild.as_ref().map(|g| g.name.clone()))
+            .unwrap_or_default();
+        audit::log(
+            &mut tx,
+            &ctx,
+            AuditAction::Import,
+            AuditTarget::Guild,
+            guild_id_i64,
+            &guild_name,
+            plan.audit_changes(replace),
+        )
+        .await?;
+        tx.commit().await?;
+        applied.update_cache(&ctx.bot, guild_id);
+        let report = applied.report;
+
+        let mut message = "Imported the configuration.".to_string();
+        if !report.is_empty() {
+            message.push_str(
+                "\n\nSome settings referenced things that don't exist in this server, \
+                so they were left out:\n",
+            );
+            message.push_str(&truncate(report.describe()));
+        }
+        btn_ctx.edit_str(&message, true).await?;
+
+        Ok(())
+    }
+}
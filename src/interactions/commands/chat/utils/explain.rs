@@ -0,0 +1,184 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::channel::message::Embed;
+use twilight_util::builder::embed::EmbedFieldBuilder;
+
+use crate::{
+    core::starboard::{
+        explain::{explain_message, Explanation},
+        msg_status::MessageStatus,
+    },
+    database::DbMessage,
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::context::CommandCtx,
+    utils::{
+        embed,
+        id_as_i64::GetI64,
+        message_link::parse_message_link,
+        views::select_paginator::{SelectPaginatorBuilder, SelectPaginatorPageBuilder},
+    },
+};
+
+use super::INVALID_MESSAGE_ERR;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "explain",
+    desc = "Explain why a message is or isn't on each starboard."
+)]
+pub struct Explain {
+    /// Link to the message to explain.
+    message: String,
+}
+
+impl Explain {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx).get_i64();
+
+        let Some((_channel_id, message_id)) = parse_message_link(&self.message) else {
+            ctx.respond_str("Invalid message link.", true).await?;
+            return Ok(());
+        };
+
+        let Some(orig) = DbMessage::get_original(&ctx.bot.pool, message_id).await? else {
+            ctx.respond_str(INVALID_MESSAGE_ERR, true).await?;
+            return Ok(());
+        };
+
+        if orig.guild_id != guild_id {
+            ctx.respond_str("That message belongs to a different server.", true)
+                .await?;
+            return Ok(());
+        }
+
+        let explanations = explain_message(&ctx.bot, &orig).await?;
+        if explanations.is_empty() {
+            ctx.respond_str("This server has no starboards.", true)
+                .await?;
+            return Ok(());
+        }
+
+        let mut paginator = SelectPaginatorBuilder::new(ctx);
+        for explanation in explanations {
+            let label = format!("Starboard '{}'", explanation.config.starboard.name);
+            let page =
+                SelectPaginatorPageBuilder::new(label).add_embed(explain_embed(&orig, explanation));
+            paginator = paginator.add_page(page);
+        }
+
+        paginator.build().run().await
+    }
+}
+
+fn explain_embed(orig: &DbMessage, ex: Explanation) -> Embed {
+    let config = &ex.config;
+
+    let summary = if config.starboard.premium_locked {
+        "This starboard is premium-locked, so nothing is sent to it.".to_string()
+    } else if !config.resolved.enabled {
+        "This starboard is disabled in this channel, so nothing is sent to it.".to_string()
+    } else {
+        let state = match (ex.on_starboard, &ex.status) {
+            (true, MessageStatus::Remove) => {
+                "The message is on the starboard, but will be removed the next time it updates."
+            }
+            (true, _) => "The message is on the starboard.",
+            (false, MessageStatus::Send(_)) => {
+                "The message isn't on the starboard, but will be sent the next time it updates."
+            }
+            (false, _) => "The message isn't on the starboard.",
+        };
+        format!("{}\n{}", state, ex.reason.describe())
+    };
+
+    let fmt_opt = |val: Option<i16>| match val {
+        None => "disabled".to_string(),
+        Some(val) => val.to_string(),
+    };
+    let points = format!(
+        "{} points\nrequired: {}\nrequired-remove: {}",
+        ex.points,
+        fmt_opt(config.resolved.required),
+        fmt_opt(config.resolved.required_remove),
+    );
+
+    let mut state = Vec::new();
+    if orig.frozen {
+        state.push("Frozen".to_string());
+    }
+    if orig.trashed {
+        state.push("Trashed".to_string());
+    }
+    if orig.forced_to.contains(&config.starboard.id) {
+        state.push("Forced to this starboard".to_string());
+    }
+    if orig.is_nsfw {
+        state.push("Sent in an NSFW channel".to_string());
+    }
+    if let Some(other) = &ex.exclusive_conflict {
+        state.push(format!(
+            "On '{other}', which is in the same exclusive group"
+        ));
+    }
+    if state.is_empty() {
+        state.push("Nothing special.".to_string());
+    }
+
+    let overrides = if config.overrides.is_empty() {
+        "None".to_string()
+    } else {
+        config
+            .overrides
+            .iter()
+            .map(|ov| format!("'{}'", ov.name))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let regex = if config.resolved.matches.is_none() && config.resolved.not_matches.is_none() {
+        "No regex is set.".to_string()
+    } else if ex.passes_regex {
+        "The message passes the regex.".to_string()
+    } else {
+        "The message doesn't pass the regex.".to_string()
+    };
+
+    let votes = match ex.vote_rejection {
+        None => "Votes on this message count.".to_string(),
+        Some(rejection) => format!("Votes don't count. {}", rejection.describe()),
+    };
+
+    let group_name = |id: i32| {
+        ex.filter_group_names
+            .get(&id)
+            .map_or("unknown", |name| name.as_str())
+    };
+    let filters = match ex.filters.checks.last() {
+        Some(check) if check.instant_fail => format!(
+            "The message fails filter {} of the group '{}' (`{}`).",
+            check.position,
+            group_name(check.filter_group_id),
+            check.failed_condition.unwrap_or("unknown"),
+        ),
+        Some(check) if check.instant_pass => format!(
+            concat!(
+                "The message passes, because filter {} of the group '{}' instantly passes. ",
+                "Any filters after it aren't checked."
+            ),
+            check.position,
+            group_name(check.filter_group_id),
+        ),
+        _ => "The message passes every filter group.".to_string(),
+    };
+
+    embed::build()
+        .title(format!("Starboard '{}'", config.starboard.name))
+        .description(summary)
+        .field(EmbedFieldBuilder::new("Points", points).inline())
+        .field(EmbedFieldBuilder::new("Message", state.join("\n")).inline())
+        .field(EmbedFieldBuilder::new("Overrides", overrides))
+        .field(EmbedFieldBuilder::new("Regex", regex))
+        .field(EmbedFieldBuilder::new("Votes", votes))
+        .field(EmbedFieldBuilder::new("Filters (without a voter)", filters))
+        .build()
+}
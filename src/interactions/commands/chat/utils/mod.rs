@@ -1,3 +1,6 @@
+pub mod audit_log;
+pub mod config;
+pub mod explain;
 pub mod force;
 pub mod freeze;
 pub mod info;
@@ -5,13 +8,18 @@ pub mod recount;
 pub mod refresh;
 pub mod trash;
 pub mod trashcan;
+pub mod undo;
 pub mod unforce;
+pub mod vote_history;
 
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
     errors::StarboardResult,
-    interactions::{commands::permissions::manage_messages, context::CommandCtx},
+    interactions::{
+        commands::permissions::{manage_guild, manage_messages},
+        context::CommandCtx,
+    },
 };
 
 const INVALID_MESSAGE_ERR: &str = concat!(
@@ -21,6 +29,45 @@ const INVALID_MESSAGE_ERR: &str = concat!(
     "\n- The message doesn't have any upvotes, so it isn't in the database.",
 );
 
+/// How much of a long preview or report to show, so that it fits in a
+/// message.
+const MAX_DESCRIPTION_LENGTH: usize = 1_500;
+
+fn truncate(mut text: String) -> String {
+    if text.len() > MAX_DESCRIPTION_LENGTH {
+        let mut end = MAX_DESCRIPTION_LENGTH;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        text.truncate(end);
+        text.push_str("...");
+    }
+
+    text
+}
+
+/// Commands that change the configuration live under /utils, which only
+/// requires the "Manage Messages" permission, so they check for "Manage
+/// Server" themselves.
+async fn check_manage_guild(ctx: &mut CommandCtx) -> StarboardResult<bool> {
+    let allowed = ctx
+        .interaction
+        .member
+        .as_ref()
+        .and_then(|member| member.permissions)
+        .is_some_and(|permissions| permissions.contains(manage_guild()));
+
+    if !allowed {
+        ctx.respond_str(
+            "You need the \"Manage Server\" permission to use this command.",
+            true,
+        )
+        .await?;
+    }
+
+    Ok(allowed)
+}
+
 #[derive(CommandModel, CreateCommand)]
 #[command(
     name = "utils",
@@ -31,6 +78,8 @@ const INVALID_MESSAGE_ERR: &str = concat!(
 pub enum Utils {
     #[command(name = "info")]
     Info(info::Info),
+    #[command(name = "explain")]
+    Explain(explain::Explain),
 
     #[command(name = "freeze")]
     Freeze(freeze::Freeze),
@@ -53,12 +102,24 @@ pub enum Utils {
     Refresh(refresh::Refresh),
     #[command(name = "recount")]
     Recount(recount::Recount),
+    #[command(name = "vote-history")]
+    VoteHistory(vote_history::VoteHistory),
+
+    #[command(name = "config-export")]
+    ConfigExport(config::ConfigExport),
+    #[command(name = "config-import")]
+    ConfigImport(config::ConfigImport),
+    #[command(name = "audit-log")]
+    AuditLog(audit_log::AuditLog),
+    #[command(name = "undo")]
+    Undo(undo::Undo),
 }
 
 impl Utils {
     pub async fn callback(self, ctx: CommandCtx) -> StarboardResult<()> {
         match self {
             Self::Info(cmd) => cmd.callback(ctx).await,
+            Self::Explain(cmd) => cmd.callback(ctx).await,
 
             Self::Freeze(cmd) => cmd.callback(ctx).await,
             Self::UnFreeze(cmd) => cmd.callback(ctx).await,
@@ -72,6 +133,12 @@ impl Utils {
 
             Self::Refresh(cmd) => cmd.callback(ctx).await,
             Self::Recount(cmd) => cmd.callback(ctx).await,
+            Self::VoteHistory(cmd) => cmd.callback(ctx).await,
+
+            Self::ConfigExport(cmd) => cmd.callback(ctx).await,
+            Self::ConfigImport(cmd) => cmd.callback(ctx).await,
+            Self::AuditLog(cmd) => cmd.callback(ctx).await,
+            Self::Undo(cmd) => cmd.callback(ctx).await,
         }
     }
 }
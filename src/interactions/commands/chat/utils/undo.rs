@@ -0,0 +1,134 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    core::{
+        audit::{
+            self,
+            undo::{UndoResult, UNDOABLE_TARGETS},
+            AuditAction, AuditTarget,
+        },
+        premium::is_premium::is_guild_premium,
+    },
+    database::models::audit_log_entry::AuditLogEntry,
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::context::CommandCtx,
+    utils::{id_as_i64::GetI64, views::confirm},
+};
+
+use super::{audit_log::format_entry, check_manage_guild, truncate};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "undo",
+    desc = "Undo the most recent edits to starboards, overrides, and filters."
+)]
+pub struct Undo {
+    /// How many edits to undo. Defaults to 1.
+    #[command(min_value = 1, max_value = 10)]
+    count: Option<i64>,
+}
+
+impl Undo {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        if !check_manage_guild(&mut ctx).await? {
+            return Ok(());
+        }
+        let guild_id = get_guild_id!(ctx).get_i64();
+
+        let target_kinds: Vec<_> = UNDOABLE_TARGETS
+            .iter()
+            .map(|target| target.as_str().to_string())
+            .collect();
+        let entries = AuditLogEntry::list_undoable(
+            &ctx.bot.pool,
+            guild_id,
+            &target_kinds,
+            self.count.unwrap_or(1),
+        )
+        .await?;
+        if entries.is_empty() {
+            ctx.respond_str("There are no edits to undo.", true).await?;
+            return Ok(());
+        }
+
+        let diff = entries
+            .iter()
+            .map(format_entry)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let Some(mut btn_ctx) = confirm::simple(
+            &mut ctx,
+            &format!(
+                "This will undo these edits, newest first:\n\n{}\nContinue?",
+                truncate(diff)
+            ),
+            true,
+        )
+        .await?
+        else {
+            return Ok(());
+        };
+
+        let premium = is_guild_premium(&ctx.bot, guild_id, true).await?;
+        let mut results = Vec::new();
+        for entry in entries {
+            let target = format!("{} '{}'", entry.target_kind, entry.target_name);
+
+            // claim the entry first, so that it can't be undone twice. The
+            // claim is rolled back with the transaction if the edit isn't
+            // reverted.
+            let mut tx = ctx.bot.pool.begin().await?;
+            if !AuditLogEntry::set_undone(&mut tx, entry.id).await? {
+                results.push(format!("The edit to {target} was already undone."));
+                continue;
+            }
+
+            match audit::undo::undo(&ctx.bot, &mut tx, &entry, premium).await? {
+                UndoResult::Undone { changes, conflicts } => {
+                    if let Some(kind) = AuditTarget::parse(&entry.target_kind) {
+                        audit::log(
+                            &mut tx,
+                            &ctx,
+                            AuditAction::Undo,
+                            kind,
+                            entry.target_id,
+                            &entry.target_name,
+                            changes,
+                        )
+                        .await?;
+                    }
+                    tx.commit().await?;
+                    audit::undo::update_cache(&ctx.bot, &entry).await?;
+
+                    let mut result = format!("Undid the edit to {target}.");
+                    if !conflicts.is_empty() {
+                        result.push_str(&format!(
+                            " These settings were changed again since, so they were left alone: {}",
+                            conflicts
+                                .iter()
+                                .map(|key| format!("`{}`", key.replace('_', "-")))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ));
+                    }
+                    results.push(result);
+                }
+                UndoResult::Missing => {
+                    results.push(format!(
+                        "Couldn't undo the edit to {target}, because it no longer exists."
+                    ));
+                }
+                UndoResult::Invalid(why) => {
+                    results.push(format!("Couldn't undo the edit to {target}: {why}"));
+                }
+            }
+        }
+
+        btn_ctx
+            .edit_str(&truncate(results.join("\n")), true)
+            .await?;
+
+        Ok(())
+    }
+}
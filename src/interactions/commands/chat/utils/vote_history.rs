@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    database::{DbMessage, Starboard, VoteEvent},
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::context::CommandCtx,
+    utils::{
+        format_points::format_points, id_as_i64::GetI64, message_link::parse_message_link,
+        views::paginator,
+    },
+};
+
+use super::INVALID_MESSAGE_ERR;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "vote-history",
+    desc = "View the timeline of votes on a message."
+)]
+pub struct VoteHistory {
+    /// Link to the message to view the vote history for.
+    message: String,
+}
+
+impl VoteHistory {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx).get_i64();
+
+        let Some((_channel_id, message_id)) = parse_message_link(&self.message) else {
+            ctx.respond_str("Invalid message link.", true).await?;
+            return Ok(());
+        };
+
+        let Some(orig) = DbMessage::get_original(&ctx.bot.pool, message_id).await? else {
+            ctx.respond_str(INVALID_MESSAGE_ERR, true).await?;
+            return Ok(());
+        };
+
+        if orig.guild_id != guild_id {
+            ctx.respond_str("That message belongs to a different server.", true)
+                .await?;
+            return Ok(());
+        }
+
+        let events = VoteEvent::list_by_message(&ctx.bot.pool, orig.message_id).await?;
+        if events.is_empty() {
+            ctx.respond_str("That message has no vote history.", true)
+                .await?;
+            return Ok(());
+        }
+
+        let starboards: HashMap<_, _> = Starboard::list_by_guild(&ctx.bot.pool, guild_id)
+            .await?
+            .into_iter()
+            .map(|sb| (sb.id, sb.name))
+            .collect();
+
+        let pages = events.chunks(20).map(|chunk| {
+            chunk
+                .iter()
+                .map(|event| {
+                    let action = match (event.kind, event.is_downvote) {
+                        (VoteEvent::ADD, false) => "upvoted",
+                        (VoteEvent::ADD, true) => "downvoted",
+                        (VoteEvent::REMOVE, false) => "removed their upvote",
+                        (VoteEvent::REMOVE, true) => "removed their downvote",
+                        (VoteEvent::FLIP, false) => "changed their vote to an upvote",
+                        (VoteEvent::FLIP, true) => "changed their vote to a downvote",
+                        _ => "did something unknown",
+                    };
+                    let starboard = starboards
+                        .get(&event.starboard_id)
+                        .map(|name| name.as_str())
+                        .unwrap_or("deleted starboard");
+                    let weight = if event.weight == 1.0 {
+                        String::new()
+                    } else {
+                        format!(" (worth {})", format_points(event.weight))
+                    };
+
+                    format!(
+                        "<t:{}:f> <@{}> {action}{weight} in '{starboard}'\n",
+                        event.created_at.timestamp(),
+                        event.user_id,
+                    )
+                })
+                .collect::<String>()
+        });
+
+        let author_id = ctx.interaction.author_id().unwrap();
+        paginator::simple(
+            &mut ctx,
+            pages.map(|page| (Some(page), None)).collect(),
+            author_id,
+            true,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
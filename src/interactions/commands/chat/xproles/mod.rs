@@ -1,6 +1,7 @@
 mod delete;
 mod setxp;
 mod view;
+mod xp_source;
 
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
@@ -25,6 +26,8 @@ pub enum XPRoles {
     ClearDeleted(delete::ClearDeleted),
     #[command(name = "view")]
     View(view::View),
+    #[command(name = "xp-source")]
+    XPSource(xp_source::SetXPSource),
 }
 
 impl XPRoles {
@@ -34,6 +37,7 @@ impl XPRoles {
             Self::Delete(cmd) => cmd.callback(ctx).await,
             Self::ClearDeleted(cmd) => cmd.callback(ctx).await,
             Self::View(cmd) => cmd.callback(ctx).await,
+            Self::XPSource(cmd) => cmd.callback(ctx).await,
         }
     }
 }
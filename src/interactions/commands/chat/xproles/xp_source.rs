@@ -0,0 +1,44 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    database::DbGuild,
+    errors::StarboardResult,
+    get_guild_id,
+    interactions::{commands::choices::xp_source::XPSource, context::CommandCtx},
+    utils::id_as_i64::GetI64,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "xp-source",
+    desc = "Choose whether XPRoles use lifetime XP or season XP."
+)]
+pub struct SetXPSource {
+    /// The XP to use. Leave empty to view the current setting.
+    source: Option<XPSource>,
+}
+
+impl SetXPSource {
+    pub async fn callback(self, mut ctx: CommandCtx) -> StarboardResult<()> {
+        let guild_id = get_guild_id!(ctx).get_i64();
+
+        let Some(source) = self.source else {
+            let use_season = DbGuild::get(&ctx.bot.pool, guild_id)
+                .await?
+                .is_some_and(|g| g.xproles_use_season);
+            let source = XPSource::from_use_season(use_season);
+
+            ctx.respond_str(&format!("XPRoles currently use {}.", source.name()), true)
+                .await?;
+            return Ok(());
+        };
+
+        DbGuild::create(&ctx.bot.pool, guild_id).await?;
+        DbGuild::set_xproles_use_season(&ctx.bot.pool, guild_id, source.is_season()).await?;
+
+        ctx.respond_str(&format!("XPRoles will now use {}.", source.name()), false)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
 pub mod go_to_message;
 pub mod on_delete;
+pub mod period;
 pub mod tribool;
+pub mod xp_source;
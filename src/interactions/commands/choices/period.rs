@@ -0,0 +1,36 @@
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+use crate::constants;
+
+#[derive(CommandOption, CreateOption, Clone, Copy)]
+pub enum Period {
+    #[option(name = "Past Week", value = "week")]
+    Week,
+    #[option(name = "Past Month", value = "month")]
+    Month,
+    #[option(name = "Past Year", value = "year")]
+    Year,
+    #[option(name = "All Time", value = "all")]
+    All,
+}
+
+impl Period {
+    /// The length of the period in seconds, or `None` for all time.
+    pub fn as_secs(&self) -> Option<i64> {
+        match self {
+            Self::Week => Some(constants::WEEK_SECONDS),
+            Self::Month => Some(constants::MONTH_SECONDS),
+            Self::Year => Some(constants::YEAR_SECONDS),
+            Self::All => None,
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::Week => "Past Week",
+            Self::Month => "Past Month",
+            Self::Year => "Past Year",
+            Self::All => "All Time",
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+#[derive(CommandOption, CreateOption, Clone, Copy)]
+pub enum XPSource {
+    #[option(name = "Lifetime XP", value = "lifetime")]
+    Lifetime,
+    #[option(name = "Season XP", value = "season")]
+    Season,
+}
+
+impl XPSource {
+    pub fn from_use_season(use_season: bool) -> Self {
+        if use_season {
+            Self::Season
+        } else {
+            Self::Lifetime
+        }
+    }
+
+    pub fn is_season(&self) -> bool {
+        matches!(self, Self::Season)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Lifetime => "lifetime XP",
+            Self::Season => "season XP",
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! Utility for formatting starboard/override settings
 
 use std::{
+    collections::HashMap,
     fmt::{Debug, Write},
     time::Duration,
 };
@@ -52,10 +53,41 @@ pub async fn format_settings(
     guild_id: Id<GuildMarker>,
     config: &StarboardConfig,
 ) -> StarboardResult<FormattedStarboardSettings> {
-    let ov_values = config
-        .overrides
-        .get(0)
-        .map(|ov| ov.get_overrides().unwrap());
+    format_settings_with_sources(bot, guild_id, config, None).await
+}
+
+/// Like [`format_settings`], but if `sources` is set, each setting it lists
+/// is marked with its label instead of bolding the settings of the first
+/// override.
+pub async fn format_settings_with_sources(
+    bot: &StarboardBot,
+    guild_id: Id<GuildMarker>,
+    config: &StarboardConfig,
+    sources: Option<&HashMap<String, String>>,
+) -> StarboardResult<FormattedStarboardSettings> {
+    let ov_values = match sources {
+        Some(_) => None,
+        None => config
+            .overrides
+            .get(0)
+            .map(|ov| ov.get_overrides().unwrap()),
+    };
+
+    let marker = |settings: &[&str]| {
+        let mut labels: Vec<_> = settings
+            .iter()
+            .filter_map(|setting| sources?.get(*setting))
+            .collect();
+        labels.sort();
+        labels.dedup();
+
+        if labels.is_empty() {
+            String::new()
+        } else {
+            let labels: Vec<_> = labels.into_iter().map(|l| l.as_str()).collect();
+            format!(" `[{}]`", labels.join(", "))
+        }
+    };
 
     macro_rules! settings {
         ($($setting: ident, $pretty_name: expr, $value: expr;)*) => {{
@@ -71,7 +103,13 @@ pub async fn format_settings(
                     $pretty_name.to_string()
                 };
 
-                writeln!(final_result, "{}: {}", setting_name, $value).unwrap();
+                writeln!(
+                    final_result,
+                    "{}: {}{}",
+                    setting_name,
+                    $value,
+                    marker(&[stringify!($setting)]),
+                ).unwrap();
             )*
             final_result
         }};
@@ -91,6 +129,24 @@ pub async fn format_settings(
     let upvote_emojis = Vec::from_stored(res.upvote_emojis.clone()).into_readable(bot, guild_id);
     let downvote_emojis =
         Vec::from_stored(res.downvote_emojis.clone()).into_readable(bot, guild_id);
+    let emoji_points = {
+        let points: Vec<_> = res
+            .emoji_points
+            .as_object()
+            .into_iter()
+            .flatten()
+            .map(|(emoji, points)| {
+                let emoji = SimpleEmoji::from_stored(emoji.to_owned()).into_readable(bot, guild_id);
+                format!("{emoji}={points}")
+            })
+            .collect();
+
+        if points.is_empty() {
+            "1 per emoji".to_string()
+        } else {
+            points.join(", ")
+        }
+    };
 
     let older_than = if res.older_than <= 0 {
         "disabled".to_string()
@@ -128,8 +184,11 @@ pub async fn format_settings(
         let setting_name = if is_bold { "**cooldown**" } else { "cooldown" };
 
         format!(
-            "{}: {} reactions per {} seconds\n",
-            setting_name, res.cooldown_count, res.cooldown_period
+            "{}: {} reactions per {} seconds{}\n",
+            setting_name,
+            res.cooldown_count,
+            res.cooldown_period,
+            marker(&["cooldown_count", "cooldown_period"]),
         )
     };
     let on_delete = match res.on_delete {
@@ -151,6 +210,7 @@ pub async fn format_settings(
         enabled, "enabled", res.enabled;
         autoreact_upvote, "autoreact-upvote", res.autoreact_upvote;
         autoreact_downvote, "autoreact-downvote", res.autoreact_downvote;
+        vote_buttons, "vote-buttons", res.vote_buttons;
         remove_invalid_reactions, "remove-invalid-reactions", res.remove_invalid_reactions;
         link_deletes, "link-deletes", res.link_deletes;
         link_edits, "link-edits", res.link_edits;
@@ -214,6 +274,7 @@ pub async fn format_settings(
             required_remove, "required-remove", required_remove;
             upvote_emojis, "upvote-emojis", upvote_emojis;
             downvote_emojis, "downvote-emojis", downvote_emojis;
+            emoji_points, "emoji-points", emoji_points;
             self_vote, "self-vote", res.self_vote;
             allow_bots, "allow-bots", res.allow_bots;
             require_image, "require-image", res.require_image;
@@ -225,10 +286,14 @@ pub async fn format_settings(
             concat!(
             "These settings are premium-only. You can input a simple phrase to match on, or you ",
             "can use regex for more advanced filtering. See [rustexp](https://rustexp.lpil.uk) ",
-            "for more info on regex.{}\n\nMessages **must** match:\n{}\n",
-            "Messages **must not** match:\n{}",
+            "for more info on regex.{}\n\nMessages **must** match{}:\n{}\n",
+            "Messages **must not** match{}:\n{}",
         ),
-            resync, must_match, must_not_match
+            resync,
+            marker(&["matches"]),
+            must_match,
+            marker(&["not_matches"]),
+            must_not_match
         ),
         filters: filters_field(bot, config.starboard.id).await?,
     };
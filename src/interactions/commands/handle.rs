@@ -35,6 +35,7 @@ pub async fn handle_command(ctx: CommandCtx) -> StarboardResult<()> {
         "filters" => chat::filters::Filters,
         "xproles" => chat::xproles::XPRoles,
         "posroles" => chat::posroles::PosRoles,
+        "seasons" => chat::seasons::Seasons,
         "utils" => chat::utils::Utils,
         "premium" => chat::premium::Premium,
         "premium-locks" => chat::premium_locks::PremiumLocks,
@@ -33,6 +33,7 @@ pub async fn post_commands(bot: Arc<StarboardBot>) {
         chat::filters::Filters,
         chat::xproles::XPRoles,
         chat::posroles::PosRoles,
+        chat::seasons::Seasons,
         chat::utils::Utils,
         chat::premium::Premium,
         chat::premium_locks::PremiumLocks,
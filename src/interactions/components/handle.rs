@@ -1,12 +1,14 @@
 use crate::{errors::StarboardResult, interactions::context::ComponentCtx};
 
-use super::dismiss::handle_dismiss;
+use super::{dismiss::handle_dismiss, vote::handle_vote_button};
 
 pub async fn handle_component(ctx: ComponentCtx) -> StarboardResult<()> {
     let Some(id) = ctx.data.custom_id.strip_prefix("stateless::") else { return Ok(()); };
 
     match id {
         "dismiss_notification" => handle_dismiss(&ctx).await?,
+        "vote::upvote" => handle_vote_button(ctx, false).await?,
+        "vote::downvote" => handle_vote_button(ctx, true).await?,
         _ => unreachable!("Unhandled stateless component: {id}"),
     }
 
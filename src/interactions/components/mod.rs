@@ -2,3 +2,4 @@
 
 pub mod dismiss;
 pub mod handle;
+pub mod vote;
@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use crate::{
+    core::{
+        emoji::{EmojiCommon, SimpleEmoji},
+        premium::is_premium::is_guild_premium,
+        starboard::{
+            config::StarboardConfig,
+            handle::RefreshMessage,
+            reaction_events::{remove_vote, save_votes},
+            vote_status::{VoteContext, VoteStatus},
+        },
+        stats::refresh_xp,
+    },
+    database::{DbMember, DbMessage, DbUser, StarboardMessage, Vote},
+    errors::StarboardResult,
+    interactions::context::ComponentCtx,
+    utils::{id_as_i64::GetI64, into_id::IntoId},
+};
+
+pub async fn handle_vote_button(mut ctx: ComponentCtx, is_downvote: bool) -> StarboardResult<()> {
+    let Some(guild_id) = ctx.interaction.guild_id else {
+        return Ok(());
+    };
+    let user_id = ctx.interaction.author_id().unwrap();
+    let message_id = ctx.interaction.message.as_ref().unwrap().id;
+    let bot = ctx.bot.clone();
+    let vote_name = if is_downvote { "downvote" } else { "upvote" };
+
+    let Some(sb_msg) = StarboardMessage::get(&bot.pool, message_id.get_i64()).await? else {
+        ctx.respond_str("This message is no longer on the starboard.", true)
+            .await?;
+        return Ok(());
+    };
+    let Some(orig) = DbMessage::get(&bot.pool, sb_msg.message_id).await? else {
+        return Ok(());
+    };
+
+    // button votes only ever apply to the starboard they were clicked on
+    let configs: Vec<_> =
+        StarboardConfig::list_for_channel(&bot, guild_id, orig.channel_id.into_id())
+            .await?
+            .into_iter()
+            .filter(|c| c.starboard.id == sb_msg.starboard_id)
+            .collect();
+    let Some(config) = configs.first() else {
+        return Ok(());
+    };
+    if !config.resolved.vote_buttons {
+        ctx.respond_str("Voting with buttons is disabled for this starboard.", true)
+            .await?;
+        return Ok(());
+    }
+
+    let emojis = if is_downvote {
+        &config.resolved.downvote_emojis
+    } else {
+        &config.resolved.upvote_emojis
+    };
+    let Some(emoji) = emojis.first() else {
+        ctx.respond_str(&format!("This starboard doesn't allow {vote_name}s."), true)
+            .await?;
+        return Ok(());
+    };
+    let emoji = SimpleEmoji::from_stored(emoji.clone());
+
+    let user_id_i64 = user_id.get_i64();
+    let existing = Vote::get(&bot.pool, orig.message_id, config.starboard.id, user_id_i64).await?;
+    let author_id = orig.author_id;
+
+    let Some(author) = DbUser::get(&bot.pool, author_id).await? else {
+        return Ok(());
+    };
+    let vote = VoteContext {
+        emoji: &emoji,
+        reactor_id: user_id,
+        message_id: orig.message_id.into_id(),
+        channel_id: orig.channel_id.into_id(),
+        message_author_id: orig.author_id.into_id(),
+        message_author_is_bot: author.is_bot,
+        message_has_image: None,
+        message_is_frozen: orig.frozen,
+    };
+    // removing a vote goes through the same checks as adding one, just like
+    // removing a reaction does
+    let status = VoteStatus::get_vote_status(&bot, vote, &configs).await?;
+    let (upvote, downvote) = match status {
+        VoteStatus::Valid(votes) => votes,
+        VoteStatus::Ignore(rejections) | VoteStatus::Remove(rejections) => {
+            let mut msg = format!("You can't {vote_name} this message.");
+            if let Some(rejection) = rejections.first() {
+                msg.push(' ');
+                msg.push_str(rejection.describe());
+            }
+            ctx.respond_str(&msg, true).await?;
+            return Ok(());
+        }
+    };
+
+    if existing.map(|v| v.is_downvote) == Some(is_downvote) {
+        // clicking the same button again toggles the vote off
+        remove_vote(
+            &bot,
+            orig.message_id,
+            config.starboard.id,
+            user_id_i64,
+            None,
+        )
+        .await?;
+        ctx.respond_str(&format!("Removed your {vote_name}."), true)
+            .await?;
+    } else {
+        DbUser::create(&bot.pool, user_id_i64, false).await?;
+        DbMember::create(&bot.pool, user_id_i64, guild_id.get_i64()).await?;
+        save_votes(&bot, &orig, user_id_i64, &upvote, &downvote, true).await?;
+
+        ctx.respond_str(&format!("Your {vote_name} was counted."), true)
+            .await?;
+    }
+
+    let is_premium = is_guild_premium(&bot, guild_id.get_i64(), true).await?;
+    let mut refresh = RefreshMessage::new(bot.clone(), orig.message_id.into_id(), is_premium);
+    refresh.set_configs(configs.into_iter().map(Arc::new).collect());
+    refresh.set_sql_message(orig);
+    refresh.refresh(false).await?;
+
+    refresh_xp(&bot, guild_id, author_id.into_id()).await?;
+
+    Ok(())
+}
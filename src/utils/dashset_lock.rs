@@ -37,6 +37,10 @@ where
             None
         }
     }
+
+    pub fn is_locked(&self, key: &T) -> bool {
+        self.set.contains(key)
+    }
 }
 
 pub struct DashSetLockGuard<'a, T>
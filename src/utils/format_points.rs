@@ -0,0 +1,11 @@
+/// Formats weighted points or XP with at most two decimal places, so that
+/// values such as `12.3` aren't shown as `12.300000190734863`.
+pub fn format_points(value: f32) -> String {
+    let formatted = format!("{value:.2}");
+    let formatted = formatted.trim_end_matches('0').trim_end_matches('.');
+
+    match formatted {
+        "-0" => "0".to_string(),
+        _ => formatted.to_string(),
+    }
+}
@@ -0,0 +1,67 @@
+//! Structured information about the content of a message, used by filters.
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use twilight_model::channel::Attachment;
+
+/// The kinds of attachments that filters can check for.
+pub const ATTACHMENT_TYPES: [&str; 4] = ["image", "video", "audio", "file"];
+
+lazy_static! {
+    static ref LINK_RE: Regex = Regex::new(r#"https?://([^\s/?#<>|]+)"#).unwrap();
+    static ref INVITE_RE: Regex =
+        Regex::new(r#"(?i)(discord\.gg|discord(app)?\.com/invite)/[\w-]+"#).unwrap();
+}
+
+/// The domains of every link in some content, lowercased and without a
+/// leading "www.".
+pub fn link_domains(content: &str) -> Vec<String> {
+    LINK_RE
+        .captures_iter(content)
+        .map(|cap| {
+            let host = cap[1].to_lowercase();
+            // strip user info and ports
+            let host = host.rsplit('@').next().unwrap_or(&host);
+            let host = host.split(':').next().unwrap_or(host);
+            host.strip_prefix("www.").unwrap_or(host).to_string()
+        })
+        .collect()
+}
+
+pub fn contains_invite(content: &str) -> bool {
+    INVITE_RE.is_match(content)
+}
+
+/// Whether a domain is, or is a subdomain of, any of the listed domains.
+pub fn domain_in(domain: &str, list: &[String]) -> bool {
+    list.iter().any(|listed| {
+        domain == listed
+            || domain
+                .strip_suffix(listed.as_str())
+                .is_some_and(|sub| sub.ends_with('.'))
+    })
+}
+
+/// The kind of an attachment; one of `ATTACHMENT_TYPES`.
+pub fn attachment_type(attachment: &Attachment) -> &'static str {
+    let content_type = attachment.content_type.as_deref().unwrap_or("");
+    if content_type.starts_with("image/") {
+        "image"
+    } else if content_type.starts_with("video/") {
+        "video"
+    } else if content_type.starts_with("audio/") {
+        "audio"
+    } else {
+        "file"
+    }
+}
+
+/// The lowercased file extension of an attachment, without the dot.
+pub fn attachment_extension(attachment: &Attachment) -> Option<String> {
+    let (name, ext) = attachment.filename.rsplit_once('.')?;
+    if name.is_empty() || ext.is_empty() {
+        return None;
+    }
+
+    Some(ext.to_lowercase())
+}
@@ -1,5 +1,6 @@
 use std::fmt::Display;
 
+use chrono::NaiveDate;
 use lazy_static::lazy_static;
 use regex::Regex;
 
@@ -29,6 +30,33 @@ pub fn parse_message_link(link: &str) -> Option<(i64, i64)> {
     Some((channel_id, message_id))
 }
 
+/// Milliseconds between the unix epoch and the discord epoch.
+const DISCORD_EPOCH: i64 = 1_420_070_400_000;
+
+/// Parses a message link, message ID, or date (YYYY-MM-DD) into the ID of a
+/// message at that point in time.
+pub fn parse_message_bound(bound: &str) -> Option<i64> {
+    let bound = bound.trim();
+
+    if let Some((_channel_id, message_id)) = parse_message_link(bound) {
+        return Some(message_id);
+    }
+    if let Ok(message_id) = bound.parse::<i64>() {
+        if message_id > 0 {
+            return Some(message_id);
+        }
+        return None;
+    }
+
+    let date = NaiveDate::parse_from_str(bound, "%Y-%m-%d").ok()?;
+    let millis = date.and_hms_opt(0, 0, 0)?.timestamp_millis() - DISCORD_EPOCH;
+    if millis <= 0 {
+        return None;
+    }
+    // dates too far in the future don't fit in a snowflake
+    millis.checked_mul(1 << 22)
+}
+
 pub fn fmt_message_link(
     guild_id: impl Display,
     channel_id: impl Display,
@@ -36,3 +64,33 @@ pub fn fmt_message_link(
 ) -> String {
     format!("https://discord.com/channels/{guild_id}/{channel_id}/{message_id}")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::parse_message_bound;
+
+    #[test]
+    fn message_bound_from_id_or_link() {
+        assert_eq!(parse_message_bound("123"), Some(123));
+        assert_eq!(parse_message_bound("1-456"), Some(456));
+        assert_eq!(
+            parse_message_bound("https://discord.com/channels/1/2/789"),
+            Some(789)
+        );
+        assert_eq!(parse_message_bound("0"), None);
+        assert_eq!(parse_message_bound("-5"), None);
+    }
+
+    #[test]
+    fn message_bound_from_date() {
+        assert_eq!(parse_message_bound("2015-01-02"), Some(86_400_000 << 22));
+        assert_eq!(
+            parse_message_bound(" 2023-06-01 "),
+            Some(265_507_200_000 << 22)
+        );
+        assert_eq!(parse_message_bound("2015-01-01"), None);
+        assert_eq!(parse_message_bound("2010-01-01"), None);
+        assert_eq!(parse_message_bound("9999-01-01"), None);
+        assert_eq!(parse_message_bound("2023-13-01"), None);
+    }
+}
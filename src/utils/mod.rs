@@ -4,12 +4,16 @@ pub mod dashset_lock;
 pub mod div_ceil;
 pub mod dm;
 pub mod embed;
+pub mod format_points;
 pub mod get_status;
 pub mod id_as_i64;
 pub mod into_id;
+pub mod message_content;
 pub mod message_link;
 pub mod notify;
 pub mod pg_error;
+pub mod progress;
+pub mod safe_regex;
 pub mod snowflake_age;
 pub mod system_content;
 pub mod views;
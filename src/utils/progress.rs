@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use twilight_model::channel::Message;
+
+use crate::client::bot::StarboardBot;
+
+/// Returns a callback for reporting the progress of a long-running task, which
+/// edits `message` to show the progress formatted by `format`.
+pub fn report_progress<P, F>(
+    bot: Arc<StarboardBot>,
+    message: &Message,
+    format: F,
+) -> impl FnMut(P) -> BoxFuture<'static, ()>
+where
+    F: Fn(P) -> String,
+{
+    let (channel_id, message_id) = (message.channel_id, message.id);
+    move |progress| {
+        let bot = bot.clone();
+        let content = format(progress);
+        Box::pin(async move {
+            // the interaction token expires after 15 minutes, so edit the
+            // message directly
+            let update = bot
+                .http
+                .update_message(channel_id, message_id)
+                .content(Some(&content));
+            if let Ok(update) = update {
+                let _ = update.await;
+            }
+        })
+    }
+}
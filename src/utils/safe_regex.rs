@@ -0,0 +1,27 @@
+//! Regexes are written by users, so they're compiled with limits, and only
+//! the start of very long messages is matched against them. The regex crate
+//! matches in linear time, so together these bound how long each regex can
+//! take on a vote.
+
+use regex::{Regex, RegexBuilder};
+
+use crate::constants;
+
+/// Compile a user-provided regex, enforcing the size limits.
+pub fn build_regex(pattern: &str) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern)
+        .size_limit(constants::REGEX_SIZE_LIMIT)
+        .dfa_size_limit(constants::REGEX_DFA_SIZE_LIMIT)
+        .build()
+}
+
+/// The part of some content that a user-provided regex is matched against,
+/// cut off at `MAX_REGEX_HAYSTACK` bytes.
+pub fn regex_haystack(content: &str) -> &str {
+    let mut end = content.len().min(constants::MAX_REGEX_HAYSTACK);
+    while !content.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    &content[..end]
+}